@@ -0,0 +1,24 @@
+use teo::prelude::*;
+
+/// Registers a custom action of the same name on three models and a custom route. The
+/// integration tests under `tests/core/app/serve/custom_actions` run this example against their
+/// schema.
+#[main]
+async fn main() -> std::io::Result<()> {
+    let mut app_builder = AppBuilder::new();
+    app_builder.custom_action("Post", "publish", |_input: Value, _, _graph: &'static Graph| async move {
+        Ok(teon!({"published": "post"}))
+    });
+    app_builder.custom_action("Comment", "publish", |_input: Value, source: ActionSource, _graph: &'static Graph| async move {
+        let request_id = source.as_request().map(|request| request.id().to_owned()).unwrap_or_default();
+        Ok(teon!({"published": "comment", "requestId": request_id}))
+    });
+    app_builder.custom_action("Draft", "publish", |_input: Value, _, _graph: &'static Graph| async move {
+        Ok(teon!({"published": "draft"}))
+    });
+    app_builder.route(Method::POST, "/ping", |_input: Value, _, _graph: &'static Graph| async move {
+        Ok(teon!("pong"))
+    });
+    let app = app_builder.build().await;
+    app.run().await
+}
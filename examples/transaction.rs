@@ -0,0 +1,30 @@
+use teo::prelude::*;
+
+/// Serves routes which write through `Graph::transaction`. The integration tests under
+/// `tests/core/graph/transaction` run this example against their schema.
+#[main]
+async fn main() -> std::io::Result<()> {
+    let mut app_builder = AppBuilder::new();
+    app_builder.route(Method::POST, "/commit", |input: Value, _, graph: &'static Graph| async move {
+        let prefix = input.get("prefix").unwrap().as_str().unwrap().to_owned();
+        graph.transaction(|tx| async move {
+            for index in 0..2 {
+                let user = tx.create_object("User", teon!({"email": format!("{prefix}{index}@teo.io")})).await?;
+                user.save().await?;
+            }
+            Ok(Value::Null)
+        }).await
+    });
+    app_builder.route(Method::POST, "/rollback", |input: Value, _, graph: &'static Graph| async move {
+        let prefix = input.get("prefix").unwrap().as_str().unwrap().to_owned();
+        graph.transaction(|tx| async move {
+            for index in 0..2 {
+                let user = tx.create_object("User", teon!({"email": format!("{prefix}{index}@teo.io")})).await?;
+                user.save().await?;
+            }
+            Err::<Value, Error>(Error::custom_validation_error("Rolled back."))
+        }).await
+    });
+    let app = app_builder.build().await;
+    app.run().await
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "data-source-mongodb")]
 pub mod mongodb;
 
-#[cfg(any(feature = "data-source-mysql", feature = "data-source-postgres", feature = "data-source-sqlite"))]
+#[cfg(any(feature = "data-source-mysql", feature = "data-source-postgres", feature = "data-source-sqlite", feature = "data-source-mssql"))]
 pub mod sql;
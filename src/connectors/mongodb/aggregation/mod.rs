@@ -344,6 +344,7 @@ impl Aggregation {
     fn build_where(model: &Model, graph: &Graph, value: &Value) -> Result<Document> {
         let value_map = value.as_hashmap().unwrap();
         let mut retval = doc!{};
+        let mut json_conditions: Vec<Document> = vec![];
         for (key, value) in value_map.iter() {
             let key = key.as_str();
             match key {
@@ -367,7 +368,11 @@ impl Aggregation {
                 _ => {
                     if let Some(field) = model.field(key) {
                         let column_name = field.column_name();
-                        retval.insert(column_name, Self::build_where_item(model, graph, field.field_type(), field.is_optional(), value)?);
+                        if field.field_type().is_json() {
+                            json_conditions.extend(Self::build_where_json(column_name, value));
+                        } else {
+                            retval.insert(column_name, Self::build_where_item(model, graph, field.field_type(), field.is_optional(), value)?);
+                        }
                     } else if let Some(relation) = model.relation(key) {
                         let relation_model = graph.model(relation.model()).unwrap();
                         let (command, inner_where) = Input::key_value(value.as_hashmap().unwrap());
@@ -388,9 +393,54 @@ impl Aggregation {
                 }
             }
         }
+        if !json_conditions.is_empty() {
+            match retval.get_array_mut("$and") {
+                Ok(and) => and.extend(json_conditions.into_iter().map(Bson::Document)),
+                Err(_) => { retval.insert("$and", json_conditions); }
+            }
+        }
         Ok(retval)
     }
 
+    // Conditions on a JSON field are keyed by dotted paths. They're joined with `$and` since more
+    // than one of them may target the same path.
+    fn build_where_json(column_name: &str, value: &Value) -> Vec<Document> {
+        let map = match value.as_hashmap() {
+            Some(map) => map,
+            None => return vec![doc!{column_name: Bson::from(value)}],
+        };
+        let mut key = column_name.to_owned();
+        if let Some(path) = map.get("path") {
+            for segment in path.as_vec().unwrap() {
+                key.push('.');
+                match segment {
+                    Value::I64(i) => key.push_str(&i.to_string()),
+                    _ => key.push_str(segment.as_str().unwrap()),
+                }
+            }
+        }
+        let mut result = vec![];
+        for (k, v) in map {
+            match k.as_str() {
+                "equals" => result.push(doc!{&key: {"$eq": Bson::from(v)}}),
+                "not" => result.push(doc!{&key: {"$ne": Bson::from(v)}}),
+                "contains" => Self::build_json_contains(&key, v, &mut result),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    fn build_json_contains(key: &str, value: &Value, result: &mut Vec<Document>) {
+        match value {
+            Value::IndexMap(map) => for (k, v) in map {
+                Self::build_json_contains(&format!("{key}.{k}"), v, result);
+            }
+            Value::Vec(values) => result.push(doc!{key: {"$all": values.iter().map(Bson::from).collect::<Vec<Bson>>()}}),
+            _ => result.push(doc!{key: {"$eq": Bson::from(value)}}),
+        }
+    }
+
     fn build_where_item(_model: &Model, _graph: &Graph, _type: &FieldType, _optional: bool, value: &Value) -> Result<Bson> {
         if let Some(map) = value.as_hashmap() {
             Ok(Bson::Document(map.iter().filter(|(k, _)| k.as_str() != "mode").map(|(k, v)| {
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
-use bson::Bson;
+use bson::{Bson, Document, Regex};
+use chrono::NaiveTime;
 use key_path::KeyPath;
 
 use crate::core::error::Error;
@@ -32,6 +33,40 @@ impl BsonCoder {
         }
     }
 
+    /// Encodes a value of a raw query. Values without a BSON counterpart are reported instead of
+    /// panicking since raw queries are written by hand.
+    pub(crate) fn encode_raw(value: &Value) -> Result<Bson> {
+        match value {
+            Value::Vec(vec) => Ok(Bson::Array(vec.iter().map(Self::encode_raw).collect::<Result<Vec<Bson>>>()?)),
+            Value::HashMap(map) => Ok(Bson::Document(map.iter().map(|(k, v)| Ok((k.clone(), Self::encode_raw(v)?))).collect::<Result<Document>>()?)),
+            Value::BTreeMap(map) => Ok(Bson::Document(map.iter().map(|(k, v)| Ok((k.clone(), Self::encode_raw(v)?))).collect::<Result<Document>>()?)),
+            Value::IndexMap(map) => Ok(Bson::Document(map.iter().map(|(k, v)| Ok((k.clone(), Self::encode_raw(v)?))).collect::<Result<Document>>()?)),
+            Value::RegExp(regex) => Ok(Bson::RegularExpression(Regex { pattern: regex.as_str().to_owned(), options: String::new() })),
+            Value::Null | Value::ObjectId(_) | Value::Bool(_) | Value::I32(_) | Value::I64(_) | Value::F32(_) | Value::F64(_) | Value::String(_) | Value::Date(_) | Value::DateTime(_) | Value::Time(_) | Value::Bytes(_) => Ok(value.clone().into()),
+            _ => Err(Error::invalid_operation(format!("Cannot encode `{:?}' in a raw query.", value))),
+        }
+    }
+
+    /// Decodes a document returned by a raw query. There's no model to follow, so BSON types
+    /// are mapped to their closest counterparts.
+    pub(crate) fn decode_raw(bson_value: &Bson) -> Value {
+        match bson_value {
+            Bson::Null | Bson::Undefined => Value::Null,
+            Bson::Boolean(b) => Value::Bool(*b),
+            Bson::Int32(n) => Value::I32(*n),
+            Bson::Int64(n) => Value::I64(*n),
+            Bson::Double(n) => Value::F64(*n),
+            Bson::String(s) | Bson::Symbol(s) => Value::String(s.clone()),
+            Bson::ObjectId(oid) => Value::ObjectId(*oid),
+            Bson::DateTime(val) => Value::DateTime(val.to_chrono()),
+            Bson::Binary(val) => Value::Bytes(val.bytes.clone()),
+            Bson::Array(arr) => Value::Vec(arr.iter().map(Self::decode_raw).collect()),
+            Bson::Document(doc) => Value::IndexMap(doc.iter().map(|(k, v)| (k.clone(), Self::decode_raw(v))).collect()),
+            // the rest have no teon counterpart, they're kept in their extended JSON form
+            _ => Value::String(bson_value.to_string()),
+        }
+    }
+
     pub(crate) fn decode<'a>(model: &Model, graph: &Graph, r#type: &FieldType, optional: bool, bson_value: &Bson, path: impl AsRef<KeyPath<'a>>) -> Result<Value> {
         if bson_value.as_null().is_some() && optional {
             return Ok(Value::Null);
@@ -75,6 +110,15 @@ impl BsonCoder {
                 Some(val) => Ok(Value::DateTime(val.to_chrono())),
                 None => Err(Error::record_decoding_error(model.name(), path, "datetime")),
             }
+            FieldType::Time => match bson_value.as_str().and_then(|s| NaiveTime::parse_from_str(s, "%H:%M:%S%.f").ok()) {
+                Some(val) => Ok(Value::Time(val)),
+                None => Err(Error::record_decoding_error(model.name(), path, "time string")),
+            }
+            FieldType::Bytes => match bson_value {
+                Bson::Binary(val) => Ok(Value::Bytes(val.bytes.clone())),
+                _ => Err(Error::record_decoding_error(model.name(), path, "binary")),
+            }
+            FieldType::Json => Ok(Self::decode_raw(bson_value)),
             FieldType::Enum(enum_def) => match bson_value.as_str() {
                 Some(val) => {
                     let enum_name = enum_def.name();
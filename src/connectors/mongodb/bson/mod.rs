@@ -1,4 +1,5 @@
-use bson::Bson;
+use bson::{Binary, Bson};
+use bson::spec::BinarySubtype;
 use bson::datetime::{DateTime as BsonDateTime};
 use chrono::{NaiveDateTime, NaiveTime, TimeZone, Utc};
 use crate::prelude::Value;
@@ -19,6 +20,9 @@ impl Into<Bson> for Value {
             Value::String(s) => Bson::String(s),
             Value::Date(val) => Bson::DateTime(BsonDateTime::from(Utc.from_utc_datetime(&NaiveDateTime::new(val, NaiveTime::default())))),
             Value::DateTime(val) => Bson::DateTime(BsonDateTime::from(val)),
+            // stored in a fixed format so that times compare in order as strings
+            Value::Time(val) => Bson::String(val.format("%H:%M:%S%.f").to_string()),
+            Value::Bytes(val) => Bson::Binary(Binary { subtype: BinarySubtype::Generic, bytes: val }),
             Value::Vec(val) => Bson::Array(val.iter().map(|i| { i.into() }).collect()),
             Value::HashMap(val) => Bson::Document(val.iter().map(|(k, v)| (k.clone(), v.into())).collect()),
             Value::BTreeMap(val) => Bson::Document(val.iter().map(|(k, v)| (k.clone(), v.into())).collect()),
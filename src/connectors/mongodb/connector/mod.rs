@@ -6,15 +6,17 @@ use std::sync::Arc;
 use std::sync::atomic::{Ordering};
 use async_trait::async_trait;
 use bson::{Bson, doc, Document};
+use chrono::Utc;
 use futures_util::StreamExt;
 use key_path::path;
 use mongodb::{options::ClientOptions, Client, Database, Collection, IndexModel};
 use mongodb::error::{ErrorKind, WriteFailure, Error as MongoDBError};
-use mongodb::options::{FindOneAndUpdateOptions, IndexOptions, ReturnDocument};
+use mongodb::options::{FindOneAndUpdateOptions, IndexOptions, ReturnDocument, UpdateOptions};
 use regex::Regex;
 use crate::connectors::mongodb::aggregation::Aggregation;
 use crate::connectors::mongodb::bson::coder::BsonCoder;
 use crate::connectors::mongodb::connector::save_session::MongoDBSaveSession;
+use crate::connectors::mongodb::migration::plan::MongoDBIndexOperation;
 use crate::core::action::{Action, FIND, MANY, NESTED, SINGLE};
 use crate::core::action::source::ActionSource;
 use crate::core::connector::Connector;
@@ -32,11 +34,15 @@ use crate::core::input::Input;
 use crate::core::result::Result;
 use crate::teon;
 
+/// The collection which records revoked auth tokens.
+const REVOKED_TOKENS_COLLECTION: &str = "_teo_revoked_tokens";
+
 #[derive(Debug)]
 pub struct MongoDBConnector {
     loaded: bool,
     client: Client,
     database: Database,
+    supports_transaction: bool,
 }
 
 impl MongoDBConnector {
@@ -57,11 +63,17 @@ impl MongoDBConnector {
             Ok(_) => (),
             Err(_) => panic!("Cannot connect to MongoDB database."),
         }
+        // transactions are only available on replica sets and sharded clusters
+        let supports_transaction = match client.database("admin").run_command(doc! {"isMaster": 1}, None).await {
+            Ok(result) => result.contains_key("setName") || result.get_str("msg").map_or(false, |m| m == "isdbgrid"),
+            Err(_) => false,
+        };
         let database = client.database(&database_name);
         MongoDBConnector {
             loaded: false,
             client,
             database,
+            supports_transaction,
         }
     }
 
@@ -69,6 +81,98 @@ impl MongoDBConnector {
         self.database.collection(name)
     }
 
+    async fn aggregate_documents(&self, col: &Collection<Document>, pipeline: Vec<Document>, session: Option<Arc<dyn SaveSession>>) -> std::result::Result<Vec<std::result::Result<Document, MongoDBError>>, MongoDBError> {
+        if let Some(session) = session {
+            let mut client_session = MongoDBSaveSession::from_save_session(session.as_ref()).client_session().await?;
+            if let Some(client_session) = client_session.as_mut() {
+                let mut cur = col.aggregate_with_session(pipeline, None, client_session).await?;
+                return Ok(cur.stream(client_session).collect().await);
+            }
+        }
+        let cur = col.aggregate(pipeline, None).await?;
+        Ok(cur.collect().await)
+    }
+
+    /// Compares the indices in the database with the models and returns the operations which
+    /// bring the database up to date. The database is only read.
+    async fn index_plan(&self, models: &Vec<Model>) -> Vec<MongoDBIndexOperation> {
+        let mut operations = vec![];
+        for model in models {
+            let name = model.name();
+            let collection = self.get_collection(name);
+            let mut reviewed_names: Vec<String> = Vec::new();
+            let cursor_result = collection.list_indexes(None).await;
+            if cursor_result.is_ok() {
+                let mut cursor = cursor_result.unwrap();
+                while let Some(Ok(index)) = cursor.next().await {
+                    if index.keys == doc!{"_id": 1} {
+                        continue
+                    }
+                    let index_name = (&index).options.as_ref().unwrap().name.as_ref().unwrap();
+                    let result = model.indices().iter().find(|i| &i.mongodb_name() == index_name);
+                    if result.is_none() {
+                        // not in our model definition, but in the database
+                        // drop this index
+                        operations.push(MongoDBIndexOperation::DropIndex { collection: name.to_string(), name: index_name.clone() });
+                    } else {
+                        let result = result.unwrap();
+                        let our_format_index: ModelIndex = (&index).into();
+                        if !Self::index_matches(model, result, &our_format_index) {
+                            // alter this index
+                            // drop first
+                            operations.push(MongoDBIndexOperation::DropIndex { collection: name.to_string(), name: index_name.clone() });
+                            // create index
+                            operations.push(MongoDBIndexOperation::CreateIndex { collection: name.to_string(), index: Box::new(Self::index_model(model, result)) });
+                        }
+                    }
+                    reviewed_names.push(index_name.clone());
+                }
+            }
+            for index in model.indices() {
+                if !reviewed_names.contains(&index.mongodb_name()) {
+                    // ignore primary
+                    if Self::is_id_index(model, index) {
+                        continue
+                    }
+                    // create this index
+                    operations.push(MongoDBIndexOperation::CreateIndex { collection: name.to_string(), index: Box::new(Self::index_model(model, index)) });
+                }
+            }
+        }
+        operations
+    }
+
+    /// Whether the index is a primary key stored in `_id`, which MongoDB indexes by itself. Other
+    /// primary keys, compound ones included, are kept unique by an index of their own.
+    fn is_id_index(model: &Model, index: &ModelIndex) -> bool {
+        index.r#type().is_primary() && index.keys().len() == 1 && model.field(index.keys().first().unwrap()).unwrap().column_name() == "_id"
+    }
+
+    /// Compares an index definition with the one read from the database, which is keyed by column
+    /// names and only knows whether it's unique.
+    fn index_matches(model: &Model, index: &ModelIndex, db_index: &ModelIndex) -> bool {
+        index.r#type().is_unique() == db_index.r#type().is_unique() &&
+            index.items().len() == db_index.items().len() &&
+            index.items().iter().zip(db_index.items().iter()).all(|(item, db_item)| {
+                model.field(item.field_name()).unwrap().column_name() == db_item.field_name() && item.sort() == db_item.sort()
+            })
+    }
+
+    fn index_model(model: &Model, index: &ModelIndex) -> IndexModel {
+        let index_options = IndexOptions::builder()
+            .name(index.mongodb_name())
+            .unique(index.r#type() == ModelIndexType::Unique || index.r#type() == ModelIndexType::Primary)
+            .sparse(true)
+            .build();
+        let mut keys = doc!{};
+        for item in index.items() {
+            let field = model.field(item.field_name()).unwrap();
+            let column_name = field.column_name();
+            keys.insert(column_name, if item.sort() == Sort::Asc { 1 } else { -1 });
+        }
+        IndexModel::builder().keys(keys).options(index_options).build()
+    }
+
     fn document_to_object(&self, document: &Document, object: &Object, select: Option<&Value>, include: Option<&Value>) -> Result<()> {
         for key in document.keys() {
             let object_field = object.model().fields().iter().find(|f| f.column_name() == key);
@@ -158,13 +262,11 @@ impl MongoDBConnector {
     async fn aggregate_or_group_by(&self, graph: &Graph, model: &Model, finder: &Value) -> Result<Vec<Value>> {
         let aggregate_input = Aggregation::build_for_aggregate(model, graph, finder)?;
         let col = self.get_collection(model.name());
-        let cur = col.aggregate(aggregate_input, None).await;
-        if cur.is_err() {
-            println!("{:?}", cur);
+        let results = self.aggregate_documents(&col, aggregate_input, graph.session()).await;
+        if results.is_err() {
             return Err(Error::unknown_database_find_error());
         }
-        let cur = cur.unwrap();
-        let results: Vec<std::result::Result<Document, MongoDBError>> = cur.collect().await;
+        let results = results.unwrap();
         let mut final_retval: Vec<Value> = vec![];
         for result in results.iter() {
             // there are records
@@ -204,7 +306,7 @@ impl MongoDBConnector {
         Ok(final_retval)
     }
 
-    async fn create_object(&self, object: &Object) -> Result<()> {
+    async fn create_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
         let model = object.model();
         let keys = object.keys_for_save();
         let col = self.get_collection(model.name());
@@ -225,7 +327,14 @@ impl MongoDBConnector {
                 }
             }
         }
-        let result = col.insert_one(doc, None).await;
+        let mut client_session = match MongoDBSaveSession::from_save_session(session.as_ref()).client_session().await {
+            Ok(client_session) => client_session,
+            Err(error) => return Err(self._handle_write_error(&error.kind, object)),
+        };
+        let result = match client_session.as_mut() {
+            Some(client_session) => col.insert_one_with_session(doc, None, client_session).await,
+            None => col.insert_one(doc, None).await,
+        };
         match result {
             Ok(insert_one_result) => {
                 let id = insert_one_result.inserted_id;
@@ -244,7 +353,7 @@ impl MongoDBConnector {
         Ok(())
     }
 
-    async fn update_object(&self, object: &Object) -> Result<()> {
+    async fn update_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
         let model = object.model();
         let keys = object.keys_for_save();
         let col = self.get_collection(model.name());
@@ -308,8 +417,15 @@ impl MongoDBConnector {
         if update_doc.is_empty() {
             return Ok(());
         }
+        let mut client_session = match MongoDBSaveSession::from_save_session(session.as_ref()).client_session().await {
+            Ok(client_session) => client_session,
+            Err(error) => return Err(self._handle_write_error(&error.kind, object)),
+        };
         if !return_new {
-            let result = col.update_one(identifier.clone(), update_doc, None).await;
+            let result = match client_session.as_mut() {
+                Some(client_session) => col.update_one_with_session(identifier.clone(), update_doc, None, client_session).await,
+                None => col.update_one(identifier.clone(), update_doc, None).await,
+            };
             return match result {
                 Ok(_) => Ok(()),
                 Err(error) => {
@@ -318,7 +434,10 @@ impl MongoDBConnector {
             }
         } else {
             let options = FindOneAndUpdateOptions::builder().return_document(ReturnDocument::After).build();
-            let result = col.find_one_and_update(identifier.clone(), update_doc, options).await;
+            let result = match client_session.as_mut() {
+                Some(client_session) => col.find_one_and_update_with_session(identifier.clone(), update_doc, options, client_session).await,
+                None => col.find_one_and_update(identifier.clone(), update_doc, options).await,
+            };
             match result {
                 Ok(updated_document) => {
                     for key in object.inner.atomic_updator_map.lock().unwrap().keys() {
@@ -340,8 +459,8 @@ impl MongoDBConnector {
 
 #[async_trait]
 impl Connector for MongoDBConnector {
-    fn default_database_type(&self, field_type: &FieldType) -> DatabaseType {
-        match field_type {
+    fn default_database_type(&self, field_type: &FieldType) -> Result<DatabaseType> {
+        Ok(match field_type {
             FieldType::ObjectId => DatabaseType::ObjectId,
             FieldType::Bool => DatabaseType::Bool,
             FieldType::I32 => DatabaseType::Int32,
@@ -352,111 +471,165 @@ impl Connector for MongoDBConnector {
             FieldType::String => DatabaseType::String,
             FieldType::Date => DatabaseType::DateTime(3),
             FieldType::DateTime => DatabaseType::DateTime(3),
+            FieldType::Time => DatabaseType::String,
+            FieldType::Bytes => DatabaseType::Binary(0),
+            FieldType::Json => DatabaseType::Json,
             FieldType::Enum(_) => DatabaseType::String,
-            FieldType::Vec(inner) => DatabaseType::Vec(Box::new(self.default_database_type(inner.field_type()))),
-            FieldType::HashMap(_) => panic!(""),
-            FieldType::BTreeMap(_) => panic!(""),
-            FieldType::Object(_) => panic!(""),
-        }
+            FieldType::Vec(inner) => DatabaseType::Vec(Box::new(self.default_database_type(inner.field_type())?)),
+            FieldType::HashMap(_) | FieldType::BTreeMap(_) => return Err(Error::invalid_operation("MongoDB doesn't support dictionary fields.")),
+            FieldType::Object(_) => return Err(Error::invalid_operation("MongoDB doesn't support object fields.")),
+        })
     }
 
     async fn migrate(&mut self, models: &Vec<Model>, reset_database: bool) -> Result<()> {
         if reset_database {
             let _ = self.database.drop(None).await;
         }
-        for model in models {
-            let name = model.name();
-            let collection = self.get_collection(name);
-            let mut reviewed_names: Vec<String> = Vec::new();
-            let cursor_result = collection.list_indexes(None).await;
-            if cursor_result.is_ok() {
-                let mut cursor = cursor_result.unwrap();
-                while let Some(Ok(index)) = cursor.next().await {
-                    if index.keys == doc!{"_id": 1} {
-                        continue
-                    }
-                    let name = (&index).options.as_ref().unwrap().name.as_ref().unwrap();
-                    let result = model.indices().iter().find(|i| &i.mongodb_name() == name);
-                    if result.is_none() {
-                        // not in our model definition, but in the database
-                        // drop this index
-                        let _ = collection.drop_index(name, None).await.unwrap();
-                    } else {
-                        let result = result.unwrap();
-                        let our_format_index: ModelIndex = (&index).into();
-                        if result != &our_format_index {
-                            // alter this index
-                            // drop first
-                            let _ = collection.drop_index(name, None).await.unwrap();
-                            // create index
-                            let index_options = IndexOptions::builder()
-                                .name(result.mongodb_name())
-                                .unique(result.r#type() == ModelIndexType::Unique || result.r#type() == ModelIndexType::Primary)
-                                .sparse(true)
-                                .build();
-                            let mut keys = doc!{};
-                            for item in result.items() {
-                                let field = model.field(item.field_name()).unwrap();
-                                let column_name = field.column_name();
-                                keys.insert(column_name, if item.sort() == Sort::Asc { 1 } else { -1 });
-                            }
-                            let index_model = IndexModel::builder().keys(keys).options(index_options).build();
-                            let _result = collection.create_index(index_model, None).await;
-                        }
-                    }
-                    reviewed_names.push(name.clone());
+        for operation in self.index_plan(models).await {
+            let description = operation.to_string();
+            let (collection, result) = match operation {
+                MongoDBIndexOperation::DropIndex { collection, name } => {
+                    let result = self.get_collection(&collection).drop_index(name, None).await.map(|_| ());
+                    (collection, result)
                 }
-            }
-            for index in model.indices() {
-                if !reviewed_names.contains(&index.mongodb_name()) {
-                    // ignore primary
-                    if index.keys().len() == 1 {
-                        let field = model.field(index.keys().get(0).unwrap()).unwrap();
-                        if field.column_name() == "_id" {
-                            continue
-                        }
-                    }
-                    // create this index
-                    let index_options = IndexOptions::builder()
-                        .name(index.mongodb_name())
-                        .unique(index.r#type() == ModelIndexType::Unique || index.r#type() == ModelIndexType::Primary)
-                        .sparse(true)
-                        .build();
-                    let mut keys = doc!{};
-                    for item in index.items() {
-                        let field = model.field(item.field_name()).unwrap();
-                        let column_name = field.column_name();
-                        keys.insert(column_name, if item.sort() == Sort::Asc { 1 } else { -1 });
-                    }
-                    let index_model = IndexModel::builder().keys(keys).options(index_options).build();
-                    let result = collection.create_index(index_model, None).await;
-                    if result.is_err() {
-                        println!("index create error: {:?}", result.err().unwrap());
-                    }
+                MongoDBIndexOperation::CreateIndex { collection, index } => {
+                    let result = self.get_collection(&collection).create_index(*index, None).await.map(|_| ());
+                    (collection, result)
                 }
+            };
+            if let Err(err) = result {
+                return Err(Error::migration_table_error(collection, None, Some(&description), err.to_string()));
             }
         }
         Ok(())
     }
 
-    async fn query_raw(&self, _query: &Value) -> Result<Value> {
-        unreachable!()
-        // let collection = self.collections.get(table.unwrap()).unwrap();
-        // let result = collection.aggregate(BsonCoder::encode_without_default_type(query), None).await;
-        // if result.is_err() {
-        //
-        // }
+    async fn migration_plan(&self, models: &Vec<Model>) -> Result<Vec<String>> {
+        Ok(self.index_plan(models).await.iter().map(|operation| operation.to_string()).collect())
+    }
+
+    async fn migration_file_contents(&self, _models: &Vec<Model>) -> Result<(String, String)> {
+        Err(Error::invalid_operation("MongoDB doesn't support migration files."))
+    }
+
+    async fn applied_migration_versions(&self) -> Result<Vec<String>> {
+        Err(Error::invalid_operation("MongoDB doesn't support migration files."))
+    }
+
+    async fn apply_migration_file(&self, _models: &Vec<Model>, _version: &str, _statements: &str) -> Result<()> {
+        Err(Error::invalid_operation("MongoDB doesn't support migration files."))
+    }
+
+    async fn revert_migration_file(&self, _version: &str, _statements: &str) -> Result<()> {
+        Err(Error::invalid_operation("MongoDB doesn't support migration files."))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        match self.database.run_command(doc! {"ping": 1}, None).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::service_unavailable(format!("Cannot connect to database: {}", err))),
+        }
+    }
+
+    async fn disconnect(&self) -> Result<()> {
+        self.client.clone().shutdown().await;
+        Ok(())
+    }
+
+    async fn revoke_token(&self, jti: &str, expires_at: i64) -> Result<()> {
+        let col = self.get_collection(REVOKED_TOKENS_COLLECTION);
+        if let Err(err) = col.delete_many(doc! {"expiresAt": {"$lt": Utc::now().timestamp()}}, None).await {
+            return Err(Error::invalid_operation(format!("Cannot purge revoked tokens: {}", err)));
+        }
+        // a token may be revoked more than once, e.g. by signing out after a refresh
+        let options = UpdateOptions::builder().upsert(true).build();
+        match col.update_one(doc! {"_id": jti}, doc! {"$set": {"expiresAt": expires_at}}, options).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot revoke token: {}", err))),
+        }
+    }
+
+    async fn is_token_revoked(&self, jti: &str) -> Result<bool> {
+        let col = self.get_collection(REVOKED_TOKENS_COLLECTION);
+        match col.find_one(doc! {"_id": jti}, None).await {
+            Ok(document) => Ok(document.is_some()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot read revoked tokens: {}", err))),
+        }
+    }
+
+    /// Runs an aggregation pipeline on a collection, `{ collection: "User", pipeline: [...] }`, or
+    /// a database command, `{ command: "count", collection: "User", arguments: {...} }`. Stages
+    /// and arguments keep the key order of the `$queryRaw` literal. The command name is passed
+    /// separately because MongoDB requires it to be the first key of the command document.
+    async fn query_raw(&self, query: &Value) -> Result<Value> {
+        if !(query.is_hashmap() || query.is_btreemap() || query.is_indexmap()) {
+            return Err(Error::invalid_operation("MongoDB raw query should be a dictionary."));
+        }
+        let collection = match query.get("collection") {
+            Some(collection) => match collection.as_str() {
+                Some(collection) => Some(collection),
+                None => return Err(Error::invalid_operation("MongoDB raw query collection should be a string.")),
+            },
+            None => None,
+        };
+        match (query.get("pipeline"), query.get("command")) {
+            (Some(pipeline), None) => {
+                let collection = match collection {
+                    Some(collection) => collection,
+                    None => return Err(Error::invalid_operation("MongoDB raw pipeline requires a collection.")),
+                };
+                let stages = match BsonCoder::encode_raw(pipeline)? {
+                    Bson::Array(stages) => stages,
+                    _ => return Err(Error::invalid_operation("MongoDB raw pipeline should be an array.")),
+                };
+                let pipeline = stages.into_iter().map(|stage| match stage {
+                    Bson::Document(stage) => Ok(stage),
+                    _ => Err(Error::invalid_operation("MongoDB raw pipeline stage should be a dictionary.")),
+                }).collect::<Result<Vec<Document>>>()?;
+                let results = match self.aggregate_documents(&self.get_collection(collection), pipeline, None).await {
+                    Ok(results) => results,
+                    Err(err) => return Err(Error::internal_server_error(err.to_string())),
+                };
+                let mut values = vec![];
+                for result in results {
+                    match result {
+                        Ok(document) => values.push(BsonCoder::decode_raw(&Bson::Document(document))),
+                        Err(err) => return Err(Error::internal_server_error(err.to_string())),
+                    }
+                }
+                Ok(Value::Vec(values))
+            }
+            (None, Some(command)) => {
+                let name = match command.as_str() {
+                    Some(name) => name,
+                    None => return Err(Error::invalid_operation("MongoDB raw command should be a string.")),
+                };
+                let mut document = Document::new();
+                document.insert(name, collection.map_or(Bson::Int32(1), |c| Bson::String(c.to_owned())));
+                if let Some(arguments) = query.get("arguments") {
+                    match BsonCoder::encode_raw(arguments)? {
+                        Bson::Document(arguments) => document.extend(arguments),
+                        _ => return Err(Error::invalid_operation("MongoDB raw command arguments should be a dictionary.")),
+                    }
+                }
+                match self.database.run_command(document, None).await {
+                    Ok(result) => Ok(BsonCoder::decode_raw(&Bson::Document(result))),
+                    Err(err) => Err(Error::internal_server_error(err.to_string())),
+                }
+            }
+            _ => Err(Error::invalid_operation("MongoDB raw query should have either a pipeline or a command.")),
+        }
     }
     
-    async fn save_object(&self, object: &Object, _session: Arc<dyn SaveSession>) -> Result<()> {
+    async fn save_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
         if object.inner.is_new.load(Ordering::SeqCst) {
-            self.create_object(object).await
+            self.create_object(object, session).await
         } else {
-            self.update_object(object).await
+            self.update_object(object, session).await
         }
     }
 
-    async fn delete_object(&self, object: &Object, _session: Arc<dyn SaveSession>) -> Result<()> {
+    async fn delete_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
         if object.inner.is_new.load(Ordering::SeqCst) {
             return Err(Error::object_is_not_saved_thus_cant_be_deleted());
         }
@@ -464,7 +637,14 @@ impl Connector for MongoDBConnector {
         let col = self.get_collection(model.name());
         let bson_identifier: Bson = object.db_identifier().into();
         let document_identifier = bson_identifier.as_document().unwrap();
-        let result = col.delete_one(document_identifier.clone(), None).await;
+        let mut client_session = match MongoDBSaveSession::from_save_session(session.as_ref()).client_session().await {
+            Ok(client_session) => client_session,
+            Err(_err) => return Err(Error::unknown_database_delete_error()),
+        };
+        let result = match client_session.as_mut() {
+            Some(client_session) => col.delete_one_with_session(document_identifier.clone(), None, client_session).await,
+            None => col.delete_one(document_identifier.clone(), None).await,
+        };
         return match result {
             Ok(_result) => Ok(()),
             Err(_err) => {
@@ -479,12 +659,11 @@ impl Connector for MongoDBConnector {
 
         let aggregate_input = Aggregation::build(model, graph, finder)?;
         let col = self.get_collection(model.name());
-        let cur = col.aggregate(aggregate_input, None).await;
-        if cur.is_err() {
+        let results = self.aggregate_documents(&col, aggregate_input, graph.session()).await;
+        if results.is_err() {
             return Err(Error::unknown_database_find_unique_error());
         }
-        let cur = cur.unwrap();
-        let results: Vec<std::result::Result<Document, MongoDBError>> = cur.collect().await;
+        let results = results.unwrap();
         if results.is_empty() {
             return Err(Error::object_not_found());
         }
@@ -502,15 +681,12 @@ impl Connector for MongoDBConnector {
         let aggregate_input = Aggregation::build(model, graph, finder)?;
         let reverse = Input::has_negative_take(finder);
         let col = self.get_collection(model.name());
-        // println!("see aggregate input: {:?}", aggregate_input);
-        let cur = col.aggregate(aggregate_input, None).await;
-        if cur.is_err() {
-            println!("{:?}", cur);
+        let results = self.aggregate_documents(&col, aggregate_input, graph.session()).await;
+        if results.is_err() {
             return Err(Error::unknown_database_find_error());
         }
-        let cur = cur.unwrap();
+        let results = results.unwrap();
         let mut result: Vec<Object> = vec![];
-        let results: Vec<std::result::Result<Document, MongoDBError>> = cur.collect().await;
         for doc in results {
             let obj = graph.new_object(model.name(), action, action_source.clone())?;
             match self.document_to_object(&doc.unwrap(), &obj, select, include) {
@@ -532,13 +708,11 @@ impl Connector for MongoDBConnector {
     async fn count(&self, graph: &Graph, model: &Model, finder: &Value) -> Result<usize> {
         let input = Aggregation::build_for_count(model, graph, finder)?;
         let col = self.get_collection(model.name());
-        let cur = col.aggregate(input, None).await;
-        if cur.is_err() {
-            println!("{:?}", cur);
+        let results = self.aggregate_documents(&col, input, graph.session()).await;
+        if results.is_err() {
             return Err(Error::unknown_database_find_error());
         }
-        let cur = cur.unwrap();
-        let results: Vec<std::result::Result<Document, MongoDBError>> = cur.collect().await;
+        let results = results.unwrap();
         if results.is_empty() {
             Ok(0)
         } else {
@@ -575,7 +749,7 @@ impl Connector for MongoDBConnector {
     }
 
     fn new_save_session(&self) -> Arc<dyn SaveSession> {
-        Arc::new(MongoDBSaveSession {})
+        Arc::new(MongoDBSaveSession::new(self.client.clone(), self.supports_transaction))
     }
 }
 
@@ -1,10 +1,76 @@
-use std::fmt::{Debug};
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use async_trait::async_trait;
+use mongodb::{Client, ClientSession};
+use mongodb::error::Error as MongoDBError;
+use tokio::sync::{Mutex, MutexGuard};
 use crate::core::connector::SaveSession;
+use crate::core::error::Error;
+use crate::core::result::Result;
 
+pub struct MongoDBSaveSession {
+    client: Client,
+    supports_transaction: bool,
+    session: Mutex<Option<ClientSession>>,
+    finished: AtomicBool,
+}
+
+impl MongoDBSaveSession {
+
+    pub(crate) fn new(client: Client, supports_transaction: bool) -> Self {
+        Self { client, supports_transaction, session: Mutex::new(None), finished: AtomicBool::new(false) }
+    }
 
-#[derive(Debug)]
-pub struct MongoDBSaveSession { }
+    pub(crate) fn from_save_session(session: &dyn SaveSession) -> &MongoDBSaveSession {
+        session.as_any().downcast_ref::<MongoDBSaveSession>().unwrap()
+    }
+
+    /// Returns the client session of this save session, the transaction is started on first use.
+    /// The guarded value is `None` for standalone servers which don't support transactions and
+    /// after the session is finished.
+    pub(crate) async fn client_session(&self) -> std::result::Result<MutexGuard<'_, Option<ClientSession>>, MongoDBError> {
+        let mut guard = self.session.lock().await;
+        if guard.is_none() && self.supports_transaction && !self.finished.load(Ordering::SeqCst) {
+            let mut session = self.client.start_session(None).await?;
+            session.start_transaction(None).await?;
+            *guard = Some(session);
+        }
+        Ok(guard)
+    }
+}
 
+impl Debug for MongoDBSaveSession {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MongoDBSaveSession").field("supports_transaction", &self.supports_transaction).field("finished", &self.finished).finish()
+    }
+}
+
+#[async_trait]
 impl SaveSession for MongoDBSaveSession {
 
+    async fn commit(&self) -> Result<()> {
+        self.finished.store(true, Ordering::SeqCst);
+        if let Some(mut session) = self.session.lock().await.take() {
+            if let Err(err) = session.commit_transaction().await {
+                let _ = session.abort_transaction().await;
+                return Err(Error::internal_server_error(format!("Cannot commit transaction: {}", err)));
+            }
+        }
+        Ok(())
+    }
+
+    async fn abort(&self) -> Result<()> {
+        self.finished.store(true, Ordering::SeqCst);
+        if let Some(mut session) = self.session.lock().await.take() {
+            if let Err(err) = session.abort_transaction().await {
+                return Err(Error::internal_server_error(format!("Cannot rollback transaction: {}", err)));
+            }
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
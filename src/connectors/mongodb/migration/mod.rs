@@ -1 +1,2 @@
 pub(crate) mod index_model;
+pub(crate) mod plan;
@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+use mongodb::IndexModel;
+
+/// A single index operation of a MongoDB migration plan.
+#[derive(Debug, Clone)]
+pub(crate) enum MongoDBIndexOperation {
+    DropIndex { collection: String, name: String },
+    CreateIndex { collection: String, index: Box<IndexModel> },
+}
+
+impl Display for MongoDBIndexOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MongoDBIndexOperation::DropIndex { collection, name } => {
+                f.write_str(&format!("db.{}.dropIndex(\"{}\")", collection, name))
+            }
+            MongoDBIndexOperation::CreateIndex { collection, index } => {
+                let options = index.options.as_ref().unwrap();
+                f.write_str(&format!(
+                    "db.{}.createIndex({}, {{ name: \"{}\", unique: {}, sparse: {} }})",
+                    collection,
+                    index.keys,
+                    options.name.as_ref().unwrap(),
+                    options.unique.unwrap_or(false),
+                    options.sparse.unwrap_or(false),
+                ))
+            }
+        }
+    }
+}
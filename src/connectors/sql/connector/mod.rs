@@ -1,22 +1,26 @@
 pub mod save_session;
+pub(crate) mod revocation;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::Ordering;
 use async_trait::async_trait;
-use quaint_forked::{prelude::*, pooled::Quaint, ast::Query as QuaintQuery};
+use quaint_forked::{prelude::*, pooled::{PooledConnection, Quaint}, ast::Query as QuaintQuery};
 use quaint_forked::error::DatabaseConstraint;
-use quaint_forked::error::ErrorKind::UniqueConstraintViolation;
+use quaint_forked::error::ErrorKind::{ForeignKeyConstraintViolation, UniqueConstraintViolation};
 use crate::core::model::Model;
 use crate::connectors::sql::schema::r#type::field::ToDatabaseType;
-use crate::connectors::sql::connector::save_session::SQLSaveSession;
+use crate::connectors::sql::connector::revocation::SQLTokenRevocation;
+use crate::connectors::sql::connector::save_session::{check_out, SQLSaveSession};
 use crate::connectors::sql::execution::Execution;
+use crate::connectors::sql::migration::history::SQLMigrationHistory;
 use crate::connectors::sql::migration::migrate::SQLMigration;
+use crate::connectors::sql::migration::plan::{migration_file_contents, migration_file_steps};
 use crate::connectors::sql::query::Query;
 use crate::connectors::sql::stmts::SQL;
 use crate::connectors::sql::schema::dialect::SQLDialect;
 use crate::connectors::sql::schema::value::decode::RowDecoder;
 use crate::connectors::sql::schema::value::encode::ToSQLString;
-use crate::connectors::sql::schema::value::encode::PSQLArrayToSQLString;
+use crate::connectors::sql::schema::value::params::SQLParams;
 use crate::connectors::sql::url::url_utils;
 use crate::core::action::Action;
 use crate::core::action::source::ActionSource;
@@ -31,45 +35,83 @@ use crate::teon;
 
 pub(crate) struct SQLConnector {
     dialect: SQLDialect,
-    pool: Quaint,
+    url: String,
+    connection_string: String,
+    pool: Mutex<Option<Quaint>>,
+    foreign_keys: bool,
 }
 
 impl SQLConnector {
 
-    pub(crate) async fn new(dialect: SQLDialect, url: &str, reset: bool) -> Self {
-        SQLMigration::create_database_if_needed(dialect, url, reset).await;
-        let url = url_utils::normalized_url(dialect, url);
-        let pool = Quaint::builder(url.as_str()).unwrap().build();
-        Self { dialect, pool }
+    pub(crate) async fn new(dialect: SQLDialect, url: &str, foreign_keys: bool) -> Self {
+        let normalized_url = url_utils::normalized_url(dialect, url);
+        let connection_string = url_utils::connection_string(dialect, &normalized_url);
+        let pool = Quaint::builder(&connection_string).unwrap().build();
+        Self { dialect, url: url.to_owned(), connection_string, pool: Mutex::new(Some(pool)), foreign_keys }
     }
 
-    async fn create_object(&self, object: &Object) -> Result<()> {
-        let conn = self.pool.check_out().await.unwrap();
+    /// Returns the connection pool. A pool which was closed by `disconnect` is opened again.
+    fn pool(&self) -> Result<Quaint> {
+        let mut pool = self.pool.lock().unwrap();
+        if let Some(pool) = pool.as_ref() {
+            return Ok(pool.clone());
+        }
+        match Quaint::builder(&self.connection_string) {
+            Ok(builder) => Ok(pool.insert(builder.build()).clone()),
+            Err(err) => Err(Error::internal_server_error(format!("Cannot open connection pool: {}", err))),
+        }
+    }
+
+    /// SQLite only enforces foreign keys on connections which enable them.
+    fn enforces_sqlite_foreign_keys(&self) -> bool {
+        self.dialect == SQLDialect::SQLite && self.foreign_keys
+    }
+
+    /// Returns a connection for migration commands, the database is created if it doesn't
+    /// exist yet.
+    async fn migration_connection(&self) -> Result<PooledConnection> {
+        SQLMigration::create_database_if_needed(self.dialect, &self.url, false).await?;
+        match self.pool()?.check_out().await {
+            Ok(conn) => Ok(conn),
+            Err(err) => Err(Error::migration_error(format!("Cannot connect to database: {}", err))),
+        }
+    }
+
+    async fn queryable(&self, session: Option<Arc<dyn SaveSession>>) -> Result<Arc<dyn Queryable>> {
+        match session {
+            Some(session) => SQLSaveSession::from_save_session(session.as_ref()).queryable().await,
+            None => Ok(Arc::new(check_out(&self.pool()?, self.enforces_sqlite_foreign_keys()).await?)),
+        }
+    }
+
+    async fn create_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
+        let conn = self.queryable(Some(session)).await?;
         let model = object.model();
         let keys = object.keys_for_save();
         let auto_keys = model.auto_keys();
+        let mut params = SQLParams::new(self.dialect);
         let mut values: Vec<(&str, String)> = vec![];
         for key in keys {
             if let Some(field) = model.field(key) {
                 let column_name = field.column_name();
                 let val = object.get_value(key).unwrap();
                 if !(field.auto_increment && val.is_null()) {
-                    values.push((column_name, PSQLArrayToSQLString::to_string_with_ft(&val, self.dialect, field.field_type())));
+                    values.push((column_name, params.bind(&val, field.field_type())));
                 }
             } else if let Some(property) = model.property(key) {
                 let val: Value = object.get_property(key).await.unwrap();
-                values.push((key, PSQLArrayToSQLString::to_string_with_ft(&val, self.dialect, property.field_type())));
+                values.push((key, params.bind(&val, property.field_type())));
             }
         }
         let value_refs: Vec<(&str, &str)> = values.iter().map(|(k, v)| (*k, v.as_str())).collect();
         let stmt = SQL::insert_into(model.table_name()).values(value_refs).returning(auto_keys).to_string(self.dialect);
-        if self.dialect == SQLDialect::PostgreSQL {
-            match conn.query(QuaintQuery::from(stmt)).await {
+        let (stmt, params) = params.finalize(stmt)?;
+        if self.dialect == SQLDialect::PostgreSQL || self.dialect == SQLDialect::MSSQL {
+            match conn.query_raw(&stmt, &params).await {
                 Ok(result_set) => {
                     let columns = result_set.columns().clone();
-                    let result = result_set.into_iter().next();
-                    if result.is_some() {
-                        let value = Execution::row_to_value(model, object.graph(), &result.unwrap(), &columns, self.dialect);
+                    if let Some(result) = result_set.into_iter().next() {
+                        let value = Execution::row_to_value(model, object.graph(), &result, &columns, self.dialect)?;
                         for (k, v) in value.as_hashmap().unwrap() {
                             object.set_value(k, v.clone())?;
                         }
@@ -82,7 +124,7 @@ impl SQLConnector {
                 }
             }
         } else {
-            match conn.query(QuaintQuery::from(stmt)).await {
+            match conn.query_raw(&stmt, &params).await {
                 Ok(result) => {
                     let id = result.last_insert_id().unwrap();
                     for key in auto_keys {
@@ -102,10 +144,11 @@ impl SQLConnector {
         }
     }
 
-    async fn update_object(&self, object: &Object) -> Result<()> {
-        let conn = self.pool.check_out().await.unwrap();
+    async fn update_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
+        let conn = self.queryable(Some(session)).await?;
         let model = object.model();
         let keys = object.keys_for_save();
+        let mut params = SQLParams::new(self.dialect);
         let mut values: Vec<(&str, String)> = vec![];
         for key in &keys {
             if let Some(field) = model.field(key) {
@@ -113,34 +156,35 @@ impl SQLConnector {
                 if let Some(updator) = object.get_atomic_updator(key) {
                     let (key, val) = Input::key_value(updator.as_hashmap().unwrap());
                     match key {
-                        "increment" => values.push((column_name, format!("{} + {}", column_name, val.to_string(self.dialect)))),
-                        "decrement" => values.push((column_name, format!("{} - {}", column_name, val.to_string(self.dialect)))),
-                        "multiply" => values.push((column_name, format!("{} * {}", column_name, val.to_string(self.dialect)))),
-                        "divide" => values.push((column_name, format!("{} / {}", column_name, val.to_string(self.dialect)))),
-                        "push" => values.push((column_name, format!("ARRAY_APPEND({}, {})", column_name, val.to_string(self.dialect)))),
+                        "increment" => values.push((column_name, format!("{} + {}", column_name, params.bind(val, field.field_type())))),
+                        "decrement" => values.push((column_name, format!("{} - {}", column_name, params.bind(val, field.field_type())))),
+                        "multiply" => values.push((column_name, format!("{} * {}", column_name, params.bind(val, field.field_type())))),
+                        "divide" => values.push((column_name, format!("{} / {}", column_name, params.bind(val, field.field_type())))),
+                        "push" => values.push((column_name, format!("ARRAY_APPEND({}, {})", column_name, params.bind(val, field.field_type().element_field().unwrap().field_type())))),
                         _ => panic!("Unhandled key."),
                     }
                 } else {
                     let val = object.get_value(key).unwrap();
-                    values.push((column_name, PSQLArrayToSQLString::to_string_with_ft(&val, self.dialect, field.field_type())));
+                    values.push((column_name, params.bind(&val, field.field_type())));
                 }
             } else if let Some(property) = model.property(key) {
                 let val: Value = object.get_property(key).await.unwrap();
-                values.push((key, PSQLArrayToSQLString::to_string_with_ft(&val, self.dialect, property.field_type())));
+                values.push((key, params.bind(&val, property.field_type())));
             }
         }
         let value_refs: Vec<(&str, &str)> = values.iter().map(|(k, v)| (*k, v.as_str())).collect();
         let identifier = object.identifier();
-        let r#where = Query::where_from_identifier(object, self.dialect);
+        let r#where = Query::where_from_previous_identifier(object, self.dialect, &mut params);
         if !value_refs.is_empty() {
             let stmt = SQL::update(model.table_name()).values(value_refs).r#where(&r#where).to_string(self.dialect);
-            let result = conn.execute(QuaintQuery::from(stmt)).await;
+            let (stmt, params) = params.finalize(stmt)?;
+            let result = conn.execute_raw(&stmt, &params).await;
             if result.is_err() {
                 println!("{:?}", result.err().unwrap());
                 return Err(Error::unknown_database_write_error());
             }
         }
-        let result = Execution::query(&self.pool, model, object.graph(), &teon!({"where": identifier, "take": 1}), self.dialect).await?;
+        let result = Execution::query(conn.as_ref(), model, object.graph(), &teon!({"where": identifier, "take": 1}), self.dialect).await?;
         if result.is_empty() {
             Err(Error::object_not_found())
         } else {
@@ -174,17 +218,77 @@ impl SQLConnector {
 #[async_trait]
 impl Connector for SQLConnector {
 
-    fn default_database_type(&self, field_type: &FieldType) -> DatabaseType {
+    fn default_database_type(&self, field_type: &FieldType) -> Result<DatabaseType> {
         field_type.to_database_type(self.dialect)
     }
 
-    async fn migrate(&mut self, models: &Vec<Model>, _reset_database: bool) -> Result<()> {
-        SQLMigration::migrate(self.dialect, &self.pool, models).await;
+    async fn migrate(&mut self, models: &Vec<Model>, reset_database: bool) -> Result<()> {
+        SQLMigration::create_database_if_needed(self.dialect, &self.url, reset_database).await?;
+        SQLMigration::migrate(self.dialect, &self.pool()?, models, self.foreign_keys).await
+    }
+
+    async fn migration_plan(&self, models: &Vec<Model>) -> Result<Vec<String>> {
+        let conn = self.migration_connection().await?;
+        let steps = SQLMigration::plan(self.dialect, &conn, models, self.foreign_keys).await?;
+        Ok(steps.iter().map(|step| step.to_string()).collect())
+    }
+
+    async fn migration_file_contents(&self, models: &Vec<Model>) -> Result<(String, String)> {
+        let conn = self.migration_connection().await?;
+        let steps = SQLMigration::plan(self.dialect, &conn, models, self.foreign_keys).await?;
+        Ok(migration_file_contents(&steps))
+    }
+
+    async fn applied_migration_versions(&self) -> Result<Vec<String>> {
+        let conn = self.migration_connection().await?;
+        SQLMigrationHistory::applied_versions(self.dialect, &conn).await
+    }
+
+    async fn apply_migration_file(&self, models: &Vec<Model>, version: &str, statements: &str) -> Result<()> {
+        let steps = migration_file_steps(models, statements)?;
+        let conn = self.migration_connection().await?;
+        SQLMigrationHistory::apply(self.dialect, &conn, version, steps).await
+    }
+
+    async fn revert_migration_file(&self, version: &str, statements: &str) -> Result<()> {
+        let conn = self.migration_connection().await?;
+        SQLMigrationHistory::revert(self.dialect, &conn, version, statements).await
+    }
+
+    async fn ping(&self) -> Result<()> {
+        let pool = match self.pool() {
+            Ok(pool) => pool,
+            Err(err) => return Err(Error::service_unavailable(err.message().to_owned())),
+        };
+        let conn = match pool.check_out().await {
+            Ok(conn) => conn,
+            Err(err) => return Err(Error::service_unavailable(format!("Cannot connect to database: {}", err))),
+        };
+        match conn.raw_cmd("SELECT 1").await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::service_unavailable(format!("Cannot query database: {}", err))),
+        }
+    }
+
+    async fn disconnect(&self) -> Result<()> {
+        // quaint can't close a pool explicitly, its connections are closed when the last handle
+        // of the pool is dropped
+        self.pool.lock().unwrap().take();
         Ok(())
     }
 
+    async fn revoke_token(&self, jti: &str, expires_at: i64) -> Result<()> {
+        let conn = self.queryable(None).await?;
+        SQLTokenRevocation::revoke(self.dialect, conn.as_ref(), jti, expires_at).await
+    }
+
+    async fn is_token_revoked(&self, jti: &str) -> Result<bool> {
+        let conn = self.queryable(None).await?;
+        SQLTokenRevocation::is_revoked(self.dialect, conn.as_ref(), jti).await
+    }
+
     async fn query_raw(&self, query: &Value) -> Result<Value> {
-        let conn = self.pool.check_out().await.unwrap();
+        let conn = check_out(&self.pool()?, self.enforces_sqlite_foreign_keys()).await?;
         let result = conn.query(QuaintQuery::from(query.as_str().unwrap())).await;
         if result.is_err() {
             let err = result.unwrap_err();
@@ -200,26 +304,31 @@ impl Connector for SQLConnector {
         }
     }
 
-    async fn save_object(&self, object: &Object, _session: Arc<dyn SaveSession>) -> Result<()> {
+    async fn save_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
         let is_new = object.inner.is_new.load(Ordering::SeqCst);
         if is_new {
-            self.create_object(object).await
+            self.create_object(object, session).await
         } else {
-            self.update_object(object).await
+            self.update_object(object, session).await
         }
     }
 
-    async fn delete_object(&self, object: &Object, _session: Arc<dyn SaveSession>) -> Result<()> {
-        let conn = self.pool.check_out().await.unwrap();
+    async fn delete_object(&self, object: &Object, session: Arc<dyn SaveSession>) -> Result<()> {
+        let conn = self.queryable(Some(session)).await?;
         if object.inner.is_new.load(Ordering::SeqCst) {
             return Err(Error::object_is_not_saved_thus_cant_be_deleted());
         }
         let model = object.model();
-        let r#where = Query::where_from_identifier(object, self.dialect);
+        let mut params = SQLParams::new(self.dialect);
+        let r#where = Query::where_from_identifier(object, self.dialect, &mut params);
         let stmt = SQL::delete_from(model.table_name()).r#where(r#where).to_string(self.dialect);
-        let result = conn.execute(QuaintQuery::from(stmt)).await;
+        let (stmt, params) = params.finalize(stmt)?;
+        let result = conn.execute_raw(&stmt, &params).await;
         if result.is_err() {
-            println!("{:?}", result.err().unwrap());
+            let err = result.err().unwrap();
+            if let ForeignKeyConstraintViolation { constraint } = err.kind() {
+                return Err(Error::deletion_denied(constraint.to_string()));
+            }
             return Err(Error::unknown_database_write_error());
         } else {
             Ok(())
@@ -227,7 +336,8 @@ impl Connector for SQLConnector {
     }
 
     async fn find_unique(&self, graph: &Graph, model: &Model, finder: &Value, _mutation_mode: bool, action: Action, action_source: ActionSource) -> Result<Object> {
-        let objects = Execution::query_objects(&self.pool, model, graph, finder, self.dialect, action, action_source.clone()).await?;
+        let conn = self.queryable(graph.session()).await?;
+        let objects = Execution::query_objects(conn.as_ref(), model, graph, finder, self.dialect, action, action_source.clone()).await?;
         if objects.is_empty() {
             Err(Error::object_not_found())
         } else {
@@ -236,25 +346,66 @@ impl Connector for SQLConnector {
     }
 
     async fn find_many(&self, graph: &Graph, model: &Model, finder: &Value, _mutation_mode: bool, action: Action, action_source: ActionSource) -> Result<Vec<Object>> {
-        Execution::query_objects(&self.pool, model, graph, finder, self.dialect, action, action_source).await
+        let conn = self.queryable(graph.session()).await?;
+        Execution::query_objects(conn.as_ref(), model, graph, finder, self.dialect, action, action_source).await
     }
 
     async fn count(&self, graph: &Graph, model: &Model, finder: &Value) -> Result<usize> {
-        match Execution::query_count(&self.pool, model, graph, finder, self.dialect).await {
+        let conn = self.queryable(graph.session()).await?;
+        match Execution::query_count(conn.as_ref(), model, graph, finder, self.dialect).await {
             Ok(c) => Ok(c as usize),
             Err(e) => Err(e),
         }
     }
 
     async fn aggregate(&self, graph: &Graph, model: &Model, finder: &Value) -> Result<Value> {
-        Execution::query_aggregate(&self.pool, model, graph, finder, self.dialect).await
+        let conn = self.queryable(graph.session()).await?;
+        Execution::query_aggregate(conn.as_ref(), model, graph, finder, self.dialect).await
     }
 
     async fn group_by(&self, graph: &Graph, model: &Model, finder: &Value) -> Result<Value> {
-        Execution::query_group_by(&self.pool, model, graph, finder, self.dialect).await
+        let conn = self.queryable(graph.session()).await?;
+        Execution::query_group_by(conn.as_ref(), model, graph, finder, self.dialect).await
     }
 
     fn new_save_session(&self) -> Arc<dyn SaveSession> {
-        Arc::new(SQLSaveSession { })
+        Arc::new(SQLSaveSession::new(self.pool(), self.enforces_sqlite_foreign_keys()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reconnects_after_disconnect() {
+        let path = std::env::temp_dir().join(format!("teo_sql_connector_reconnect_{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::fs::File::create(&path).unwrap();
+        let connector = SQLConnector::new(SQLDialect::SQLite, &format!("sqlite:{}", path.display()), false).await;
+        connector.query_raw(&Value::String("CREATE TABLE records (id INTEGER PRIMARY KEY)".to_owned())).await.unwrap();
+        connector.query_raw(&Value::String("INSERT INTO records (id) VALUES (1)".to_owned())).await.unwrap();
+        connector.disconnect().await.unwrap();
+        assert!(connector.pool.lock().unwrap().is_none());
+        connector.ping().await.unwrap();
+        let result = connector.query_raw(&Value::String("SELECT id FROM records".to_owned())).await.unwrap();
+        assert!(!result.is_null());
+        connector.disconnect().await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reopening_pool_fails_without_panic() {
+        let connector = SQLConnector {
+            dialect: SQLDialect::PostgreSQL,
+            url: "postgres://127.0.0.1:99999999/db".to_owned(),
+            connection_string: "postgres://127.0.0.1:99999999/db".to_owned(),
+            pool: Mutex::new(None),
+            foreign_keys: false,
+        };
+        assert!(connector.ping().await.is_err());
+        assert!(connector.query_raw(&Value::String("SELECT 1".to_owned())).await.is_err());
+        let session = connector.new_save_session();
+        assert!(SQLSaveSession::from_save_session(session.as_ref()).queryable().await.is_err());
     }
 }
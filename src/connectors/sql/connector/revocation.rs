@@ -0,0 +1,85 @@
+use chrono::Utc;
+use quaint_forked::prelude::{Queryable, Value as QuaintValue};
+use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::value::params::SQLParams;
+use crate::core::error::Error;
+use crate::core::result::Result;
+
+/// The table which records revoked auth tokens.
+pub(crate) const REVOKED_TOKENS_TABLE: &str = "_teo_revoked_tokens";
+
+pub(crate) struct SQLTokenRevocation { }
+
+impl SQLTokenRevocation {
+
+    /// Creates the table alongside migrations, requests expect it to exist.
+    pub(crate) async fn create_table_if_needed(dialect: SQLDialect, conn: &dyn Queryable) -> Result<()> {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let columns = format!("{escape}jti{escape_end} VARCHAR(191) NOT NULL PRIMARY KEY, {escape}expiresAt{escape_end} BIGINT NOT NULL");
+        let sql = if dialect == SQLDialect::MSSQL {
+            format!("IF OBJECT_ID(N'{REVOKED_TOKENS_TABLE}', N'U') IS NULL CREATE TABLE {escape}{REVOKED_TOKENS_TABLE}{escape_end}( {columns} )")
+        } else {
+            format!("CREATE TABLE IF NOT EXISTS {escape}{REVOKED_TOKENS_TABLE}{escape_end}( {columns} )")
+        };
+        match conn.raw_cmd(&sql).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot create revoked tokens table: {}", err))),
+        }
+    }
+
+    /// Records token `jti` as revoked and drops the records of tokens which have expired.
+    pub(crate) async fn revoke(dialect: SQLDialect, conn: &dyn Queryable, jti: &str, expires_at: i64) -> Result<()> {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let mut params = SQLParams::new(dialect);
+        let now_param = params.push(QuaintValue::int64(Utc::now().timestamp()));
+        let (sql, values) = params.finalize(format!("DELETE FROM {escape}{REVOKED_TOKENS_TABLE}{escape_end} WHERE {escape}expiresAt{escape_end} < {now_param}"))?;
+        if let Err(err) = conn.execute_raw(&sql, &values).await {
+            return Err(Error::invalid_operation(format!("Cannot purge revoked tokens: {}", err)));
+        }
+        let mut params = SQLParams::new(dialect);
+        let jti_param = params.push(QuaintValue::text(jti.to_owned()));
+        let expires_at_param = params.push(QuaintValue::int64(expires_at));
+        let table = format!("{escape}{REVOKED_TOKENS_TABLE}{escape_end}");
+        let insert = format!("{table}({escape}jti{escape_end}, {escape}expiresAt{escape_end}) VALUES ({jti_param}, {expires_at_param})");
+        // a token may be revoked more than once, e.g. by signing out after a refresh
+        let (sql, values) = params.finalize(match dialect {
+            SQLDialect::MySQL => format!("INSERT IGNORE INTO {insert}"),
+            SQLDialect::MSSQL => format!("IF NOT EXISTS (SELECT 1 FROM {table} WHERE {escape}jti{escape_end} = {jti_param}) INSERT INTO {insert}"),
+            _ => format!("INSERT INTO {insert} ON CONFLICT ({escape}jti{escape_end}) DO NOTHING"),
+        })?;
+        match conn.execute_raw(&sql, &values).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot revoke token: {}", err))),
+        }
+    }
+
+    pub(crate) async fn is_revoked(dialect: SQLDialect, conn: &dyn Queryable, jti: &str) -> Result<bool> {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let mut params = SQLParams::new(dialect);
+        let jti_param = params.push(QuaintValue::text(jti.to_owned()));
+        let (sql, values) = params.finalize(format!("SELECT {escape}jti{escape_end} FROM {escape}{REVOKED_TOKENS_TABLE}{escape_end} WHERE {escape}jti{escape_end} = {jti_param}"))?;
+        match conn.query_raw(&sql, &values).await {
+            Ok(result_set) => Ok(!result_set.is_empty()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot read revoked tokens: {}", err))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quaint_forked::single::Quaint;
+    use super::*;
+
+    #[tokio::test]
+    async fn revoking_twice_is_idempotent() {
+        let conn = Quaint::new_in_memory().unwrap();
+        let expires_at = Utc::now().timestamp() + 3600;
+        SQLTokenRevocation::create_table_if_needed(SQLDialect::SQLite, &conn).await.unwrap();
+        SQLTokenRevocation::revoke(SQLDialect::SQLite, &conn, "jti", expires_at).await.unwrap();
+        SQLTokenRevocation::revoke(SQLDialect::SQLite, &conn, "jti", expires_at).await.unwrap();
+        assert!(SQLTokenRevocation::is_revoked(SQLDialect::SQLite, &conn, "jti").await.unwrap());
+    }
+}
@@ -1,9 +1,99 @@
-use std::fmt::Debug;
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use async_trait::async_trait;
+use quaint_forked::prelude::Queryable;
+use quaint_forked::connector::{OwnedTransaction, start_owned_transaction};
+use quaint_forked::pooled::{PooledConnection, Quaint};
+use tokio::sync::Mutex;
 use crate::core::connector::SaveSession;
+use crate::core::error::Error;
+use crate::core::result::Result;
 
-#[derive(Debug)]
-pub struct SQLSaveSession { }
+pub struct SQLSaveSession {
+    pool: Result<Quaint>,
+    sqlite_foreign_keys: bool,
+    transaction: Mutex<Option<Arc<OwnedTransaction>>>,
+    finished: AtomicBool,
+}
+
+impl SQLSaveSession {
+
+    /// A pool which couldn't be opened fails the session when it's first used.
+    pub(crate) fn new(pool: Result<Quaint>, sqlite_foreign_keys: bool) -> Self {
+        Self { pool, sqlite_foreign_keys, transaction: Mutex::new(None), finished: AtomicBool::new(false) }
+    }
+
+    pub(crate) fn from_save_session(session: &dyn SaveSession) -> &SQLSaveSession {
+        session.as_any().downcast_ref::<SQLSaveSession>().unwrap()
+    }
+
+    /// Returns the transaction of this session, the transaction is started on first use. After
+    /// the session is finished, a plain connection is returned.
+    pub(crate) async fn queryable(&self) -> Result<Arc<dyn Queryable>> {
+        let pool = self.pool.as_ref().map_err(|err| err.clone())?;
+        if self.finished.load(Ordering::SeqCst) {
+            return Ok(Arc::new(check_out(pool, self.sqlite_foreign_keys).await?));
+        }
+        let mut transaction = self.transaction.lock().await;
+        if transaction.is_none() {
+            let conn = check_out(pool, self.sqlite_foreign_keys).await?;
+            match start_owned_transaction(Arc::new(conn), None).await {
+                Ok(owned_transaction) => *transaction = Some(Arc::new(owned_transaction)),
+                Err(err) => return Err(Error::internal_server_error(format!("Cannot start transaction: {}", err))),
+            }
+        }
+        Ok(transaction.as_ref().unwrap().clone())
+    }
+}
 
+/// Checks out a connection from the pool. With `sqlite_foreign_keys`, foreign key enforcement
+/// is turned on for the connection, this has to happen before a transaction is started.
+pub(crate) async fn check_out(pool: &Quaint, sqlite_foreign_keys: bool) -> Result<PooledConnection> {
+    let conn = match pool.check_out().await {
+        Ok(conn) => conn,
+        Err(err) => return Err(Error::internal_server_error(format!("Cannot connect to database: {}", err))),
+    };
+    if sqlite_foreign_keys {
+        if let Err(err) = conn.raw_cmd("PRAGMA foreign_keys = ON").await {
+            return Err(Error::internal_server_error(format!("Cannot enable foreign keys: {}", err)));
+        }
+    }
+    Ok(conn)
+}
+
+impl Debug for SQLSaveSession {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SQLSaveSession").field("finished", &self.finished).finish()
+    }
+}
+
+#[async_trait]
 impl SaveSession for SQLSaveSession {
 
+    async fn commit(&self) -> Result<()> {
+        self.finished.store(true, Ordering::SeqCst);
+        if let Some(transaction) = self.transaction.lock().await.take() {
+            if let Err(err) = transaction.commit().await {
+                let _ = transaction.rollback().await;
+                return Err(Error::internal_server_error(format!("Cannot commit transaction: {}", err)));
+            }
+        }
+        Ok(())
+    }
+
+    async fn abort(&self) -> Result<()> {
+        self.finished.store(true, Ordering::SeqCst);
+        if let Some(transaction) = self.transaction.lock().await.take() {
+            if let Err(err) = transaction.rollback().await {
+                return Err(Error::internal_server_error(format!("Cannot rollback transaction: {}", err)));
+            }
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -2,13 +2,12 @@ use std::borrow::Cow;
 use array_tool::vec::Uniq;
 use std::collections::HashMap;
 use async_recursion::async_recursion;
-use quaint_forked::pooled::Quaint;
 use quaint_forked::prelude::{Queryable, ResultRow};
-use quaint_forked::ast::{Query as QuaintQuery};
 use crate::connectors::sql::query::Query;
 use crate::connectors::sql::schema::dialect::SQLDialect;
 use crate::connectors::sql::schema::value::decode::RowDecoder;
-use crate::connectors::sql::schema::value::encode::{SQLEscape, ToSQLString, ToWrapped};
+use crate::connectors::sql::schema::value::encode::{SQLEscape, ToWrapped};
+use crate::connectors::sql::schema::value::params::SQLParams;
 use crate::core::action::Action;
 use crate::core::action::source::ActionSource;
 use crate::core::error::Error;
@@ -23,17 +22,17 @@ pub(crate) struct Execution { }
 
 impl Execution {
 
-    pub(crate) fn row_to_value(model: &Model, graph: &Graph, row: &ResultRow, columns: &Vec<String>, dialect: SQLDialect) -> Value {
+    pub(crate) fn row_to_value(model: &Model, graph: &Graph, row: &ResultRow, columns: &Vec<String>, dialect: SQLDialect) -> Result<Value> {
 
-        Value::HashMap(columns.iter().filter_map(|column_name| {
+        Ok(Value::HashMap(columns.iter().filter_map(|column_name| {
             if let Some(field) = model.field_with_column_name(column_name) {
                 if field.auto_increment && dialect == SQLDialect::PostgreSQL {
-                    Some((field.name().to_owned(), RowDecoder::decode_serial(field.is_optional(), row, column_name)))
+                    Some(Ok((field.name().to_owned(), RowDecoder::decode_serial(field.is_optional(), row, column_name))))
                 } else {
-                    Some((field.name().to_owned(), RowDecoder::decode(field.field_type(), field.is_optional(), row, column_name, dialect)))
+                    Some(RowDecoder::decode(field.field_type(), field.is_optional(), row, column_name, dialect).map(|v| (field.name().to_owned(), v)))
                 }
             } else if let Some(property) = model.property(column_name) {
-                Some((property.name().to_owned(), RowDecoder::decode(property.field_type(), property.is_optional(), row, column_name, dialect)))
+                Some(RowDecoder::decode(property.field_type(), property.is_optional(), row, column_name, dialect).map(|v| (property.name().to_owned(), v)))
             } else if column_name.contains(".") {
                 let names: Vec<&str> = column_name.split(".").collect();
                 let relation_name = names[0];
@@ -43,15 +42,15 @@ impl Execution {
                 } else {
                     let opposite_model = graph.model(model.relation(relation_name).unwrap().model()).unwrap();
                     let field = opposite_model.field(field_name).unwrap();
-                    Some((column_name.to_owned(), RowDecoder::decode(field.field_type(), field.is_optional(), row, column_name, dialect)))
+                    Some(RowDecoder::decode(field.field_type(), field.is_optional(), row, column_name, dialect).map(|v| (column_name.to_owned(), v)))
                 }
             } else {
                 panic!("Unhandled key {}.", column_name);
             }
-        }).collect())
+        }).collect::<Result<HashMap<String, Value>>>()?))
     }
 
-    fn row_to_aggregate_value(model: &Model, _graph: &Graph, row: &ResultRow, columns: &Vec<String>, dialect: SQLDialect) -> Value {
+    fn row_to_aggregate_value(model: &Model, _graph: &Graph, row: &ResultRow, columns: &Vec<String>, dialect: SQLDialect) -> Result<Value> {
         let mut retval: HashMap<String, Value> = HashMap::new();
         for column in columns {
             let result_key = column.as_str();
@@ -66,24 +65,24 @@ impl Execution {
                     let count: i64 = row.get(result_key).unwrap().as_i64().unwrap();
                     retval.get_mut(group).unwrap().as_hashmap_mut().unwrap().insert(field_name.to_string(), teon!(count));
                 } else if group == "_avg" || group == "_sum" { // force f64
-                    let v = RowDecoder::decode(&FieldType::F64, true, &row, result_key, dialect);
+                    let v = RowDecoder::decode(&FieldType::F64, true, row, result_key, dialect)?;
                     retval.get_mut(group).unwrap().as_hashmap_mut().unwrap().insert(field_name.to_string(), v);
                 } else { // field type
                     let field = model.field(field_name).unwrap();
-                    let v = RowDecoder::decode(field.field_type(), true, &row, result_key, dialect);
+                    let v = RowDecoder::decode(field.field_type(), true, row, result_key, dialect)?;
                     retval.get_mut(group).unwrap().as_hashmap_mut().unwrap().insert(field_name.to_string(), v);
                 }
             } else if let Some(field) = model.field_with_column_name(result_key) {
-                retval.insert(field.name().to_owned(), RowDecoder::decode(field.field_type(), field.is_optional(), row, result_key, dialect));
+                retval.insert(field.name().to_owned(), RowDecoder::decode(field.field_type(), field.is_optional(), row, result_key, dialect)?);
             } else if let Some(property) = model.property(result_key) {
-                retval.insert(property.name().to_owned(), RowDecoder::decode(property.field_type(), property.is_optional(), row, result_key, dialect));
+                retval.insert(property.name().to_owned(), RowDecoder::decode(property.field_type(), property.is_optional(), row, result_key, dialect)?);
             }
         }
-        Value::HashMap(retval)
+        Ok(Value::HashMap(retval))
     }
 
-    pub(crate) async fn query_objects(pool: &Quaint, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect, action: Action, action_source: ActionSource) -> Result<Vec<Object>> {
-        let values = Self::query(pool, model, graph, finder, dialect).await?;
+    pub(crate) async fn query_objects(conn: &dyn Queryable, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect, action: Action, action_source: ActionSource) -> Result<Vec<Object>> {
+        let values = Self::query(conn, model, graph, finder, dialect).await?;
         let select = finder.as_hashmap().unwrap().get("select");
         let include = finder.as_hashmap().unwrap().get("include");
         let mut results = vec![];
@@ -96,8 +95,7 @@ impl Execution {
     }
 
     #[async_recursion]
-    async fn query_internal(pool: &Quaint, model: &Model, graph: &Graph, value: &Value, dialect: SQLDialect, additional_where: Option<String>, additional_left_join: Option<String>, join_table_results: Option<Vec<String>>, force_negative_take: bool, additional_distinct: Option<Vec<String>>) -> Result<Vec<Value>> {
-        let conn = pool.check_out().await.unwrap();
+    async fn query_internal(conn: &dyn Queryable, model: &Model, graph: &Graph, value: &Value, dialect: SQLDialect, additional_where: Option<&(dyn Fn(&mut SQLParams) -> String + Sync)>, additional_left_join: Option<String>, join_table_results: Option<Vec<String>>, force_negative_take: bool, additional_distinct: Option<Vec<String>>) -> Result<Vec<Value>> {
         let _select = value.get("select");
         let include = value.get("include");
         let original_distinct = value.get("distinct").map(|v| if v.as_vec().unwrap().is_empty() { None } else { Some(v.as_vec().unwrap()) }).flatten();
@@ -110,10 +108,12 @@ impl Execution {
         } else {
             Cow::Borrowed(value)
         };
-        let stmt = Query::build(model, graph, value_for_build.as_ref(), dialect, additional_where, additional_left_join, join_table_results, force_negative_take);
+        let mut params = SQLParams::new(dialect);
+        let stmt = Query::build(model, graph, value_for_build.as_ref(), dialect, additional_where, additional_left_join, join_table_results, force_negative_take, &mut params);
+        let (stmt, params) = params.finalize(stmt)?;
         // println!("sql stmt: {}", &stmt);
         let reverse = Input::has_negative_take(value);
-        let rows = match conn.query(QuaintQuery::from(stmt)).await {
+        let rows = match conn.query_raw(&stmt, &params).await {
             Ok(rows) => rows,
             Err(err) => {
                 println!("{:?}", err);
@@ -124,7 +124,7 @@ impl Execution {
             return Ok(vec![])
         }
         let columns = rows.columns().clone();
-        let mut results = rows.into_iter().map(|row| Self::row_to_value(model, graph, &row, &columns, dialect)).collect::<Vec<Value>>();
+        let mut results = rows.into_iter().map(|row| Self::row_to_value(model, graph, &row, &columns, dialect)).collect::<Result<Vec<Value>>>()?;
         if reverse {
             results.reverse();
         }
@@ -162,25 +162,28 @@ impl Execution {
                     } else {
                         opposite_fields.iter().map(|f| opposite_model.field(f).unwrap().column_name().escape(dialect)).collect::<Vec<String>>().join(",").to_wrapped()
                     };
-                    let values = if opposite_fields.len() == 1 {
-                        // in a (?,?,?,?,?) format
-                        let field_name = fields.get(0).unwrap();
-                        results.iter().map(|v| {
-                            v.as_hashmap().unwrap().get(field_name).unwrap().to_string(dialect)
-                        }).collect::<Vec<String>>().join(",").to_wrapped()
-                    } else {
-                        // in a (VALUES (?,?),(?,?)) format
-                        format!("(VALUES {})", results.iter().map(|o| {
-                            fields.iter().map(|f| o.as_hashmap().unwrap().get(f).unwrap().to_string(dialect)).collect::<Vec<String>>().join(",").to_wrapped()
-                        }).collect::<Vec<String>>().join(","))
+                    let where_addition = |params: &mut SQLParams| {
+                        let values = if opposite_fields.len() == 1 {
+                            // in a (?,?,?,?,?) format
+                            let field_name = fields.first().unwrap();
+                            let field_type = model.field(field_name).unwrap().field_type();
+                            results.iter().map(|v| {
+                                params.bind(v.as_hashmap().unwrap().get(field_name).unwrap(), field_type)
+                            }).collect::<Vec<String>>().join(",").to_wrapped()
+                        } else {
+                            // in a (VALUES (?,?),(?,?)) format
+                            format!("(VALUES {})", results.iter().map(|o| {
+                                fields.iter().map(|f| params.bind(o.as_hashmap().unwrap().get(f).unwrap(), model.field(f).unwrap().field_type())).collect::<Vec<String>>().join(",").to_wrapped()
+                            }).collect::<Vec<String>>().join(","))
+                        };
+                        Query::where_item(&names, "IN", &values)
                     };
-                    let where_addition = Query::where_item(&names, "IN", &values);
                     let nested_query = if value.is_hashmap() {
                         Self::without_paging_and_skip_take_distinct(value)
                     } else {
                         Cow::Owned(teon!({}))
                     };
-                    let included_values = Self::query_internal(pool, opposite_model, graph, &nested_query, dialect, Some(where_addition), None, None, negative_take, None).await?;
+                    let included_values = Self::query_internal(conn, opposite_model, graph, &nested_query, dialect, Some(&where_addition), None, None, negative_take, None).await?;
                     // println!("see included: {:?}", included_values);
                     for result in results.iter_mut() {
                         let mut skipped = 0;
@@ -237,18 +240,21 @@ impl Execution {
                     } else {
                         through_relation.fields().iter().map(|f| format!("j.{}", through_table.field(f).unwrap().column_name().escape(dialect))).collect::<Vec<String>>().join(",").to_wrapped()
                     };
-                    let values = if through_relation.len() == 1 { // (?,?,?,?,?) format
-                        let field_name = through_relation.references().get(0).unwrap();
-                        results.iter().map(|v| {
-                            v.as_hashmap().unwrap().get(field_name).unwrap().to_string(dialect)
-                        }).collect::<Vec<String>>().join(",").to_wrapped()
-                    } else { // (VALUES (?,?),(?,?)) format
-                        let pairs = results.iter().map(|o| {
-                            through_relation.references().iter().map(|f| o.as_hashmap().unwrap().get(f).unwrap().to_string(dialect)).collect::<Vec<String>>().join(",").to_wrapped()
-                        }).collect::<Vec<String>>().join(",");
-                        format!("(VALUES {})", pairs)
+                    let where_addition = |params: &mut SQLParams| {
+                        let values = if through_relation.len() == 1 { // (?,?,?,?,?) format
+                            let field_name = through_relation.references().first().unwrap();
+                            let field_type = model.field(field_name).unwrap().field_type();
+                            results.iter().map(|v| {
+                                params.bind(v.as_hashmap().unwrap().get(field_name).unwrap(), field_type)
+                            }).collect::<Vec<String>>().join(",").to_wrapped()
+                        } else { // (VALUES (?,?),(?,?)) format
+                            let pairs = results.iter().map(|o| {
+                                through_relation.references().iter().map(|f| params.bind(o.as_hashmap().unwrap().get(f).unwrap(), model.field(f).unwrap().field_type())).collect::<Vec<String>>().join(",").to_wrapped()
+                            }).collect::<Vec<String>>().join(",");
+                            format!("(VALUES {})", pairs)
+                        };
+                        Query::where_item(&names, "IN", &values)
                     };
-                    let where_addition = Query::where_item(&names, "IN", &values);
                     let nested_query = if value.is_hashmap() {
                         Self::without_paging_and_skip_take(value)
                     } else {
@@ -256,8 +262,8 @@ impl Execution {
                     };
                     let join_table_results = through_relation.iter().map(|(f, r)| {
                         let through_column_name = through_model.field(f).unwrap().column_name().to_string();
-                        if dialect == SQLDialect::PostgreSQL {
-                            format!("j.{} AS \"{}.{}\"", through_column_name.as_str().escape(dialect), opposite_relation.unwrap().name(), r)
+                        if dialect == SQLDialect::PostgreSQL || dialect == SQLDialect::MSSQL {
+                            format!("j.{} AS {}", through_column_name.as_str().escape(dialect), format!("{}.{}", opposite_relation.unwrap().name(), r).as_str().escape(dialect))
                         } else {
                             format!("j.{} AS `{}.{}`", through_column_name, opposite_relation.unwrap().name(), r)
                         }
@@ -269,7 +275,7 @@ impl Execution {
                     } else {
                         None
                     };
-                    let included_values = Self::query_internal(pool, opposite_model, graph, &nested_query, dialect, Some(where_addition), Some(left_join), Some(join_table_results), negative_take, additional_inner_distinct).await?;
+                    let included_values = Self::query_internal(conn, opposite_model, graph, &nested_query, dialect, Some(&where_addition), Some(left_join), Some(join_table_results), negative_take, additional_inner_distinct).await?;
                     // println!("see included {:?}", included_values);
                     for result in results.iter_mut() {
                         let mut skipped = 0;
@@ -313,18 +319,19 @@ impl Execution {
         Ok(results)
     }
 
-    pub(crate) async fn query(pool: &Quaint, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Vec<Value>> {
-       Self::query_internal(pool, model, graph, finder, dialect, None, None, None, false, None).await
+    pub(crate) async fn query(conn: &dyn Queryable, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Vec<Value>> {
+       Self::query_internal(conn, model, graph, finder, dialect, None, None, None, false, None).await
     }
 
-    pub(crate) async fn query_aggregate(pool: &Quaint, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Value> {
-        let conn = pool.check_out().await.unwrap();
-        let stmt = Query::build_for_aggregate(model, graph, finder, dialect);
-        match conn.query(QuaintQuery::from(&*stmt)).await {
+    pub(crate) async fn query_aggregate(conn: &dyn Queryable, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Value> {
+        let mut params = SQLParams::new(dialect);
+        let stmt = Query::build_for_aggregate(model, graph, finder, dialect, &mut params);
+        let (stmt, params) = params.finalize(stmt)?;
+        match conn.query_raw(&stmt, &params).await {
             Ok(result_set) => {
                 let columns = result_set.columns().clone();
                 let result = result_set.into_iter().next().unwrap();
-                Ok(Self::row_to_aggregate_value(model, graph, &result, &columns, dialect))
+                Self::row_to_aggregate_value(model, graph, &result, &columns, dialect)
             },
             Err(err) => {
                 println!("{:?}", err);
@@ -333,10 +340,11 @@ impl Execution {
         }
     }
 
-    pub(crate) async fn query_group_by(pool: &Quaint, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Value> {
-        let conn = pool.check_out().await.unwrap();
-        let stmt = Query::build_for_group_by(model, graph, finder, dialect);
-        let rows = match conn.query(QuaintQuery::from(stmt)).await {
+    pub(crate) async fn query_group_by(conn: &dyn Queryable, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<Value> {
+        let mut params = SQLParams::new(dialect);
+        let stmt = Query::build_for_group_by(model, graph, finder, dialect, &mut params);
+        let (stmt, params) = params.finalize(stmt)?;
+        let rows = match conn.query_raw(&stmt, &params).await {
             Ok(rows) => rows,
             Err(err) => {
                 println!("{:?}", err);
@@ -346,13 +354,14 @@ impl Execution {
         let columns = rows.columns().clone();
         Ok(Value::Vec(rows.into_iter().map(|r| {
             Self::row_to_aggregate_value(model, graph, &r, &columns, dialect)
-        }).collect::<Vec<Value>>()))
+        }).collect::<Result<Vec<Value>>>()?))
     }
 
-    pub(crate) async fn query_count(pool: &Quaint, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<u64> {
-        let conn = pool.check_out().await.unwrap();
-        let stmt = Query::build_for_count(model, graph, finder, dialect, None, None, None, false);
-        match conn.query(QuaintQuery::from(stmt)).await {
+    pub(crate) async fn query_count(conn: &dyn Queryable, model: &Model, graph: &Graph, finder: &Value, dialect: SQLDialect) -> Result<u64> {
+        let mut params = SQLParams::new(dialect);
+        let stmt = Query::build_for_count(model, graph, finder, dialect, None, None, None, false, &mut params);
+        let (stmt, params) = params.finalize(stmt)?;
+        match conn.query_raw(&stmt, &params).await {
             Ok(result) => {
                 let result = result.into_iter().next().unwrap();
                 let count: i64 = result.into_iter().next().unwrap().as_i64().unwrap();
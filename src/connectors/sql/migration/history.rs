@@ -0,0 +1,111 @@
+use chrono::Utc;
+use quaint_forked::connector::TransactionCapable;
+use quaint_forked::pooled::PooledConnection;
+use quaint_forked::prelude::{Queryable, Value as QuaintValue};
+use quaint_forked::ast::Query;
+use crate::connectors::sql::connector::revocation::SQLTokenRevocation;
+use crate::connectors::sql::migration::plan::SQLMigrationStep;
+use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::value::params::SQLParams;
+use crate::core::error::Error;
+use crate::core::pipeline::ctx::Ctx;
+use crate::core::result::Result;
+use crate::prelude::Value;
+
+/// The table which records applied migration files.
+pub(crate) const MIGRATION_HISTORY_TABLE: &str = "_teo_migrations";
+
+pub(crate) struct SQLMigrationHistory { }
+
+impl SQLMigrationHistory {
+
+    async fn create_table_if_needed(dialect: SQLDialect, conn: &PooledConnection) -> Result<()> {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let columns = format!("{escape}version{escape_end} VARCHAR(191) NOT NULL PRIMARY KEY, {escape}appliedAt{escape_end} VARCHAR(191) NOT NULL");
+        let sql = if dialect == SQLDialect::MSSQL {
+            format!("IF OBJECT_ID(N'{MIGRATION_HISTORY_TABLE}', N'U') IS NULL CREATE TABLE {escape}{MIGRATION_HISTORY_TABLE}{escape_end}( {columns} )")
+        } else {
+            format!("CREATE TABLE IF NOT EXISTS {escape}{MIGRATION_HISTORY_TABLE}{escape_end}( {columns} )")
+        };
+        match conn.raw_cmd(&sql).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot create migration history table: {}", err))),
+        }
+    }
+
+    /// Returns the versions of applied migration files in applying order.
+    pub(crate) async fn applied_versions(dialect: SQLDialect, conn: &PooledConnection) -> Result<Vec<String>> {
+        Self::create_table_if_needed(dialect, conn).await?;
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let sql = format!("SELECT {escape}version{escape_end} FROM {escape}{MIGRATION_HISTORY_TABLE}{escape_end} ORDER BY {escape}version{escape_end} ASC");
+        match conn.query(Query::from(sql)).await {
+            Ok(result_set) => Ok(result_set.into_iter().map(|row| row.get("version").unwrap().as_str().unwrap().to_owned()).collect()),
+            Err(err) => Err(Error::invalid_operation(format!("Cannot read migration history: {}", err))),
+        }
+    }
+
+    /// Runs the steps of migration file `version` and records it as applied.
+    pub(crate) async fn apply(dialect: SQLDialect, conn: &PooledConnection, version: &str, steps: Vec<SQLMigrationStep>) -> Result<()> {
+        Self::create_table_if_needed(dialect, conn).await?;
+        SQLTokenRevocation::create_table_if_needed(dialect, conn).await?;
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let mut params = SQLParams::new(dialect);
+        let version_param = params.push(QuaintValue::text(version.to_owned()));
+        let applied_at_param = params.push(QuaintValue::text(Utc::now().to_rfc3339()));
+        let record = format!("INSERT INTO {escape}{MIGRATION_HISTORY_TABLE}{escape_end}({escape}version{escape_end}, {escape}appliedAt{escape_end}) VALUES ({version_param}, {applied_at_param})");
+        Self::run_in_transaction(dialect, conn, version, steps, params.finalize(record)?).await
+    }
+
+    /// Runs the statements which revert migration file `version` and removes it from history.
+    pub(crate) async fn revert(dialect: SQLDialect, conn: &PooledConnection, version: &str, statements: &str) -> Result<()> {
+        Self::create_table_if_needed(dialect, conn).await?;
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let mut params = SQLParams::new(dialect);
+        let version_param = params.push(QuaintValue::text(version.to_owned()));
+        let record = format!("DELETE FROM {escape}{MIGRATION_HISTORY_TABLE}{escape_end} WHERE {escape}version{escape_end} = {version_param}");
+        Self::run_in_transaction(dialect, conn, version, vec![SQLMigrationStep::statement("", statements)], params.finalize(record)?).await
+    }
+
+    /// Runs the steps and the history record in one transaction. MySQL commits each schema
+    /// statement implicitly, so on MySQL only the history record and data changes are rolled
+    /// back when a step fails, schema statements before the failing one stay applied.
+    async fn run_in_transaction(dialect: SQLDialect, conn: &PooledConnection, version: &str, steps: Vec<SQLMigrationStep>, record: (String, Vec<QuaintValue<'static>>)) -> Result<()> {
+        let (record, params) = record;
+        let transaction = match conn.start_transaction(None).await {
+            Ok(transaction) => transaction,
+            Err(err) => return Err(Error::invalid_operation(format!("Cannot start transaction for migration '{}': {}", version, err))),
+        };
+        let mut result = Ok(());
+        for step in steps {
+            result = match step {
+                SQLMigrationStep::Statement { sql, .. } => transaction.raw_cmd(&sql).await.map_err(|err| err.to_string()),
+                SQLMigrationStep::Action { column, action, .. } => match action.process(Ctx::initial_state_with_value(Value::Null)).await {
+                    Ok(_) => Ok(()),
+                    Err(err) => Err(format!("migration action of '{}' failed: {}", column, err.message())),
+                },
+            };
+            if result.is_err() { break }
+        }
+        if result.is_ok() {
+            result = transaction.execute_raw(&record, &params).await.map(|_| ()).map_err(|err| err.to_string());
+        }
+        match result {
+            Ok(_) => match transaction.commit().await {
+                Ok(_) => Ok(()),
+                Err(err) => Err(Error::invalid_operation(format!("Cannot commit migration '{}': {}", version, err))),
+            },
+            Err(err) => {
+                let _ = transaction.rollback().await;
+                if dialect == SQLDialect::MySQL {
+                    Err(Error::invalid_operation(format!("Migration '{}' failed: {}. MySQL doesn't roll back schema changes, statements before the failing one are applied.", version, err)))
+                } else {
+                    Err(Error::invalid_operation(format!("Migration '{}' failed: {}", version, err)))
+                }
+            }
+        }
+    }
+}
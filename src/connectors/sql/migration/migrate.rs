@@ -3,20 +3,26 @@ use std::fs;
 use itertools::Itertools;
 use maplit::hashset;
 use quaint_forked::pooled::{PooledConnection, Quaint};
-use quaint_forked::prelude::Queryable;
+use quaint_forked::prelude::{Queryable, ResultSet};
 use quaint_forked::ast::Query;
-use crate::connectors::sql::migration::sql::{sqlite_auto_increment_query, sqlite_list_indices_query};
+use crate::connectors::sql::migration::history::MIGRATION_HISTORY_TABLE;
+use crate::connectors::sql::connector::revocation::{REVOKED_TOKENS_TABLE, SQLTokenRevocation};
+use crate::connectors::sql::migration::plan::SQLMigrationStep;
+use crate::connectors::sql::migration::sql::{mssql_columns_query, mssql_indices_query, mssql_primary_columns_query, sqlite_auto_increment_query, sqlite_list_indices_query};
 use super::super::url::url_utils;
 use crate::connectors::sql::schema::column::decoder::{ColumnDecoder, ColumnManipulation};
 use crate::connectors::sql::stmts::create::table::SQLCreateTableStatement;
 use crate::connectors::sql::stmts::SQL;
 use crate::connectors::sql::schema::column::SQLColumn;
 use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::foreign_key::{SQLForeignKey, SQLReferentialAction};
 use crate::core::model::Model;
 use crate::connectors::sql::schema::value::encode::ToSQLString;
 use crate::core::field::Sort;
 use crate::core::model::index::{ModelIndex, ModelIndexItem, ModelIndexType};
+use crate::core::error::Error;
 use crate::core::pipeline::ctx::Ctx;
+use crate::core::result::Result;
 use crate::prelude::Value;
 
 pub(crate) struct SQLMigration { }
@@ -25,17 +31,17 @@ impl SQLMigration {
 
     // Create database
 
-    pub(crate) async fn create_database_if_needed(dialect: SQLDialect, url: &str, reset: bool) {
+    pub(crate) async fn create_database_if_needed(dialect: SQLDialect, url: &str, reset: bool) -> Result<()> {
         match dialect {
             SQLDialect::SQLite => Self::create_sqlite_database_if_needed(url, reset).await,
             _ => Self::create_server_database_if_needed(dialect, url, reset).await,
         }
     }
 
-    pub(crate) async fn create_sqlite_database_if_needed(url: &str, reset: bool) {
+    pub(crate) async fn create_sqlite_database_if_needed(url: &str, reset: bool) -> Result<()> {
         let url = url_utils::remove_scheme(url);
         if url_utils::is_memory_url(url) {
-            return
+            return Ok(())
         }
         let absolutized_url = url_utils::absolutized(url);
         if absolutized_url.exists() && reset {
@@ -44,121 +50,191 @@ impl SQLMigration {
         }
         if !absolutized_url.exists() || reset {
             // create a new one
-            fs::File::create(absolutized_url).expect("SQLite database file create failed.");
+            if let Err(err) = fs::File::create(&absolutized_url) {
+                return Err(Error::migration_error(format!("Cannot create SQLite database file '{}': {}", absolutized_url.display(), err)));
+            }
         }
+        Ok(())
     }
 
-    pub(crate) async fn create_server_database_if_needed(dialect: SQLDialect, url: &str, reset: bool) {
+    pub(crate) async fn create_server_database_if_needed(dialect: SQLDialect, url: &str, reset: bool) -> Result<()> {
         let url = url_utils::normalized_url(dialect, url);
         let db_name = &url.path()[1..];
         let url_without_db = url_utils::remove_db_path(dialect, &url);
-        let pool = Quaint::builder(url_without_db.as_str()).unwrap().build();
-        let conn = pool.check_out().await.unwrap();
+        let pool = match Quaint::builder(&url_utils::connection_string(dialect, &url_without_db)) {
+            Ok(builder) => builder.build(),
+            Err(err) => return Err(Error::migration_error(format!("Invalid database url: {}", err))),
+        };
+        let conn = match pool.check_out().await {
+            Ok(conn) => conn,
+            Err(err) => return Err(Error::migration_error(format!("Cannot connect to database server: {}", err))),
+        };
         // drop database if needed
         if reset {
             let stmt = SQL::drop().database(db_name).if_exists().to_string(dialect);
-            conn.execute(Query::from(stmt)).await.unwrap();
+            Self::execute_database_statement(&conn, db_name, &stmt).await?;
         }
         // create database if needed
         if dialect == SQLDialect::PostgreSQL {
             let stmt = format!("select from pg_database where datname = '{}'", db_name);
-            let result = conn.query(Query::from(stmt)).await.unwrap();
+            let result = match conn.query(Query::from(stmt.as_str())).await {
+                Ok(result) => result,
+                Err(err) => return Err(Error::migration_error(format!("Cannot create database `{}': {} SQL: {}", db_name, err, stmt))),
+            };
             if result.is_empty() {
                 let stmt = SQL::create().database(db_name).to_string(dialect);
-                conn.execute(Query::from(stmt)).await.unwrap();
+                Self::execute_database_statement(&conn, db_name, &stmt).await?;
             }
         } else {
             let stmt = SQL::create().database(db_name).if_not_exists().to_string(dialect);
-            conn.execute(Query::from(stmt)).await.unwrap();
+            Self::execute_database_statement(&conn, db_name, &stmt).await?;
         }
         // use database
         if dialect == SQLDialect::PostgreSQL {
             let stmt = format!("SET search_path TO {db_name}");
-            conn.execute(Query::from(stmt)).await.unwrap();
+            Self::execute_database_statement(&conn, db_name, &stmt).await
         } else {
             let stmt = SQL::r#use().database(db_name).to_string(dialect);
-            conn.raw_cmd(&stmt).await.unwrap();
+            Self::execute_database_statement(&conn, db_name, &stmt).await
+        }
+    }
+
+    async fn execute_database_statement(conn: &PooledConnection, db_name: &str, stmt: &str) -> Result<()> {
+        match conn.raw_cmd(stmt).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::migration_error(format!("Cannot create database `{}': {} SQL: {}", db_name, err, stmt))),
         }
     }
 
     // Migrate
 
-    pub(crate) async fn db_columns(conn: &PooledConnection, dialect: SQLDialect, table_name: &str) -> HashSet<SQLColumn> {
+    /// Runs a query which reads `table`, failing with an error which names the table and the
+    /// query.
+    pub(crate) async fn query(conn: &PooledConnection, table: &str, sql: impl Into<String>) -> Result<ResultSet> {
+        let sql = sql.into();
+        match conn.query(Query::from(sql.as_str())).await {
+            Ok(result_set) => Ok(result_set),
+            Err(err) => Err(Error::migration_table_error(table, None, Some(&sql), err.to_string())),
+        }
+    }
+
+    pub(crate) async fn db_columns(conn: &PooledConnection, dialect: SQLDialect, table_name: &str) -> Result<HashSet<SQLColumn>> {
         match dialect {
             SQLDialect::SQLite => {
-                let columns_result = conn.query(Query::from(format!("pragma table_info('{}')", table_name))).await.unwrap();
-                let indices_result = conn.query(Query::from(sqlite_list_indices_query(table_name))).await.unwrap();
-                let auto_increment_result = conn.query(Query::from(sqlite_auto_increment_query(table_name))).await.unwrap();
+                let columns_result = Self::query(conn, table_name, format!("pragma table_info('{}')", table_name)).await?;
+                let indices_result = Self::query(conn, table_name, sqlite_list_indices_query(table_name)).await?;
+                let auto_increment_result = Self::query(conn, table_name, sqlite_auto_increment_query(table_name)).await?;
                 let db_columns = ColumnDecoder::decode_sqlite_columns(columns_result, indices_result, auto_increment_result);
-                db_columns
+                Ok(db_columns)
+            }
+            SQLDialect::MSSQL => {
+                let columns_result = Self::query(conn, table_name, mssql_columns_query(table_name)).await?;
+                let primary_result = Self::query(conn, table_name, mssql_primary_columns_query(table_name)).await?;
+                let db_columns = ColumnDecoder::decode_mssql_columns(columns_result, primary_result);
+                Ok(db_columns)
             }
             _ => {
                 let mut results = hashset! {};
-                let db_table_columns = conn.query(if dialect == SQLDialect::PostgreSQL {
-                    let desc = format!("SELECT * FROM information_schema.columns where table_name = '{}'", table_name);
-                    Query::from(desc)
+                let db_table_columns = Self::query(conn, table_name, if dialect == SQLDialect::PostgreSQL {
+                    format!("SELECT * FROM information_schema.columns where table_name = '{}'", table_name)
                 } else {
-                    let desc = SQL::describe(table_name).to_string(dialect);
-                    Query::from(desc)
-                }).await.unwrap();
+                    SQL::describe(table_name).to_string(dialect)
+                }).await?;
                 for db_table_column in db_table_columns {
-                    let db_column = ColumnDecoder::decode(db_table_column, dialect, conn, table_name).await;
+                    let db_column = ColumnDecoder::decode(db_table_column, dialect, conn, table_name).await?;
                     results.insert(db_column);
                 }
-                results
+                Ok(results)
             }
         }
     }
 
-    pub(crate) async fn get_db_user_tables(dialect: SQLDialect, conn: &PooledConnection) -> Vec<String> {
-        match dialect {
-            SQLDialect::MySQL => {
-                let sql = "SHOW TABLES";
-                let db_result = conn.query(Query::from(sql)).await.unwrap();
-                db_result.into_iter().map(|result| { result.into_single().unwrap().to_string().unwrap() }).collect()
-            }
-            SQLDialect::PostgreSQL => {
-                let sql = "SELECT tablename FROM pg_catalog.pg_tables WHERE schemaname != 'pg_catalog' AND schemaname != 'information_schema'";
-                let db_result = conn.query(Query::from(sql)).await.unwrap();
-                db_result.into_iter().map(|result| { result.into_single().unwrap().to_string().unwrap() }).collect()
+    pub(crate) async fn get_db_user_tables(dialect: SQLDialect, conn: &PooledConnection) -> Result<Vec<String>> {
+        let sql = match dialect {
+            SQLDialect::MySQL => "SHOW TABLES",
+            SQLDialect::PostgreSQL => "SELECT tablename FROM pg_catalog.pg_tables WHERE schemaname != 'pg_catalog' AND schemaname != 'information_schema'",
+            SQLDialect::SQLite => "SELECT name FROM sqlite_master WHERE type in ('table') AND name not like 'sqlite?_%' escape '?'",
+            SQLDialect::MSSQL => "SELECT TABLE_NAME FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_TYPE = 'BASE TABLE' AND TABLE_CATALOG = DB_NAME()",
+        };
+        let db_result = match conn.query(Query::from(sql)).await {
+            Ok(db_result) => db_result,
+            Err(err) => return Err(Error::migration_error(format!("Cannot read database tables: {} SQL: {}", err, sql))),
+        };
+        db_result.into_iter().map(|result| {
+            match result.into_single().ok().and_then(|value| value.to_string()) {
+                Some(name) => Ok(name),
+                None => Err(Error::migration_error(format!("Cannot read database tables: unexpected table name. SQL: {}", sql))),
             }
-            SQLDialect::SQLite => {
-                let sql = "SELECT name FROM sqlite_master WHERE type in ('table') AND name not like 'sqlite?_%' escape '?'";
-                let db_result = conn.query(Query::from(sql)).await.unwrap();
-                db_result.into_iter().map(|result| { result.into_single().unwrap().to_string().unwrap() }).collect()
-            }
-            _ => unreachable!()
-        }
+        }).collect()
     }
 
-    pub(crate) async fn rename_table(dialect: SQLDialect, conn: &PooledConnection, old_name: &str, new_name: &str) {
+    pub(crate) async fn table_has_records(dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> Result<bool> {
         let escape = dialect.escape();
-        let sql = format!("ALTER TABLE {escape}{old_name}{escape} RENAME TO {escape}{new_name}{escape}");
-        conn.execute(Query::from(sql)).await.unwrap();
+        let escape_end = dialect.escape_end();
+        let sql = if dialect == SQLDialect::MSSQL {
+            format!("select top 1 * from {escape}{table_name}{escape_end}")
+        } else {
+            format!("select * from {escape}{table_name}{escape_end} limit 1")
+        };
+        Ok(!Self::query(conn, table_name, sql).await?.is_empty())
     }
 
-    pub(crate) async fn table_has_records(dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> bool {
-        let escape = dialect.escape();
-        let sql = format!("select * from {escape}{table_name}{escape} limit 1");
-        !conn.query(Query::from(sql)).await.unwrap().is_empty()
+    pub(crate) async fn migrate(dialect: SQLDialect, pool: &Quaint, models: &Vec<Model>, foreign_keys: bool) -> Result<()> {
+        let conn = match pool.check_out().await {
+            Ok(conn) => conn,
+            Err(err) => return Err(Error::migration_error(format!("Cannot connect to database: {}", err))),
+        };
+        let steps = Self::plan(dialect, &conn, models, foreign_keys).await?;
+        for step in steps {
+            match step {
+                SQLMigrationStep::Statement { table, column, sql, .. } => {
+                    if let Err(err) = conn.raw_cmd(&sql).await {
+                        return Err(Error::migration_table_error(&table, column.as_deref(), Some(&sql), err.to_string()));
+                    }
+                }
+                SQLMigrationStep::Action { table, column, action } => {
+                    let ctx = Ctx::initial_state_with_value(Value::Null);
+                    if let Err(err) = action.process(ctx).await {
+                        return Err(Error::migration_table_error(&table, Some(&column), None, format!("migration action failed: {}", err.message())));
+                    }
+                }
+            }
+        }
+        SQLTokenRevocation::create_table_if_needed(dialect, &conn).await
     }
 
-    pub(crate) async fn migrate(dialect: SQLDialect, pool: &Quaint, models: &Vec<Model>) {
-        let conn = pool.check_out().await.unwrap();
-        let mut db_tables = Self::get_db_user_tables(dialect, &conn).await;
+    /// Compares the database with the models and returns the steps which bring the database
+    /// up to date. The database is only read. When `foreign_keys` is false, foreign key
+    /// constraints are neither created nor dropped.
+    pub(crate) async fn plan(dialect: SQLDialect, conn: &PooledConnection, models: &[Model], foreign_keys: bool) -> Result<Vec<SQLMigrationStep>> {
+        let mut steps = vec![];
+        let mut db_tables = Self::get_db_user_tables(dialect, conn).await?;
+        db_tables.retain(|table| table != MIGRATION_HISTORY_TABLE && table != REVOKED_TOKENS_TABLE);
+        let original_db_tables = db_tables.clone();
+        // tables which are created or recreated by this migration
+        let mut rebuilt_tables: HashSet<String> = hashset!{};
+        // SQLite declares foreign keys when creating tables only
+        let inline_foreign_keys = foreign_keys && dialect == SQLDialect::SQLite;
         // compare each table and do migration
         for model in models {
             if model.r#virtual() { continue }
             let table_name = model.table_name();
+            // the name of the table in the database before this migration
+            let mut db_table_name = table_name.to_string();
             if let Some(migration) = model.migration() {
                 if !db_tables.iter().any(|x| x == table_name) {
                     for old_name in &migration.renamed {
                         if db_tables.contains(old_name) {
                             // rename
-                            Self::rename_table(dialect, &conn, old_name.as_str(), table_name).await;
+                            steps.push(SQLMigrationStep::reversible(
+                                table_name,
+                                Self::rename_table_statement(dialect, old_name.as_str(), table_name),
+                                Self::rename_table_statement(dialect, table_name, old_name.as_str()),
+                            ));
                             let index = db_tables.clone().iter().find_position(|v| *v == old_name).unwrap().0;
                             db_tables.remove(index);
                             db_tables.push(table_name.to_string());
+                            db_table_name = old_name.to_string();
                             break;
                         }
                     }
@@ -168,43 +244,51 @@ impl SQLMigration {
             let is_table_exist = db_tables.iter().any(|x| x == table_name);
             if !is_table_exist {
                 // table not exist, create table
-                Self::create_table(dialect, &conn, model).await;
+                steps.extend(Self::create_table_statements(dialect, model, models, inline_foreign_keys));
+                rebuilt_tables.insert(table_name.to_owned());
             } else {
                 // remove from list
                 let index = db_tables.clone().iter().find_position(|x| *x == table_name).unwrap().0;
                 db_tables.remove(index);
                 // start migrate for this table
                 let model_columns = ColumnDecoder::decode_model_columns(model);
-                let db_columns = Self::db_columns(&conn, dialect, table_name).await;
+                let db_columns = Self::db_columns(conn, dialect, &db_table_name).await?;
                 let need_to_alter_any_column = ColumnDecoder::need_to_alter_any_columns(&db_columns, &model_columns);
                 if need_to_alter_any_column && dialect == SQLDialect::SQLite {
-                    panic!("SQLite doesn't support column altering");
+                    return Err(Error::migration_table_error(table_name, None, None, "SQLite doesn't support column altering."));
+                }
+                let table_has_records = Self::table_has_records(dialect, conn, &db_table_name).await?;
+                let mut db_indices = Self::db_indices(dialect, conn, &db_table_name).await?;
+                if dialect == SQLDialect::MySQL {
+                    // MySQL creates an index for each foreign key
+                    let foreign_key_names: Vec<String> = SQLForeignKey::from_model(model, models).into_iter().map(|f| f.name).collect();
+                    db_indices.retain(|i| !i.name().map_or(false, |n| foreign_key_names.iter().any(|f| f == n)));
                 }
-                let table_has_records = Self::table_has_records(dialect, &conn, table_name).await;
-                let db_indices = Self::db_indices(dialect, &conn, model).await;
                 let model_indices = Self::normalized_model_indices(model.indices(), dialect, table_name);
                 // here update columns and indices
                 let manipulations = ColumnDecoder::manipulations(&db_columns, &model_columns, &db_indices, &model_indices, model);
                 if table_has_records && manipulations.iter().find(|m| m.is_add_column_non_null()).is_some() && model.allows_drop_when_migrate() {
-                    Self::drop_table(dialect, &conn, table_name).await;
-                    Self::create_table(dialect, &conn, model).await;
+                    steps.push(SQLMigrationStep::statement(table_name, Self::drop_table_statement(dialect, table_name)));
+                    steps.extend(Self::create_table_statements(dialect, model, models, inline_foreign_keys));
+                    rebuilt_tables.insert(table_name.to_owned());
                 } else {
                     for m in manipulations.iter() {
                         match m {
                             ColumnManipulation::CreateIndex(index) => {
                                 let create = index.to_sql_create(dialect, table_name);
-                                conn.execute(Query::from(create)).await.unwrap();
+                                let drop = index.to_sql_drop(dialect, table_name);
+                                steps.push(SQLMigrationStep::reversible(table_name, create, drop));
                             }
                             ColumnManipulation::DropIndex(index) => {
                                 let drop = index.to_sql_drop(dialect, table_name);
-                                conn.execute(Query::from(drop)).await.unwrap();
+                                let create = index.to_sql_create(dialect, table_name);
+                                steps.push(SQLMigrationStep::reversible(table_name, drop, create));
                             }
                             ColumnManipulation::AddColumn(column, action, default) => {
                                 if column.not_null() && default.is_none() {
                                     // if any records, just raise here
-                                    let has_records = Self::table_has_records(dialect, &conn, table_name).await;
-                                    if has_records {
-                                        panic!("Cannot add new non null column `{}', table `{}' has records. Consider add a default value or drop the table.", column.name(), table_name)
+                                    if table_has_records {
+                                        return Err(Error::migration_table_error(table_name, Some(column.name()), None, "Cannot add new non null column to a table which has records. Consider add a default value or drop the table."));
                                     }
                                 }
                                 let mut c = column.clone().clone();
@@ -212,38 +296,42 @@ impl SQLMigration {
                                     c.set_default(Some(default.as_ref().unwrap().to_string(dialect)));
                                 }
                                 let stmt = SQL::alter_table(table_name).add(c).to_string(dialect);
-                                conn.execute(Query::from(stmt)).await.unwrap();
-                                if let Some(action)= action {
-                                    let ctx = Ctx::initial_state_with_value(Value::Null);
-                                    action.process(ctx).await.unwrap();
+                                let revert = SQL::alter_table(table_name).drop_column(column.name()).to_string(dialect);
+                                steps.push(SQLMigrationStep::reversible(table_name, stmt, revert).with_column(column.name()));
+                                if let Some(action) = action {
+                                    steps.push(SQLMigrationStep::Action { table: table_name.to_owned(), column: column.name().to_owned(), action: action.clone() });
                                 }
                             }
                             ColumnManipulation::AlterColumn(old_column, new_column, _action) => {
                                 if dialect != SQLDialect::PostgreSQL {
-                                    let alter = SQL::alter_table(table_name).modify(new_column.clone().clone()).to_string(dialect);
-                                    conn.execute(Query::from(alter)).await.unwrap();
+                                    let alter = SQL::alter_table(table_name).modify((*new_column).clone()).to_string(dialect);
+                                    let revert = SQL::alter_table(table_name).modify((*old_column).clone()).to_string(dialect);
+                                    steps.push(SQLMigrationStep::reversible(table_name, alter, revert).with_column(new_column.name()));
                                 } else {
                                     let clauses = Self::psql_alter_clauses(table_name, *old_column, *new_column);
-                                    for clause in clauses {
-                                        conn.execute(Query::from(clause)).await.unwrap();
+                                    let revert_clauses = Self::psql_alter_clauses(table_name, *new_column, *old_column);
+                                    if !clauses.is_empty() {
+                                        steps.push(SQLMigrationStep::reversible(table_name, clauses.join(";\n"), revert_clauses.join(";\n")).with_column(new_column.name()));
                                     }
                                 }
                             }
                             ColumnManipulation::RemoveColumn(name, action) => {
-                                if let Some(action)= action {
-                                    let ctx = Ctx::initial_state_with_value(Value::Null);
-                                    action.process(ctx).await.unwrap();
+                                if let Some(action) = action {
+                                    steps.push(SQLMigrationStep::Action { table: table_name.to_owned(), column: name.to_string(), action: action.clone() });
                                 }
                                 let stmt = SQL::alter_table(table_name).drop_column(name).to_string(dialect);
-                                conn.execute(Query::from(stmt)).await.unwrap();
+                                match db_columns.iter().find(|c| c.name() == name) {
+                                    Some(column) => {
+                                        let revert = SQL::alter_table(table_name).add(column.clone()).to_string(dialect);
+                                        steps.push(SQLMigrationStep::reversible(table_name, stmt, revert).with_column(name.as_str()));
+                                    }
+                                    None => steps.push(SQLMigrationStep::statement(table_name, stmt).with_column(name.as_str())),
+                                }
                             }
                             ColumnManipulation::RenameColumn { old, new } => {
-                                let stmt = if dialect == SQLDialect::PostgreSQL {
-                                    format!("ALTER TABLE {} RENAME COLUMN '{}' TO '{}'", table_name, old, new)
-                                } else {
-                                    format!("ALTER TABLE {} RENAME COLUMN `{}` TO `{}`", table_name, old, new)
-                                };
-                                conn.execute(Query::from(stmt)).await.unwrap();
+                                let stmt = Self::rename_column_statement(dialect, table_name, old, new);
+                                let revert = Self::rename_column_statement(dialect, table_name, new, old);
+                                steps.push(SQLMigrationStep::reversible(table_name, stmt, revert).with_column(new.as_str()));
                             }
                         }
                     }
@@ -252,43 +340,211 @@ impl SQLMigration {
         }
         // drop tables
         for table in db_tables {
-            Self::drop_table(dialect, &conn, &table).await;
+            steps.push(SQLMigrationStep::statement(&table, Self::drop_table_statement(dialect, &table)));
+        }
+        // foreign keys are dropped before and added after other steps
+        if foreign_keys && dialect != SQLDialect::SQLite {
+            let (drops, adds) = Self::foreign_key_steps(dialect, conn, models, &original_db_tables, &rebuilt_tables).await?;
+            steps.splice(0..0, drops);
+            steps.extend(adds);
+        }
+        Ok(steps)
+    }
+
+    async fn foreign_key_steps(dialect: SQLDialect, conn: &PooledConnection, models: &[Model], db_tables: &[String], rebuilt_tables: &HashSet<String>) -> Result<(Vec<SQLMigrationStep>, Vec<SQLMigrationStep>)> {
+        let mut model_foreign_keys = vec![];
+        for model in models {
+            if model.r#virtual() { continue }
+            for mut foreign_key in SQLForeignKey::from_model(model, models) {
+                foreign_key.on_delete = foreign_key.on_delete.normalized(dialect);
+                foreign_key.on_update = foreign_key.on_update.normalized(dialect);
+                model_foreign_keys.push((model.table_name().to_owned(), foreign_key));
+            }
+        }
+        let mut kept = vec![];
+        let mut drops = vec![];
+        for table in db_tables {
+            for foreign_key in Self::db_foreign_keys(dialect, conn, table).await? {
+                let pair = (table.clone(), foreign_key);
+                if !rebuilt_tables.contains(table) && !rebuilt_tables.contains(&pair.1.referenced_table) && model_foreign_keys.contains(&pair) {
+                    kept.push(pair);
+                } else {
+                    let (table, foreign_key) = pair;
+                    let drop = SQL::alter_table(&table).drop_foreign_key(&foreign_key.name).to_string(dialect);
+                    let revert = SQL::alter_table(&table).add_foreign_key(foreign_key).to_string(dialect);
+                    drops.push(SQLMigrationStep::reversible(table, drop, revert));
+                }
+            }
+        }
+        let mut adds = vec![];
+        for (table, foreign_key) in model_foreign_keys {
+            if kept.iter().any(|(t, f)| t == &table && f == &foreign_key) { continue }
+            let revert = SQL::alter_table(&table).drop_foreign_key(&foreign_key.name).to_string(dialect);
+            let add = SQL::alter_table(&table).add_foreign_key(foreign_key).to_string(dialect);
+            adds.push(SQLMigrationStep::reversible(table, add, revert));
+        }
+        Ok((drops, adds))
+    }
+
+    async fn db_foreign_keys(dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> Result<Vec<SQLForeignKey>> {
+        let sql = match dialect {
+            SQLDialect::MySQL => format!(r#"SELECT k.CONSTRAINT_NAME AS constraint_name,
+       k.COLUMN_NAME AS column_name,
+       k.REFERENCED_TABLE_NAME AS referenced_table_name,
+       k.REFERENCED_COLUMN_NAME AS referenced_column_name,
+       r.DELETE_RULE AS delete_rule,
+       r.UPDATE_RULE AS update_rule
+FROM information_schema.KEY_COLUMN_USAGE AS k
+JOIN information_schema.REFERENTIAL_CONSTRAINTS AS r
+ON r.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA
+AND r.CONSTRAINT_NAME = k.CONSTRAINT_NAME
+WHERE k.TABLE_SCHEMA = DATABASE()
+AND k.TABLE_NAME = '{table_name}'
+ORDER BY k.CONSTRAINT_NAME, k.ORDINAL_POSITION"#),
+            SQLDialect::PostgreSQL => format!(r#"SELECT con.conname AS constraint_name,
+       a.attname AS column_name,
+       ref.relname AS referenced_table_name,
+       ra.attname AS referenced_column_name,
+       con.confdeltype::text AS delete_rule,
+       con.confupdtype::text AS update_rule
+FROM pg_constraint AS con
+JOIN pg_class AS trel
+ON trel.oid = con.conrelid
+JOIN pg_namespace AS tnsp
+ON trel.relnamespace = tnsp.oid
+JOIN pg_class AS ref
+ON ref.oid = con.confrelid
+CROSS JOIN LATERAL unnest(con.conkey, con.confkey) WITH ORDINALITY AS k (conkey, confkey, ordinality)
+JOIN pg_attribute AS a
+ON a.attrelid = con.conrelid
+AND a.attnum = k.conkey
+JOIN pg_attribute AS ra
+ON ra.attrelid = con.confrelid
+AND ra.attnum = k.confkey
+WHERE con.contype = 'f'
+AND tnsp.nspname = 'public'
+AND trel.relname = '{table_name}'
+ORDER BY con.conname, k.ordinality"#),
+            SQLDialect::MSSQL => format!(r#"SELECT fk.name AS constraint_name,
+       c.name AS column_name,
+       rt.name AS referenced_table_name,
+       rc.name AS referenced_column_name,
+       fk.delete_referential_action_desc AS delete_rule,
+       fk.update_referential_action_desc AS update_rule
+FROM sys.foreign_keys AS fk
+JOIN sys.foreign_key_columns AS fkc
+ON fkc.constraint_object_id = fk.object_id
+JOIN sys.tables AS t
+ON t.object_id = fk.parent_object_id
+JOIN sys.columns AS c
+ON c.object_id = fkc.parent_object_id
+AND c.column_id = fkc.parent_column_id
+JOIN sys.tables AS rt
+ON rt.object_id = fk.referenced_object_id
+JOIN sys.columns AS rc
+ON rc.object_id = fkc.referenced_object_id
+AND rc.column_id = fkc.referenced_column_id
+WHERE t.name = '{table_name}'
+ORDER BY fk.name, fkc.constraint_column_id"#),
+            _ => return Ok(vec![]),
+        };
+        let result_set = Self::query(conn, table_name, sql).await?;
+        let mut foreign_keys: Vec<SQLForeignKey> = vec![];
+        for row in result_set {
+            let name = row.get("constraint_name").unwrap().as_str().unwrap();
+            let column_name = row.get("column_name").unwrap().as_str().unwrap().to_owned();
+            let referenced_column_name = row.get("referenced_column_name").unwrap().as_str().unwrap().to_owned();
+            if let Some(foreign_key) = foreign_keys.iter_mut().find(|f| f.name == name) {
+                foreign_key.columns.push(column_name);
+                foreign_key.referenced_columns.push(referenced_column_name);
+            } else {
+                foreign_keys.push(SQLForeignKey {
+                    name: name.to_owned(),
+                    columns: vec![column_name],
+                    referenced_table: row.get("referenced_table_name").unwrap().as_str().unwrap().to_owned(),
+                    referenced_columns: vec![referenced_column_name],
+                    on_delete: SQLReferentialAction::from_db_str(row.get("delete_rule").unwrap().as_str().unwrap()),
+                    on_update: SQLReferentialAction::from_db_str(row.get("update_rule").unwrap().as_str().unwrap()),
+                });
+            }
         }
+        Ok(foreign_keys)
     }
 
-    async fn drop_table(dialect: SQLDialect, conn: &PooledConnection, table: &str) {
+    fn rename_table_statement(dialect: SQLDialect, old_name: &str, new_name: &str) -> String {
+        if dialect == SQLDialect::MSSQL {
+            return format!("EXEC sp_rename '{old_name}', '{new_name}'");
+        }
         let escape = dialect.escape();
-        let sql = format!("DROP TABLE {escape}{table}{escape}");
-        conn.execute(Query::from(sql)).await.unwrap();
+        let escape_end = dialect.escape_end();
+        format!("ALTER TABLE {escape}{old_name}{escape_end} RENAME TO {escape}{new_name}{escape_end}")
     }
 
-    async fn create_table(dialect: SQLDialect, conn: &PooledConnection, model: &Model) {
+    fn rename_column_statement(dialect: SQLDialect, table: &str, old: &str, new: &str) -> String {
+        if dialect == SQLDialect::MSSQL {
+            return format!("EXEC sp_rename '{}.{}', '{}', 'COLUMN'", table, old, new);
+        }
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        format!("ALTER TABLE {escape}{table}{escape_end} RENAME COLUMN {escape}{old}{escape_end} TO {escape}{new}{escape_end}")
+    }
+
+    fn drop_table_statement(dialect: SQLDialect, table: &str) -> String {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        format!("DROP TABLE {escape}{table}{escape_end}")
+    }
+
+    fn create_table_statements(dialect: SQLDialect, model: &Model, models: &[Model], inline_foreign_keys: bool) -> Vec<SQLMigrationStep> {
         // create table
-        let stmt = SQLCreateTableStatement::from(model).to_string(dialect);
-        conn.execute(Query::from(stmt)).await.unwrap();
+        let mut stmt = SQLCreateTableStatement::from(model);
+        if inline_foreign_keys {
+            stmt.foreign_keys(SQLForeignKey::from_model(model, models));
+        }
+        let mut steps = vec![SQLMigrationStep::reversible(
+            model.table_name(),
+            stmt.to_string(dialect),
+            Self::drop_table_statement(dialect, model.table_name()),
+        )];
         // create indices
         for index in model.indices() {
             // primary is created when creating table
-            if index.r#type().is_primary() { continue }
-            let stmt = index.to_sql_create(dialect, model.table_name());
-            conn.execute(Query::from(stmt)).await.unwrap();
+            if index.r#type().is_primary() {
+                // PostgreSQL compares tables with a unique index for the primary key, create it
+                // together with the table so that the next plan is empty
+                if dialect != SQLDialect::PostgreSQL { continue }
+                let unique = index.psql_primary_to_unique(model.table_name());
+                steps.push(SQLMigrationStep::reversible(
+                    model.table_name(),
+                    unique.to_sql_create(dialect, model.table_name()),
+                    unique.to_sql_drop(dialect, model.table_name()),
+                ));
+                continue
+            }
+            steps.push(SQLMigrationStep::reversible(
+                model.table_name(),
+                index.to_sql_create(dialect, model.table_name()),
+                index.to_sql_drop(dialect, model.table_name()),
+            ));
         }
+        steps
     }
 
     fn psql_alter_clauses(table: &str, old_column: &SQLColumn, new_column: &SQLColumn) -> Vec<String> {
         let mut result = vec![];
         let name = new_column.name();
         let escape = SQLDialect::PostgreSQL.escape();
+        let escape_end = SQLDialect::PostgreSQL.escape_end();
         if old_column.r#type() != new_column.r#type() {
-            result.push(format!("ALTER TABLE {escape}{table}{escape} ALTER COLUMN {escape}{name}{escape} TYPE {}", new_column.r#type().to_string(SQLDialect::PostgreSQL)));
+            result.push(format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{name}{escape_end} TYPE {}", new_column.r#type().to_string(SQLDialect::PostgreSQL)));
         }
         if old_column.default().is_none() && new_column.default().is_some() {
-            result.push(format!("ALTER TABLE {escape}{table}{escape} ALTER COLUMN {escape}{name}{escape} SET DEFAULT {}", new_column.default().unwrap()));
+            result.push(format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{name}{escape_end} SET DEFAULT {}", new_column.default().unwrap()));
         } else if old_column.default().is_some() && new_column.default().is_none() {
-            result.push(format!("ALTER TABLE {escape}{table}{escape} ALTER COLUMN {escape}{name}{escape} DROP DEFAULT"));
+            result.push(format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{name}{escape_end} DROP DEFAULT"));
         } else if old_column.default().is_some() && new_column.default().is_some() {
             if old_column.default() == new_column.default() {
-                result.push(format!("ALTER TABLE {escape}{table}{escape} ALTER COLUMN {escape}{name}{escape} SET DEFAULT {}", new_column.default().unwrap()));
+                result.push(format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{name}{escape_end} SET DEFAULT {}", new_column.default().unwrap()));
             }
         }
         result
@@ -310,19 +566,18 @@ impl SQLMigration {
         results.into_iter().collect()
     }
 
-    async fn db_indices(dialect: SQLDialect, conn: &PooledConnection, model: &Model) -> HashSet<ModelIndex> {
+    async fn db_indices(dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> Result<HashSet<ModelIndex>> {
         match dialect {
-            SQLDialect::PostgreSQL => Self::psql_db_indices(conn, model).await,
-            SQLDialect::MySQL => Self::mysql_db_indices(conn, model).await,
-            SQLDialect::SQLite => Self::sqlite_db_indices(conn, model).await,
-            _ => unreachable!(),
+            SQLDialect::PostgreSQL => Self::psql_db_indices(conn, table_name).await,
+            SQLDialect::MySQL => Self::mysql_db_indices(conn, table_name).await,
+            SQLDialect::SQLite => Self::sqlite_db_indices(conn, table_name).await,
+            SQLDialect::MSSQL => Self::mssql_db_indices(conn, table_name).await,
         }
     }
 
-    async fn mysql_db_indices(conn: &PooledConnection, model: &Model) -> HashSet<ModelIndex> {
-        let table_name = model.table_name();
+    async fn mysql_db_indices(conn: &PooledConnection, table_name: &str) -> Result<HashSet<ModelIndex>> {
         let sql = format!("SHOW INDEX FROM `{}`", table_name);
-        let result_set = conn.query(Query::from(sql)).await.unwrap();
+        let result_set = Self::query(conn, table_name, sql).await?;
         let mut indices = vec![];
         for row in result_set {
             let index_name = row.get("Key_name").unwrap().as_str().unwrap();
@@ -342,11 +597,10 @@ impl SQLMigration {
                 ))
             }
         }
-        indices.into_iter().collect()
+        Ok(indices.into_iter().collect())
     }
 
-    async fn psql_db_indices(conn: &PooledConnection, model: &Model) -> HashSet<ModelIndex> {
-        let table_name = model.table_name();
+    async fn psql_db_indices(conn: &PooledConnection, table_name: &str) -> Result<HashSet<ModelIndex>> {
         let sql = format!(r#"SELECT     irel.relname                           AS index_name,
            a.attname                              AS column_name,
            i.indisunique                          AS is_unique,
@@ -381,7 +635,7 @@ GROUP BY   tnsp.nspname,
            array_position(i.indkey, a.attnum),
            o.OPTION ORDER BY column_position
 "#);
-        let result_set = conn.query(Query::from(sql)).await.unwrap();
+        let result_set = Self::query(conn, table_name, sql).await?;
         let mut indices = vec![];
         for row in result_set {
             let index_name = row.get("index_name").unwrap().as_str().unwrap();
@@ -402,11 +656,40 @@ GROUP BY   tnsp.nspname,
                 ))
             }
         }
-        indices.into_iter().collect()
+        Ok(indices.into_iter().collect())
+    }
+
+    async fn mssql_db_indices(conn: &PooledConnection, table_name: &str) -> Result<HashSet<ModelIndex>> {
+        let result_set = Self::query(conn, table_name, mssql_indices_query(table_name)).await?;
+        let mut indices = vec![];
+        for row in result_set {
+            let is_primary = row.get("is_primary").unwrap().as_bool().unwrap();
+            // primary keys are declared inline, SQL Server generates their names
+            let index_name = if is_primary {
+                format!("PK_{table_name}")
+            } else {
+                row.get("index_name").unwrap().as_str().unwrap().to_owned()
+            };
+            let column_name = row.get("column_name").unwrap().as_str().unwrap();
+            let order = Sort::from_desc_bool(row.get("is_descending").unwrap().as_bool().unwrap());
+            if let Some(position) = indices.iter().position(|m: &ModelIndex| m.name().unwrap() == index_name) {
+                let model_index = indices.get_mut(position).unwrap();
+                let item = ModelIndexItem::new(column_name, order, None);
+                model_index.append_item(item);
+            } else {
+                let is_unique = row.get("is_unique").unwrap().as_bool().unwrap();
+                let item = ModelIndexItem::new(column_name, order, None);
+                indices.push(ModelIndex::new(
+                    if is_primary { ModelIndexType::Primary } else if is_unique { ModelIndexType::Unique } else { ModelIndexType::Index },
+                    Some(index_name),
+                    vec![item],
+                ))
+            }
+        }
+        Ok(indices.into_iter().collect())
     }
 
-    async fn sqlite_db_indices(conn: &PooledConnection, model: &Model) -> HashSet<ModelIndex> {
-        let table_name = model.table_name();
+    async fn sqlite_db_indices(conn: &PooledConnection, table_name: &str) -> Result<HashSet<ModelIndex>> {
         let sql = format!(r#"SELECT
     il.name as index_name,
     ii.name as column_name,
@@ -431,8 +714,8 @@ GROUP BY
     il.partial,
     il.seq,
     ii.`desc`
-ORDER BY 1,6"#, table_name);
-        let result_set = conn.query(Query::from(sql)).await.unwrap();
+ORDER BY 1,6,ii.seqno"#, table_name);
+        let result_set = Self::query(conn, table_name, sql).await?;
         let mut indices = vec![];
         for row in result_set {
             let index_name = row.get("index_name").unwrap().as_str().unwrap();
@@ -459,14 +742,17 @@ ORDER BY 1,6"#, table_name);
         }).is_some();
         if !includes_primary {
             let sql = format!("SELECT * FROM pragma_table_info(\"{table_name}\") WHERE pk = 1");
-            let result_set = conn.query(Query::from(sql)).await.unwrap();
-            let row = result_set.into_single().unwrap();
+            let result_set = Self::query(conn, table_name, sql.as_str()).await?;
+            let row = match result_set.into_single() {
+                Ok(row) => row,
+                Err(err) => return Err(Error::migration_table_error(table_name, None, Some(&sql), format!("Cannot read primary key: {}", err))),
+            };
             let column_name = row.get("name").unwrap().as_str().unwrap();
             let index = ModelIndex::new(ModelIndexType::Primary, Some(format!("sqlite_autoindex_{table_name}_1")), vec![
                 ModelIndexItem::new(column_name.to_owned(), Sort::Asc, None)
             ]);
             results.push(index);
         }
-        results.into_iter().collect()
+        Ok(results.into_iter().collect())
     }
 }
@@ -1,2 +1,4 @@
+pub(crate) mod history;
 pub(crate) mod migrate;
+pub(crate) mod plan;
 pub(crate) mod sql;
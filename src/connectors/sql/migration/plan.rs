@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+use crate::core::error::Error;
+use crate::core::model::Model;
+use crate::core::pipeline::Pipeline;
+use crate::core::result::Result;
+
+/// The comment which marks a `@migration` action in a migration file, followed by the table
+/// and the column of the action.
+const ACTION_MARKER: &str = "-- teo:action ";
+
+/// A single operation of a SQL migration plan.
+#[derive(Debug, Clone)]
+pub(crate) enum SQLMigrationStep {
+    /// A SQL statement to execute on `table`, with the statement which reverts it if it can be
+    /// reverted.
+    Statement { table: String, column: Option<String>, sql: String, revert: Option<String> },
+    /// A `@migration` action of `column` which runs between statements.
+    Action { table: String, column: String, action: Pipeline },
+}
+
+impl SQLMigrationStep {
+
+    pub(crate) fn statement(table: impl Into<String>, sql: impl Into<String>) -> Self {
+        SQLMigrationStep::Statement { table: table.into(), column: None, sql: sql.into(), revert: None }
+    }
+
+    pub(crate) fn reversible(table: impl Into<String>, sql: impl Into<String>, revert: impl Into<String>) -> Self {
+        SQLMigrationStep::Statement { table: table.into(), column: None, sql: sql.into(), revert: Some(revert.into()) }
+    }
+
+    pub(crate) fn with_column(self, name: impl Into<String>) -> Self {
+        match self {
+            SQLMigrationStep::Statement { table, column: _, sql, revert } => SQLMigrationStep::Statement { table, column: Some(name.into()), sql, revert },
+            SQLMigrationStep::Action { table, column: _, action } => SQLMigrationStep::Action { table, column: name.into(), action },
+        }
+    }
+}
+
+impl Display for SQLMigrationStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SQLMigrationStep::Statement { sql, .. } => f.write_str(&terminated(sql)),
+            SQLMigrationStep::Action { table, column, .. } => f.write_str(&action_marker(table, column)),
+        }
+    }
+}
+
+/// Renders the steps into the content of an up and a down migration file. Steps which can't be
+/// reverted are left as comments in the down file.
+pub(crate) fn migration_file_contents(steps: &Vec<SQLMigrationStep>) -> (String, String) {
+    let mut up = vec![];
+    let mut down = vec![];
+    for step in steps {
+        match step {
+            SQLMigrationStep::Statement { sql, revert, .. } => {
+                up.push(terminated(sql));
+                match revert {
+                    Some(revert) => down.push(terminated(revert)),
+                    None => down.push(format!("-- irreversible: {}", terminated(sql))),
+                }
+            }
+            SQLMigrationStep::Action { table, column, .. } => {
+                up.push(action_marker(table, column));
+            }
+        }
+    }
+    down.reverse();
+    (up.join("\n") + "\n", down.join("\n") + "\n")
+}
+
+/// Splits the content of an up migration file into steps. The SQL between action markers is
+/// kept as a single statement, and each marker is resolved to the `@migration` action of its
+/// field in `models`.
+pub(crate) fn migration_file_steps(models: &[Model], statements: &str) -> Result<Vec<SQLMigrationStep>> {
+    let mut steps = vec![];
+    let mut sql = String::new();
+    for line in statements.lines() {
+        match line.trim().strip_prefix(ACTION_MARKER) {
+            Some(target) => {
+                if !sql.trim().is_empty() {
+                    steps.push(SQLMigrationStep::statement("", sql.clone()));
+                }
+                sql.clear();
+                let (table, column) = match target.trim().split_once(' ') {
+                    Some((table, column)) => (table, column.trim()),
+                    None => return Err(Error::migration_error(format!("Invalid migration action marker '{}'.", line.trim()))),
+                };
+                let action = match models.iter().find(|m| m.table_name() == table) {
+                    Some(model) => model.field(column).or(model.dropped_field(column)).and_then(|f| f.migration()).and_then(|m| m.action.clone()),
+                    None => None,
+                };
+                match action {
+                    Some(action) => steps.push(SQLMigrationStep::Action { table: table.to_owned(), column: column.to_owned(), action }),
+                    None => return Err(Error::migration_table_error(table, Some(column), None, "migration action is not found in the schema")),
+                }
+            }
+            None => {
+                sql.push_str(line);
+                sql.push('\n');
+            }
+        }
+    }
+    if !sql.trim().is_empty() {
+        steps.push(SQLMigrationStep::statement("", sql));
+    }
+    Ok(steps)
+}
+
+fn action_marker(table: &str, column: &str) -> String {
+    format!("{ACTION_MARKER}{table} {column}")
+}
+
+fn terminated(sql: &str) -> String {
+    if sql.ends_with(";") {
+        sql.to_owned()
+    } else {
+        format!("{};", sql)
+    }
+}
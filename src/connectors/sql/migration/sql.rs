@@ -22,3 +22,43 @@ pub(crate) fn sqlite_auto_increment_query(table_name: &str) -> String {
 pub(crate) fn psql_is_auto_increment(table_name: &str, column_name: &str) -> String {
     format!("select relname from pg_class where relname = '{}_{}_seq'", table_name, column_name)
 }
+
+pub(crate) fn mssql_columns_query(table_name: &str) -> String {
+    format!("SELECT
+  COLUMN_NAME AS column_name,
+  DATA_TYPE AS data_type,
+  CHARACTER_MAXIMUM_LENGTH AS character_maximum_length,
+  NUMERIC_PRECISION AS numeric_precision,
+  NUMERIC_SCALE AS numeric_scale,
+  DATETIME_PRECISION AS datetime_precision,
+  IS_NULLABLE AS is_nullable,
+  COLUMNPROPERTY(OBJECT_ID(QUOTENAME(TABLE_SCHEMA) + '.' + QUOTENAME(TABLE_NAME)), COLUMN_NAME, 'IsIdentity') AS is_identity
+FROM INFORMATION_SCHEMA.COLUMNS
+WHERE TABLE_CATALOG = DB_NAME() AND TABLE_NAME = '{}'
+ORDER BY ORDINAL_POSITION", table_name)
+}
+
+pub(crate) fn mssql_primary_columns_query(table_name: &str) -> String {
+    format!("SELECT
+  k.COLUMN_NAME AS column_name
+FROM INFORMATION_SCHEMA.TABLE_CONSTRAINTS AS t
+JOIN INFORMATION_SCHEMA.KEY_COLUMN_USAGE AS k
+ON k.CONSTRAINT_NAME = t.CONSTRAINT_NAME AND k.TABLE_NAME = t.TABLE_NAME
+WHERE t.CONSTRAINT_TYPE = 'PRIMARY KEY' AND t.TABLE_NAME = '{}'", table_name)
+}
+
+pub(crate) fn mssql_indices_query(table_name: &str) -> String {
+    format!("SELECT
+  i.name AS index_name,
+  c.name AS column_name,
+  i.is_primary_key AS is_primary,
+  i.is_unique AS is_unique,
+  ic.is_descending_key AS is_descending
+FROM sys.indexes AS i
+JOIN sys.index_columns AS ic
+ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+JOIN sys.columns AS c
+ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+WHERE i.object_id = OBJECT_ID('{}') AND i.type > 0
+ORDER BY i.name, ic.key_ordinal", table_name)
+}
@@ -1,12 +1,16 @@
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use maplit::{btreemap, hashmap};
 use once_cell::sync::Lazy;
+use quaint_forked::prelude::Value as QuaintValue;
+use serde_json::Value as JsonValue;
 use crate::connectors::sql::schema::dialect::SQLDialect;
-use crate::connectors::sql::schema::value::encode::{IfIMode, SQLEscape, ToLike, ToSQLString, ToWrapped, ValueToSQLString, WrapInArray};
+use crate::connectors::sql::schema::value::encode::{IfIMode, SQLEscape, ToSQLString, ToWrapped};
+use crate::connectors::sql::schema::value::params::SQLParams;
 use crate::connectors::sql::stmts::select::r#where::{ToWrappedSQLString, WhereClause};
 use crate::connectors::sql::stmts::select::r#where::WhereClause::{And, Not};
 use crate::connectors::sql::stmts::SQL;
+use crate::core::error::Error;
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
 use crate::core::input::Input;
 use crate::core::model::Model;
@@ -20,161 +24,353 @@ impl Query {
         format!("{} {} {}", lhs.as_ref(), op, rhs)
     }
 
-    pub(crate) fn where_from_identifier(object: &Object, dialect: SQLDialect) -> String {
-        Self::where_from_value(object.model(), object.graph(), &object.identifier(), dialect)
+    pub(crate) fn where_from_identifier(object: &Object, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        Self::where_from_value(object.model(), object.graph(), &object.identifier(), dialect, params)
+    }
+
+    pub(crate) fn where_from_previous_identifier(object: &Object, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        Self::where_from_value(object.model(), object.graph(), &object.previous_identifier(), dialect, params)
     }
 
     fn where_entry_array(
         column_name: &str,
         r#type: &FieldType,
-        optional: bool,
         value: &Value,
-        graph: &Graph,
-        op: &str
+        op: &str,
+        params: &mut SQLParams,
     ) -> String {
         let arr_val = value.as_vec().unwrap();
         let mut arr: Vec<String> = Vec::new();
         for val in arr_val {
-            arr.push(val.to_sql_string(r#type, optional, graph));
+            arr.push(params.bind(val, r#type));
         }
         Query::where_item(column_name, op, &arr.join(", ").to_wrapped())
     }
 
+    fn like_pattern(value: &Value, left: bool, right: bool) -> Value {
+        let mut pattern = String::new();
+        if left {
+            pattern.push('%');
+        }
+        pattern.push_str(value.as_str().unwrap());
+        if right {
+            pattern.push('%');
+        }
+        Value::String(pattern)
+    }
+
+    /// The path is bound in the form of `$."key"[0]`, PostgreSQL takes a text array instead.
+    fn json_path(path: &[Value], dialect: SQLDialect, params: &mut SQLParams) -> String {
+        if dialect == SQLDialect::PostgreSQL {
+            let segments = path.iter().map(|segment| match segment {
+                Value::I64(i) => QuaintValue::Text(Some(Cow::Owned(i.to_string()))),
+                _ => QuaintValue::Text(Some(Cow::Owned(segment.as_str().unwrap().to_owned()))),
+            }).collect();
+            return params.push(QuaintValue::Array(Some(segments)));
+        }
+        let mut json_path = "$".to_owned();
+        for segment in path {
+            match segment {
+                Value::I64(i) => json_path.push_str(&format!("[{i}]")),
+                _ => json_path.push_str(&format!(".\"{}\"", segment.as_str().unwrap().replace('\\', "\\\\").replace('"', "\\\""))),
+            }
+        }
+        params.push(QuaintValue::Text(Some(Cow::Owned(json_path))))
+    }
+
+    fn json_document(value: &Value, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        let json_value: JsonValue = value.into();
+        match dialect {
+            SQLDialect::PostgreSQL => params.push(QuaintValue::Json(Some(json_value))),
+            SQLDialect::MySQL => format!("CAST({} AS JSON)", params.push(QuaintValue::Text(Some(Cow::Owned(json_value.to_string()))))),
+            _ => format!("json({})", params.push(QuaintValue::Text(Some(Cow::Owned(json_value.to_string()))))),
+        }
+    }
+
+    fn json_target(column_name: &str, path: &[Value], dialect: SQLDialect, params: &mut SQLParams) -> String {
+        match dialect {
+            SQLDialect::PostgreSQL => if path.is_empty() {
+                column_name.to_owned()
+            } else {
+                format!("({} #> {})", column_name, Self::json_path(path, dialect, params))
+            },
+            SQLDialect::MySQL => if path.is_empty() {
+                column_name.to_owned()
+            } else {
+                format!("JSON_EXTRACT({}, {})", column_name, Self::json_path(path, dialect, params))
+            },
+            SQLDialect::SQLite => format!("json_extract({}, {})", column_name, Self::json_path(path, dialect, params)),
+            SQLDialect::MSSQL => params.fail(Error::invalid_operation("JSON filters are not supported on SQL Server.")),
+        }
+    }
+
+    fn json_compare(column_name: &str, path: &[Value], value: &Value, op: &str, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        if value.is_null() && (path.is_empty() || dialect == SQLDialect::SQLite) {
+            let target = if path.is_empty() { column_name.to_owned() } else { Self::json_target(column_name, path, dialect, params) };
+            return Self::where_item(target, "IS", if op == "=" { "NULL" } else { "NOT NULL" });
+        }
+        if dialect == SQLDialect::SQLite {
+            let equals = Self::sqlite_json_equals(column_name, &mut path.to_vec(), value, params);
+            return if op == "=" { equals } else { format!("NOT ({})", equals) };
+        }
+        let target = Self::json_target(column_name, path, dialect, params);
+        Self::where_item(target, op, &Self::json_document(value, dialect, params))
+    }
+
+    fn json_object_entries(value: &Value) -> Option<Vec<(&String, &Value)>> {
+        match value {
+            Value::HashMap(map) => Some(map.iter().collect()),
+            Value::BTreeMap(map) => Some(map.iter().collect()),
+            Value::IndexMap(map) => Some(map.iter().collect()),
+            _ => None,
+        }
+    }
+
+    /// SQLite only compares documents in their text form, which depends on the order of the keys.
+    /// Objects and arrays are matched by their type, their size and each of their members instead,
+    /// scalars are compared as the SQL values `json_extract` returns.
+    fn sqlite_json_equals(document: &str, path: &mut Vec<Value>, value: &Value, params: &mut SQLParams) -> String {
+        if let Some(entries) = Self::json_object_entries(value) {
+            let json_path = Self::json_path(path, SQLDialect::SQLite, params);
+            let mut result = vec![
+                Self::where_item(format!("json_type({}, {})", document, json_path), "=", "'object'"),
+                Self::where_item(format!("(SELECT COUNT(*) FROM (SELECT {} AS document) AS d, json_each(d.document, {}))", document, json_path), "=", &entries.len().to_string()),
+            ];
+            for (key, value) in entries {
+                path.push(Value::String(key.clone()));
+                result.push(Self::sqlite_json_equals(document, path, value, params));
+                path.pop();
+            }
+            return And(result).to_wrapped_string(SQLDialect::SQLite);
+        }
+        match value {
+            Value::Vec(values) => {
+                let json_path = Self::json_path(path, SQLDialect::SQLite, params);
+                let mut result = vec![
+                    Self::where_item(format!("json_type({}, {})", document, json_path), "=", "'array'"),
+                    Self::where_item(format!("json_array_length({}, {})", document, json_path), "=", &values.len().to_string()),
+                ];
+                for (index, value) in values.iter().enumerate() {
+                    path.push(Value::I64(index as i64));
+                    result.push(Self::sqlite_json_equals(document, path, value, params));
+                    path.pop();
+                }
+                And(result).to_wrapped_string(SQLDialect::SQLite)
+            }
+            Value::Null => Self::where_item(format!("json_type({}, {})", document, Self::json_path(path, SQLDialect::SQLite, params)), "=", "'null'"),
+            _ => Self::where_item(format!("json_extract({}, {})", document, Self::json_path(path, SQLDialect::SQLite, params)), "=", &params.push(Self::json_scalar(value))),
+        }
+    }
+
+    fn json_scalar(value: &Value) -> QuaintValue<'static> {
+        match value {
+            Value::Bool(b) => QuaintValue::Boolean(Some(*b)),
+            Value::I64(i) => QuaintValue::Int64(Some(*i)),
+            Value::F64(f) => QuaintValue::Double(Some(*f)),
+            Value::String(s) => QuaintValue::Text(Some(Cow::Owned(s.clone()))),
+            _ => QuaintValue::Text(None),
+        }
+    }
+
+    fn json_contains(column_name: &str, path: &[Value], value: &Value, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        match dialect {
+            SQLDialect::PostgreSQL => {
+                let target = Self::json_target(column_name, path, dialect, params);
+                Self::where_item(target, "@>", &Self::json_document(value, dialect, params))
+            }
+            SQLDialect::MySQL => {
+                let document = Self::json_document(value, dialect, params);
+                format!("JSON_CONTAINS({}, {}, {})", column_name, document, Self::json_path(path, dialect, params))
+            }
+            SQLDialect::SQLite => {
+                let mut result = vec![];
+                Self::sqlite_json_contains(column_name, &mut path.to_vec(), value, &mut result, params);
+                if result.is_empty() {
+                    "1 = 1".to_owned()
+                } else {
+                    And(result).to_wrapped_string(dialect)
+                }
+            }
+            SQLDialect::MSSQL => params.fail(Error::invalid_operation("JSON filters are not supported on SQL Server.")),
+        }
+    }
+
+    /// SQLite has no containment operator. Objects are matched key by key and each element of an
+    /// array must be found in the array at the same path. The document is selected before it's
+    /// passed to `json_each`, whose hidden columns would shadow a column of the same name.
+    fn sqlite_json_contains(column_name: &str, path: &mut Vec<Value>, value: &Value, result: &mut Vec<String>, params: &mut SQLParams) {
+        if let Some(entries) = Self::json_object_entries(value) {
+            for (key, value) in entries {
+                path.push(Value::String(key.clone()));
+                Self::sqlite_json_contains(column_name, path, value, result, params);
+                path.pop();
+            }
+            return;
+        }
+        match value {
+            Value::Vec(values) => for value in values {
+                let json_path = Self::json_path(path, SQLDialect::SQLite, params);
+                let condition = if value.is_vec() || Self::json_object_entries(value).is_some() {
+                    Self::sqlite_json_equals("e.value", &mut vec![], value, params)
+                } else {
+                    Self::where_item("e.value", "=", &params.push(Self::json_scalar(value)))
+                };
+                result.push(format!("EXISTS (SELECT 1 FROM (SELECT {} AS document) AS d, json_each(d.document, {}) AS e WHERE {})", column_name, json_path, condition));
+            }
+            _ => result.push(Self::json_compare(column_name, path, value, "=", SQLDialect::SQLite, params)),
+        }
+    }
+
+    fn where_entry_json(column_name: &str, map: &HashMap<String, Value>, dialect: SQLDialect, params: &mut SQLParams) -> String {
+        let path = map.get("path").map(|p| p.as_vec().unwrap().as_slice()).unwrap_or(&[]);
+        let mut result: Vec<String> = vec![];
+        for (key, value) in map {
+            match key.as_str() {
+                "equals" => result.push(Self::json_compare(column_name, path, value, "=", dialect, params)),
+                "not" => result.push(Self::json_compare(column_name, path, value, "<>", dialect, params)),
+                "contains" => result.push(Self::json_contains(column_name, path, value, dialect, params)),
+                "path" => { }
+                _ => result.push(params.fail(Error::invalid_operation(format!("Unknown JSON filter '{}'.", key)))),
+            }
+        }
+        And(result).to_wrapped_string(dialect)
+    }
+
     fn where_entry_item(
         column_name: &str,
         r#type: &FieldType,
-        optional: bool,
         value: &Value,
-        graph: &Graph,
         dialect: SQLDialect,
+        params: &mut SQLParams,
     ) -> String {
-        let column_name = column_name.escape(dialect);
+        if r#type.is_json() {
+            if let Some(map) = value.as_hashmap() {
+                return Self::where_entry_json(column_name, map, dialect, params);
+            }
+        }
         if let Some(map) = value.as_hashmap() {
             let mut result: Vec<String> = vec![];
             for (key, value) in map {
                 match key.as_str() {
                     "equals" => {
-                        result.push(Self::where_item(&column_name, "=", &value.to_sql_string(r#type, optional, graph)));
+                        result.push(Self::where_item(&column_name, "=", &params.bind(value, r#type)));
                     }
                     "not" => {
-                        result.push(Self::where_item(&column_name, "<>", &value.to_sql_string(r#type, optional, graph)));
+                        result.push(Self::where_item(&column_name, "<>", &params.bind(value, r#type)));
                     }
                     "gt" => {
-                        result.push(Self::where_item(&column_name, ">", &value.to_sql_string(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, ">", &params.bind(value, r#type)));
                     }
                     "gte" => {
-                        result.push(Self::where_item(&column_name, ">=", &value.to_sql_string(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, ">=", &params.bind(value, r#type)));
                     }
                     "lt" => {
-                        result.push(Self::where_item(&column_name, "<", &value.to_sql_string(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, "<", &params.bind(value, r#type)));
                     }
                     "lte" => {
-                        result.push(Self::where_item(&column_name, "<=", &value.to_sql_string(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, "<=", &params.bind(value, r#type)));
                     }
                     "in" => {
-                        result.push(Self::where_entry_array(&column_name, r#type, optional, value, graph, "IN"));
+                        result.push(Self::where_entry_array(&column_name, r#type, value, "IN", params));
                     }
                     "notIn" => {
-                        result.push(Self::where_entry_array(&column_name, r#type, optional, value, graph, "NOT IN"));
+                        result.push(Self::where_entry_array(&column_name, r#type, value, "NOT IN", params));
                     }
                     "contains" => {
                         let i_mode = Input::has_i_mode(map);
-                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &value.to_sql_string(r#type, false, graph).to_like(true, true).to_i_mode(i_mode)));
+                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &params.bind(&Self::like_pattern(value, true, true), r#type).to_i_mode(i_mode)));
                     }
                     "startsWith" => {
                         let i_mode = Input::has_i_mode(map);
-                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &value.to_sql_string(r#type, false, graph).to_like(false, true).to_i_mode(i_mode)));
+                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &params.bind(&Self::like_pattern(value, false, true), r#type).to_i_mode(i_mode)));
                     }
                     "endsWith" => {
                         let i_mode = Input::has_i_mode(map);
-                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &value.to_sql_string(r#type, false, graph).to_like(true, false).to_i_mode(i_mode)));
+                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "LIKE", &params.bind(&Self::like_pattern(value, true, false), r#type).to_i_mode(i_mode)));
                     }
                     "matches" => {
                         let i_mode = Input::has_i_mode(map);
-                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "REGEXP", &value.to_sql_string(r#type, false, graph).to_i_mode(i_mode)));
+                        result.push(Self::where_item(&column_name.to_i_mode(i_mode), "REGEXP", &params.bind(value, r#type).to_i_mode(i_mode)));
                     }
                     "mode" => { }
                     "has" => {
-                        let element_type = r#type.element_field().unwrap();
-                        result.push(Self::where_item(&column_name, "@>", &value.to_sql_string_array_arg(element_type.field_type(), element_type.is_optional(), graph).wrap_in_array()));
+                        result.push(Self::where_item(&column_name, "@>", &params.bind(&Value::Vec(vec![value.clone()]), r#type)));
                     }
                     "hasEvery" => {
-                        result.push(Self::where_item(&column_name, "@>", &value.to_sql_string_array_arg(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, "@>", &params.bind(value, r#type)));
                     }
                     "hasSome" => {
-                        result.push(Self::where_item(&column_name, "&&", &value.to_sql_string_array_arg(r#type, false, graph)));
+                        result.push(Self::where_item(&column_name, "&&", &params.bind(value, r#type)));
                     }
                     "isEmpty" => {
                         result.push(Self::where_item(&format!("ARRAY_LENGTH({})", &column_name), "=", "0"));
                     }
                     "length" => {
-                        result.push(Self::where_item(&format!("ARRAY_LENGTH({})", &column_name), "=", &value.to_sql_string(&FieldType::I64, false, graph)));
+                        result.push(Self::where_item(&format!("ARRAY_LENGTH({})", &column_name), "=", &params.bind(value, &FieldType::I64)));
                     }
                     "_count" => {
-                        result.push(Self::where_entry_item(&format!("COUNT({})", &column_name), &FieldType::I64, false, value, graph, dialect));
+                        result.push(Self::where_entry_item(&format!("COUNT({})", &column_name), &FieldType::I64, value, dialect, params));
                     }
                     "_avg" | "_sum" => {
-                        result.push(Self::where_entry_item(&format!("{}({})", key[1..].to_uppercase(), &column_name), &FieldType::F64, true, value, graph, dialect));
+                        result.push(Self::where_entry_item(&format!("{}({})", key[1..].to_uppercase(), &column_name), &FieldType::F64, value, dialect, params));
                     }
                     "_min" | "_max" => {
-                        result.push(Self::where_entry_item(&format!("{}({})", key[1..].to_uppercase(), &column_name), r#type, optional, value, graph, dialect));
+                        result.push(Self::where_entry_item(&format!("{}({})", key[1..].to_uppercase(), &column_name), r#type, value, dialect, params));
                     }
                     _ => panic!("Unhandled key."),
                 }
             }
             And(result).to_wrapped_string(dialect)
         } else {
-            Query::where_item(column_name, "=", &value.to_sql_string(r#type, optional, graph))
+            Query::where_item(column_name, "=", &params.bind(value, r#type))
         }
     }
 
     fn where_entry(
         column_name: &str,
         field_type: &FieldType,
-        optional: bool,
         value: &Value,
-        graph: &Graph,
         dialect: SQLDialect,
+        params: &mut SQLParams,
     ) -> String {
-        Self::where_entry_item(column_name, field_type, optional, value, graph, dialect)
+        Self::where_entry_item(column_name, field_type, value, dialect, params)
     }
 
-    pub(crate) fn where_from_value(model: &Model, _graph: &Graph, identifier: &Value, dialect: SQLDialect) -> String {
+    pub(crate) fn where_from_value(model: &Model, _graph: &Graph, identifier: &Value, dialect: SQLDialect, params: &mut SQLParams) -> String {
         let mut retval: Vec<String> = vec![];
         for (key, value) in identifier.as_hashmap().unwrap() {
             let field = model.field(key).unwrap();
-            let column_name = field.column_name();
-            retval.push(format!("{} = {}", column_name, value.to_string(dialect)));
+            let column_name = field.column_name().escape(dialect);
+            retval.push(format!("{} = {}", column_name, params.bind(value, field.field_type())));
         }
         And(retval).to_string(dialect)
     }
 
-    pub(crate) fn r#where(model: &Model, graph: &Graph, r#where: &Value, dialect: SQLDialect, table_alias: Option<&str>) -> String {
+    pub(crate) fn r#where(model: &Model, graph: &Graph, r#where: &Value, dialect: SQLDialect, table_alias: Option<&str>, params: &mut SQLParams) -> String {
         let r#where = r#where.as_hashmap().unwrap();
         let mut retval: Vec<String> = vec![];
         for (key, value) in r#where.iter() {
             if key == "AND" {
-                let inner = WhereClause::And(value.as_vec().unwrap().iter().map(|w| Self::r#where(model, graph, w, dialect, table_alias)).collect()).to_string(dialect);
+                let inner = WhereClause::And(value.as_vec().unwrap().iter().map(|w| Self::r#where(model, graph, w, dialect, table_alias, params)).collect()).to_string(dialect);
                 let val = "(".to_owned() + &inner + ")";
                 retval.push(val);
             } else if key == "OR" {
-                let inner = WhereClause::Or(value.as_vec().unwrap().iter().map(|_w| Self::r#where(model, graph, value, dialect, table_alias)).collect()).to_string(dialect);
+                let inner = WhereClause::Or(value.as_vec().unwrap().iter().map(|_w| Self::r#where(model, graph, value, dialect, table_alias, params)).collect()).to_string(dialect);
                 let val = "(".to_owned() + &inner + ")";
                 retval.push(val);
             } else if key == "NOT" {
-                let inner = WhereClause::Not(Self::r#where(model, graph, value, dialect, table_alias)).to_string(dialect);
+                let inner = WhereClause::Not(Self::r#where(model, graph, value, dialect, table_alias, params)).to_string(dialect);
                 let val = "(".to_owned() + &inner + ")";
                 retval.push(val);
             } else {
                 if let Some(field) = model.field(key) {
-                    let column_name = field.column_name();
-                    let optional = field.optionality.is_optional();
+                    let column_name = field.column_name().escape(dialect);
                     let entry_column_name = if let Some(alias) = table_alias {
-                        Cow::Owned(format!("{}.{}", alias, column_name))
+                        format!("{}.{}", alias, column_name)
                     } else {
-                        Cow::Borrowed(column_name)
+                        column_name
                     };
-                    let where_entry = Query::where_entry(&entry_column_name, field.field_type(), optional, value, graph, dialect);
+                    let where_entry = Query::where_entry(&entry_column_name, field.field_type(), value, dialect, params);
                     retval.push(where_entry);
                 } else if let Some(relation) = model.relation(key) {
                     let has_join_table = relation.has_join_table();
@@ -230,7 +426,7 @@ impl Query {
                                 format!("t.{} IS NOT NULL", f.escape(dialect))
                             }).collect::<Vec<String>>().join(" AND ")
                         };
-                        let mut inner_where = Query::r#where(opposite_model, graph, value, dialect, Some("j"));
+                        let mut inner_where = Query::r#where(opposite_model, graph, value, dialect, Some("j"), params);
                         if key.as_str() == "every" {
                             inner_where = Not(inner_where.to_wrapped()).to_string(dialect).to_wrapped();
                         }
@@ -291,12 +487,13 @@ impl Query {
         graph: &Graph,
         value: &Value,
         dialect: SQLDialect,
-        additional_where: Option<String>,
+        additional_where: Option<&(dyn Fn(&mut SQLParams) -> String + Sync)>,
         additional_left_join: Option<String>,
         join_table_results: Option<Vec<String>>,
         force_negative_take: bool,
+        params: &mut SQLParams,
     ) -> String {
-        format!("SELECT COUNT(*) FROM ({}) AS _", Self::build(model, graph, value, dialect, additional_where, additional_left_join, join_table_results, force_negative_take))
+        format!("SELECT COUNT(*) FROM ({}) AS _", Self::build(model, graph, value, dialect, additional_where, additional_left_join, join_table_results, force_negative_take, params))
     }
 
     pub(crate) fn build_for_group_by(
@@ -304,15 +501,16 @@ impl Query {
         graph: &Graph,
         value: &Value,
         dialect: SQLDialect,
+        params: &mut SQLParams,
     ) -> String {
-        let aggregate = Self::build_for_aggregate(model, graph, value, dialect);
+        let aggregate = Self::build_for_aggregate(model, graph, value, dialect, params);
         let map = value.as_hashmap().unwrap();
         let by = map.get("by").unwrap().as_vec().unwrap().iter().map(|v| {
             let field_name = v.as_str().unwrap();
             model.field(field_name).unwrap().column_name()
         }).collect::<Vec<&str>>().join(",");
         let having = if let Some(having) = map.get("having") {
-            let inner = Query::r#where(model, graph, having, dialect, None);
+            let inner = Query::r#where(model, graph, having, dialect, None, params);
             " HAVING (".to_owned() + &inner + ")"
         } else {
             "".to_owned()
@@ -325,6 +523,7 @@ impl Query {
         graph: &Graph,
         value: &Value,
         dialect: SQLDialect,
+        params: &mut SQLParams,
     ) -> String {
         let map = value.as_hashmap().unwrap();
         let mut results: Vec<String> = vec![];
@@ -335,17 +534,21 @@ impl Query {
                         let k = k.as_str();
                         if v.as_bool().unwrap() {
                             match k {
-                                "_all" => results.push("COUNT(*) as `_count._all`".to_owned()),
+                                "_all" => results.push(format!("COUNT(*) as {}", "_count._all".escape(dialect))),
                                 _ => {
                                     let column_name = model.field(k).unwrap().column_name();
                                     let func = SQL_AGGREGATE_MAP.get(key.as_str()).unwrap();
                                     // CAST(AVG(id) as DOUBLE)
                                     let mut left = format!("{}({})", func, column_name);
                                     match key.as_str() {
-                                        "_avg" | "_sum" => left = format!("CAST({} AS DOUBLE)", left),
+                                        "_avg" | "_sum" => left = if dialect == SQLDialect::MSSQL {
+                                            format!("CAST({} AS FLOAT)", left)
+                                        } else {
+                                            format!("CAST({} AS DOUBLE)", left)
+                                        },
                                         _ => ()
                                     }
-                                    results.push(format!("{} as `{}.{}`", left, key, k));
+                                    results.push(format!("{} as {}", left, format!("{}.{}", key, k).as_str().escape(dialect)));
                                 }
                             }
                         }
@@ -360,7 +563,7 @@ impl Query {
                 results.push(model.field(field_name).unwrap().column_name().to_string());
             }
         }
-        format!("SELECT {} FROM ({}) AS _", results.join(","), Self::build(model, graph, value, dialect, None, None, None, false))
+        format!("SELECT {} FROM ({}) AS _", results.join(","), Self::build(model, graph, value, dialect, None, None, None, false, params))
     }
 
     pub(crate) fn build(
@@ -368,10 +571,11 @@ impl Query {
         graph: &Graph,
         value: &Value,
         dialect: SQLDialect,
-        additional_where: Option<String>,
+        additional_where: Option<&(dyn Fn(&mut SQLParams) -> String + Sync)>,
         additional_left_join: Option<String>,
         join_table_results: Option<Vec<String>>,
         force_negative_take: bool,
+        params: &mut SQLParams,
     ) -> String {
         let r#where = value.get("where");
         let order_by = value.get("orderBy");
@@ -407,14 +611,10 @@ impl Query {
             let key = order_by.keys().next().unwrap();
             let column_key = model.field(key).unwrap().column_name();
             let columns = cursor.as_hashmap().unwrap().keys().map(|_k| {
-                if dialect == SQLDialect::PostgreSQL {
-                    format!("{} AS \"c.{}\"", column_key, column_key)
-                } else {
-                    format!("{} AS `c.{}`", column_key, column_key)
-                }
+                format!("{} AS {}", column_key, format!("c.{}", column_key).as_str().escape(dialect))
             }).collect::<Vec<String>>();
             let column_refs: Vec<&str> = columns.iter().map(|k| k.as_str()).collect();
-            let sub_where = Query::r#where(model, graph, cursor, dialect, None);
+            let sub_where = Query::r#where(model, graph, cursor, dialect, None, params);
             let mut query = SQL::select(Some(&column_refs), &table_name);
             query.r#where(sub_where);
             Cow::Owned(format!("{}, ({}) AS c", &table_name, &query.to_string(dialect)))
@@ -424,10 +624,12 @@ impl Query {
         let mut stmt = SQL::select(if columns.is_empty() { None } else { Some(&column_refs) }, from.as_ref());
         if let Some(r#where) = r#where {
             if !r#where.as_hashmap().unwrap().is_empty() {
-                stmt.r#where(Query::r#where(model, graph, r#where, dialect, None));
+                stmt.r#where(Query::r#where(model, graph, r#where, dialect, None, params));
             }
         }
         if let Some(additional_where) = additional_where {
+            // built after the main where, so that its parameters come in statement order
+            let additional_where = additional_where(params);
             if stmt.r#where.is_some() {
                 stmt.r#where(And(vec![stmt.r#where.as_ref().unwrap().clone(), additional_where.to_string()]).to_string(dialect));
             } else {
@@ -439,7 +641,7 @@ impl Query {
             let key = order_by.keys().next().unwrap();
             let order = if order_by.values().next().unwrap().as_str().unwrap() == if negative_take { "desc" } else { "asc" }
                 { ">=" } else { "<=" };
-            let cursor_where = Query::where_item(&key, order, &format!("`c.{}`", key));
+            let cursor_where = Query::where_item(&key, order, &format!("c.{}", key).as_str().escape(dialect));
             if stmt.r#where.is_some() {
                 stmt.r#where(And(vec![stmt.r#where.as_ref().unwrap().clone(), cursor_where]).to_string(dialect));
             } else {
@@ -491,3 +693,52 @@ static SQL_AGGREGATE_MAP: Lazy<BTreeMap<&str, &str>> = Lazy::new(|| {
         "_max" => "MAX"
     }
 });
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use crate::connectors::sql::schema::dialect::SQLDialect;
+    use crate::connectors::sql::schema::value::params::SQLParams;
+    use crate::prelude::Value;
+    use super::Query;
+
+    fn json_filter(key: &str, dialect: SQLDialect) -> Result<String, String> {
+        let mut params = SQLParams::new(dialect);
+        let map = HashMap::from([(key.to_owned(), Value::String("teo".to_owned()))]);
+        let sql = Query::where_entry_json("data", &map, dialect, &mut params);
+        params.finalize(sql).map(|(sql, _)| sql).map_err(|err| err.message().to_owned())
+    }
+
+    #[test]
+    fn json_filters_are_rejected_on_sql_server() {
+        assert_eq!(json_filter("equals", SQLDialect::MSSQL), Err("JSON filters are not supported on SQL Server.".to_owned()));
+        assert_eq!(json_filter("contains", SQLDialect::MSSQL), Err("JSON filters are not supported on SQL Server.".to_owned()));
+        assert!(json_filter("equals", SQLDialect::PostgreSQL).is_ok());
+    }
+
+    #[test]
+    fn unknown_json_filter_is_an_error() {
+        assert_eq!(json_filter("startsWith", SQLDialect::SQLite), Err("Unknown JSON filter 'startsWith'.".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn sqlite_json_equals_ignores_key_order() {
+        use quaint_forked::prelude::Queryable;
+        use quaint_forked::single::Quaint;
+        let conn = Quaint::new_in_memory().unwrap();
+        conn.raw_cmd("CREATE TABLE records (data TEXT)").await.unwrap();
+        conn.raw_cmd(r#"INSERT INTO records (data) VALUES ('{"b":[1,{"y":null,"x":"s"}],"a":true}')"#).await.unwrap();
+        let document = Value::HashMap(HashMap::from([
+            ("a".to_owned(), Value::Bool(true)),
+            ("b".to_owned(), Value::Vec(vec![Value::I64(1), Value::IndexMap([("x".to_owned(), Value::String("s".to_owned())), ("y".to_owned(), Value::Null)].into_iter().collect())])),
+        ]));
+        for (key, value, count) in [("equals", document.clone(), 1), ("not", document, 0), ("equals", Value::HashMap(HashMap::from([("a".to_owned(), Value::Bool(true))])), 0)] {
+            let mut params = SQLParams::new(SQLDialect::SQLite);
+            let map = HashMap::from([(key.to_owned(), value)]);
+            let sql = Query::where_entry_json("data", &map, SQLDialect::SQLite, &mut params);
+            let (sql, values) = params.finalize(format!("SELECT COUNT(*) AS count FROM records WHERE {}", sql)).unwrap();
+            let row = conn.query_raw(&sql, &values).await.unwrap().into_single().unwrap();
+            assert_eq!(row["count"].as_i64(), Some(count), "{}", key);
+        }
+    }
+}
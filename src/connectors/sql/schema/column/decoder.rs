@@ -3,7 +3,8 @@ use std::sync::Arc;
 use itertools::Itertools;
 use maplit::{hashset};
 use quaint_forked::pooled::PooledConnection;
-use quaint_forked::prelude::{Query, Queryable, ResultRow, ResultSet};
+use quaint_forked::prelude::{ResultRow, ResultSet};
+use crate::connectors::sql::migration::migrate::SQLMigration;
 use crate::connectors::sql::migration::sql::psql_is_auto_increment;
 use crate::connectors::sql::schema::column::SQLColumn;
 use crate::connectors::sql::schema::dialect::SQLDialect;
@@ -13,6 +14,7 @@ use crate::core::model::index::ModelIndex;
 use crate::core::model::Model;
 use crate::core::pipeline::Pipeline;
 use crate::core::property::Property;
+use crate::core::result::Result;
 use crate::prelude::Value;
 
 #[derive(Debug)]
@@ -40,7 +42,9 @@ impl<'a> ColumnManipulation<'a> {
 
     pub(crate) fn priority(&self, model: &Model) -> i64 {
         match self {
-            ColumnManipulation::CreateIndex(_) => -100,
+            // indices may cover columns which are added or renamed by the same migration, so
+            // they're created after every column change
+            ColumnManipulation::CreateIndex(_) => i64::MAX,
             ColumnManipulation::DropIndex(_) => -100,
             _ => self.get_field(model).map(|f| f.migration().map(|m| m.priority.unwrap_or(0))).unwrap_or(Some(0)).unwrap_or(0)
         }
@@ -178,7 +182,8 @@ impl ColumnDecoder {
             let name = column.get("name").unwrap().as_str().unwrap();
             let r#type = column.get("type").unwrap().as_str().unwrap();
             let not_null = column.get("notnull").unwrap().as_bool().unwrap();
-            let pk = column.get("pk").unwrap().as_bool().unwrap();
+            // columns of a compound primary key are numbered by their position in the key
+            let pk = column.get("pk").unwrap().as_integer().unwrap() > 0;
             let unique_row = indices_iter.iter().find(|i| {
                 i.get("column_name").unwrap().as_str().unwrap() == name
             });
@@ -199,20 +204,52 @@ impl ColumnDecoder {
         result
     }
 
-    async fn psql_primary_field_name(conn: &PooledConnection, table_name: &str) -> Vec<String> {
+    pub(crate) fn decode_mssql_columns(columns: ResultSet, primary_columns: ResultSet) -> HashSet<SQLColumn> {
+        let primary_names: Vec<String> = primary_columns.into_iter().map(|r| {
+            r.get("column_name").unwrap().to_string().unwrap()
+        }).collect();
+        let mut result = hashset!{};
+        for column in columns {
+            let name = column.get("column_name").unwrap().to_string().unwrap();
+            let data_type = column.get("data_type").unwrap().to_string().unwrap();
+            let integer = |key: &str| column.get(key).map(|v| v.as_integer()).flatten();
+            let r#type = match data_type.as_str() {
+                "char" | "nchar" | "varchar" | "nvarchar" => match integer("character_maximum_length") {
+                    Some(-1) | None => format!("{data_type}(max)"),
+                    Some(length) => format!("{data_type}({length})"),
+                },
+                "decimal" | "numeric" => format!("{data_type}({},{})", integer("numeric_precision").unwrap(), integer("numeric_scale").unwrap()),
+                "float" => format!("{data_type}({})", integer("numeric_precision").unwrap()),
+                "datetime2" | "time" => format!("{data_type}({})", integer("datetime_precision").unwrap()),
+                _ => data_type,
+            };
+            let nullable = column.get("is_nullable").unwrap().to_string().unwrap() == "YES";
+            result.insert(SQLColumn {
+                name: name.clone(),
+                r#type: SQLTypeDecoder::decode(&r#type, SQLDialect::MSSQL),
+                not_null: !nullable,
+                auto_increment: integer("is_identity") == Some(1),
+                default: None,
+                primary_key: primary_names.contains(&name),
+            });
+        }
+        result
+    }
+
+    async fn psql_primary_field_name(conn: &PooledConnection, table_name: &str) -> Result<Vec<String>> {
         let sql = format!("SELECT a.attname
 FROM   pg_index i
 JOIN   pg_attribute a ON a.attrelid = i.indrelid
                      AND a.attnum = ANY(i.indkey)
 WHERE  i.indrelid = '{}'::regclass
 AND    i.indisprimary", table_name);
-        let result = conn.query(Query::from(sql)).await.unwrap();
-        result.into_iter().map(|r| {
+        let result = SQLMigration::query(conn, table_name, sql).await?;
+        Ok(result.into_iter().map(|r| {
             r.get("attname").unwrap().to_string().unwrap()
-        }).collect()
+        }).collect())
     }
 
-    async fn psql_is_unique(conn: &PooledConnection, table_name: &str, column_name: &str) -> bool {
+    async fn psql_is_unique(conn: &PooledConnection, table_name: &str, column_name: &str) -> Result<bool> {
         let sql = format!("SELECT *
             FROM INFORMATION_SCHEMA.TABLE_CONSTRAINTS tc
         inner join INFORMATION_SCHEMA.CONSTRAINT_COLUMN_USAGE cu
@@ -221,14 +258,14 @@ AND    i.indisprimary", table_name);
         tc.CONSTRAINT_TYPE = 'UNIQUE'
         and tc.TABLE_NAME = '{}'
         and cu.COLUMN_NAME = '{}'", table_name, column_name);
-        !conn.query(Query::from(sql)).await.unwrap().is_empty()
+        Ok(!SQLMigration::query(conn, table_name, sql).await?.is_empty())
     }
 
-    async fn psql_is_auto_increment(conn: &PooledConnection, table_name: &str, column_name: &str) -> bool {
-        !conn.query(Query::from(psql_is_auto_increment(table_name, column_name))).await.unwrap().is_empty()
+    async fn psql_is_auto_increment(conn: &PooledConnection, table_name: &str, column_name: &str) -> Result<bool> {
+        Ok(!SQLMigration::query(conn, table_name, psql_is_auto_increment(table_name, column_name)).await?.is_empty())
     }
 
-    pub(crate) async fn decode(row: ResultRow, dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> SQLColumn {
+    pub(crate) async fn decode(row: ResultRow, dialect: SQLDialect, conn: &PooledConnection, table_name: &str) -> Result<SQLColumn> {
         if dialect == SQLDialect::MySQL {
             let field: String = row.get("Field").unwrap().to_string().unwrap();
             let field_type_in_string: String = row.get("Type").unwrap().to_string().unwrap();
@@ -238,16 +275,16 @@ AND    i.indisprimary", table_name);
             let extra: String = row.get("Extra").unwrap().to_string().unwrap();
             let auto_increment = extra.contains("auto_increment");
             let primary = &key == "PRI";
-            SQLColumn {
+            Ok(SQLColumn {
                 name: field,
                 r#type: SQLTypeDecoder::decode(&field_type_in_string, dialect),
                 not_null: !null,
                 auto_increment,
                 default: None,
                 primary_key: primary,
-            }
+            })
         } else if dialect == SQLDialect::PostgreSQL { // postgres
-            let primary_names = Self::psql_primary_field_name(conn, table_name).await;
+            let primary_names = Self::psql_primary_field_name(conn, table_name).await?;
             let column_name: String = row.get("column_name").unwrap().to_string().unwrap();
             let nullable_text: String = row.get("is_nullable").unwrap().to_string().unwrap();
             let nullable: bool = nullable_text == "YES";
@@ -257,14 +294,14 @@ AND    i.indisprimary", table_name);
                 udt_name.remove(0);
                 data_type = data_type + "|" + udt_name.as_str()
             }
-            SQLColumn {
+            Ok(SQLColumn {
                 name: column_name.clone(),
                 r#type: SQLTypeDecoder::decode(&data_type, dialect),
                 not_null: !nullable,
                 default: None,
                 primary_key: primary_names.contains(&column_name),
-                auto_increment: Self::psql_is_auto_increment(conn, table_name, &column_name).await,
-            }
+                auto_increment: Self::psql_is_auto_increment(conn, table_name, &column_name).await?,
+            })
         } else {
             unreachable!()
         }
@@ -53,6 +53,10 @@ impl SQLColumn {
     pub(crate) fn set_default(&mut self, default: Option<String>) {
         self.default = default;
     }
+
+    pub(crate) fn set_primary_key(&mut self, primary_key: bool) {
+        self.primary_key = primary_key;
+    }
 }
 
 impl ToSQLString for SQLColumn {
@@ -65,6 +69,8 @@ impl ToSQLString for SQLColumn {
         let auto_inc = if self.auto_increment {
             if dialect == SQLDialect::MySQL {
                 " AUTO_INCREMENT"
+            } else if dialect == SQLDialect::MSSQL {
+                " IDENTITY(1,1)"
             } else {
                 " AUTOINCREMENT"
             }
@@ -76,6 +82,8 @@ impl ToSQLString for SQLColumn {
                 t
             };
             format!("\"{name}\" {t_with_auto_inc}{default}{not_null}{primary}")
+        } else if dialect == SQLDialect::MSSQL {
+            format!("[{name}] {t}{auto_inc}{default}{not_null}{primary}")
         } else {
             format!("`{name}` {t}{default}{not_null}{primary}{auto_inc}")
         }
@@ -10,6 +10,15 @@ impl SQLDialect {
     pub(crate) fn escape(&self) -> &str {
         match self {
             SQLDialect::PostgreSQL => "\"",
+            SQLDialect::MSSQL => "[",
+            _ => "`",
+        }
+    }
+
+    pub(crate) fn escape_end(&self) -> &str {
+        match self {
+            SQLDialect::PostgreSQL => "\"",
+            SQLDialect::MSSQL => "]",
             _ => "`",
         }
     }
@@ -0,0 +1,151 @@
+use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::value::encode::ToSQLString;
+use crate::core::model::Model;
+use crate::core::relation::delete_rule::DeleteRule;
+use crate::core::relation::update_rule::UpdateRule;
+
+const MAX_CONSTRAINT_NAME_LEN: usize = 63;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub(crate) enum SQLReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
+impl SQLReferentialAction {
+
+    pub(crate) fn from_delete_rule(rule: DeleteRule) -> Self {
+        match rule {
+            DeleteRule::Default => SQLReferentialAction::NoAction,
+            DeleteRule::Nullify => SQLReferentialAction::SetNull,
+            DeleteRule::Cascade => SQLReferentialAction::Cascade,
+            DeleteRule::Deny => SQLReferentialAction::Restrict,
+        }
+    }
+
+    pub(crate) fn from_update_rule(rule: UpdateRule) -> Self {
+        match rule {
+            UpdateRule::Default => SQLReferentialAction::NoAction,
+            UpdateRule::Nullify => SQLReferentialAction::SetNull,
+            UpdateRule::Cascade => SQLReferentialAction::Cascade,
+            UpdateRule::Deny => SQLReferentialAction::Restrict,
+        }
+    }
+
+    /// Decodes the rule names of MySQL's information schema, the action codes of PostgreSQL's
+    /// `pg_constraint` and the action descriptions of SQL Server's `sys.foreign_keys`.
+    pub(crate) fn from_db_str(s: &str) -> Self {
+        match s {
+            "RESTRICT" | "r" => SQLReferentialAction::Restrict,
+            "CASCADE" | "c" => SQLReferentialAction::Cascade,
+            "SET NULL" | "SET_NULL" | "n" => SQLReferentialAction::SetNull,
+            _ => SQLReferentialAction::NoAction,
+        }
+    }
+
+    /// SQL Server doesn't have `RESTRICT`, `NO ACTION` behaves the same there.
+    pub(crate) fn normalized(self, dialect: SQLDialect) -> Self {
+        if dialect == SQLDialect::MSSQL && self == SQLReferentialAction::Restrict {
+            SQLReferentialAction::NoAction
+        } else {
+            self
+        }
+    }
+
+    pub(crate) fn to_str(self) -> &'static str {
+        match self {
+            SQLReferentialAction::NoAction => "NO ACTION",
+            SQLReferentialAction::Restrict => "RESTRICT",
+            SQLReferentialAction::Cascade => "CASCADE",
+            SQLReferentialAction::SetNull => "SET NULL",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub(crate) struct SQLForeignKey {
+    pub(crate) name: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) referenced_table: String,
+    pub(crate) referenced_columns: Vec<String>,
+    pub(crate) on_delete: SQLReferentialAction,
+    pub(crate) on_update: SQLReferentialAction,
+}
+
+impl SQLForeignKey {
+
+    /// Returns foreign keys derived from the relations of `model` which hold the foreign key.
+    /// Relations with both the default delete rule and the default update rule don't restrict
+    /// the database, so no constraint is declared for them.
+    pub(crate) fn from_model(model: &Model, models: &[Model]) -> Vec<SQLForeignKey> {
+        let mut result = vec![];
+        for relation in model.relations() {
+            if relation.through().is_some() || !relation.has_foreign_key() { continue }
+            if relation.delete_rule() == DeleteRule::Default && relation.update_rule() == UpdateRule::Default { continue }
+            let referenced_model = match models.iter().find(|m| m.name() == relation.model()) {
+                Some(referenced_model) => referenced_model,
+                None => continue,
+            };
+            if referenced_model.r#virtual() { continue }
+            let columns: Vec<String> = relation.fields().iter().map(|f| model.field(f).unwrap().column_name().to_owned()).collect();
+            let referenced_columns: Vec<String> = relation.references().iter().map(|f| referenced_model.field(f).unwrap().column_name().to_owned()).collect();
+            result.push(SQLForeignKey {
+                name: Self::constraint_name(model.table_name(), &columns),
+                columns,
+                referenced_table: referenced_model.table_name().to_owned(),
+                referenced_columns,
+                on_delete: SQLReferentialAction::from_delete_rule(relation.delete_rule()),
+                on_update: SQLReferentialAction::from_update_rule(relation.update_rule()),
+            });
+        }
+        result
+    }
+
+    /// PostgreSQL cuts identifiers at 63 bytes and MySQL rejects names longer than 64
+    /// characters. Long names are shortened and suffixed with a stable hash of the full name,
+    /// so the same relation always maps to the same constraint.
+    fn constraint_name(table_name: &str, columns: &[String]) -> String {
+        let name = format!("{}_{}_fkey", table_name, columns.join("_"));
+        if name.len() <= MAX_CONSTRAINT_NAME_LEN { return name }
+        let hash = name.bytes().fold(0x811c9dc5u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193));
+        let mut end = MAX_CONSTRAINT_NAME_LEN - 9;
+        while !name.is_char_boundary(end) { end -= 1 }
+        format!("{}_{:08x}", &name[..end], hash)
+    }
+}
+
+impl ToSQLString for SQLForeignKey {
+    fn to_string(&self, dialect: SQLDialect) -> String {
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let name = &self.name;
+        let columns = self.columns.iter().map(|c| format!("{escape}{c}{escape_end}")).collect::<Vec<String>>().join(", ");
+        let referenced_table = &self.referenced_table;
+        let referenced_columns = self.referenced_columns.iter().map(|c| format!("{escape}{c}{escape_end}")).collect::<Vec<String>>().join(", ");
+        let on_delete = self.on_delete.normalized(dialect).to_str();
+        let on_update = self.on_update.normalized(dialect).to_str();
+        format!("CONSTRAINT {escape}{name}{escape_end} FOREIGN KEY ({columns}) REFERENCES {escape}{referenced_table}{escape_end}({referenced_columns}) ON DELETE {on_delete} ON UPDATE {on_update}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_constraint_name_is_kept() {
+        assert_eq!(SQLForeignKey::constraint_name("posts", &["authorCode".to_owned()]), "posts_authorCode_fkey");
+    }
+
+    #[test]
+    fn long_constraint_name_is_shortened_stably() {
+        let columns = vec!["organizationIdentifier".to_owned(), "departmentIdentifier".to_owned()];
+        let name = SQLForeignKey::constraint_name("organization_department_memberships", &columns);
+        assert_eq!(name.len(), MAX_CONSTRAINT_NAME_LEN);
+        assert!(name.starts_with("organization_department_memberships_organizationIdent"));
+        assert_eq!(name, SQLForeignKey::constraint_name("organization_department_memberships", &columns));
+        assert_ne!(name, SQLForeignKey::constraint_name("organization_department_memberships", &["organizationIdentifier".to_owned(), "departmentIdentifierCode".to_owned()]));
+    }
+}
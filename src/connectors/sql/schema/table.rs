@@ -1,3 +1,4 @@
+use crate::connectors::sql::schema::column::SQLColumn;
 use crate::connectors::sql::stmts::create::table::SQLCreateTableStatement;
 use crate::connectors::sql::stmts::SQL;
 use crate::core::model::Model;
@@ -6,8 +7,16 @@ impl From<&Model> for SQLCreateTableStatement {
     fn from(model: &Model) -> Self {
         let mut stmt = SQL::create().table(model.table_name());
         stmt.if_not_exists();
+        let compound_primary = model.primary_index().keys().len() > 1;
         for field in model.fields() {
-            stmt.column(field.into());
+            let mut column: SQLColumn = field.into();
+            if compound_primary {
+                column.set_primary_key(false);
+            }
+            stmt.column(column);
+        }
+        if compound_primary {
+            stmt.primary_key(model.primary_index().keys().iter().map(|k| model.field(k).unwrap().column_name().to_owned()).collect());
         }
         for property in model.properties() {
             if property.cached {
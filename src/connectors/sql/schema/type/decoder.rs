@@ -41,6 +41,9 @@ fn mysql_type_to_database_type(r#type: &str) -> DatabaseType {
                 "varchar" => DatabaseType::VarChar { m: arg.map(|a| u16::from_str(a).unwrap()).unwrap(), n: None, c: None },
                 "date" => DatabaseType::Date,
                 "datetime" => DatabaseType::DateTime(u8::from_str(arg.unwrap()).unwrap()),
+                "time" => DatabaseType::Time(arg.map(|a| u8::from_str(a).unwrap()).unwrap_or(0), false),
+                "json" => DatabaseType::Json,
+                "longblob" => DatabaseType::LongBlob,
                 "decimal" => {
                     if let Some(args) = arg {
                         let args = args.split(",").into_iter().collect::<Vec<&str>>();
@@ -68,6 +71,10 @@ fn postgresql_type_to_database_type(r#type: &str) -> DatabaseType {
         "integer" | "int4" => DatabaseType::Int { m: None, u: false },
         "text" => DatabaseType::Text { m: None, n: None, c: None },
         "timestamp without time zone" | "timestamp" => DatabaseType::Timestamp { p: 3, z: false },
+        "time without time zone" | "time" => DatabaseType::Time(3, false),
+        "json" => DatabaseType::Json,
+        "jsonb" => DatabaseType::JsonB,
+        "bytea" => DatabaseType::ByteA,
         "boolean" | "bool" => DatabaseType::Bool,
         "bigint" | "int8" => DatabaseType::BigInt { m: None, u: false },
         "double precision" | "float8" => DatabaseType::Double { m: None, d: None },
@@ -98,6 +105,7 @@ fn sqlite_type_to_database_type(r#type: &str) -> DatabaseType {
                 "text" => DatabaseType::Text { m: None, n: None, c: None },
                 "real" => DatabaseType::Real,
                 "double" => DatabaseType::Double { m: None, d: None },
+                "blob" => DatabaseType::LongBlob,
                 "decimal" => {
                     if let Some(args) = arg {
                         let args = args.split(",").into_iter().collect::<Vec<&str>>();
@@ -113,7 +121,50 @@ fn sqlite_type_to_database_type(r#type: &str) -> DatabaseType {
 }
 
 fn mssql_type_to_database_type(r#type: &str) -> DatabaseType {
-    match r#type.to_lowercase().as_str() {
-        _ => panic!("Unhandled database type.")
+    let r#type_string = r#type.to_lowercase();
+    let r#type: &str = r#type_string.as_str();
+    let regex = Regex::new("([^ \\(\\)]+)(\\((.+)\\))?").unwrap();
+    match regex.captures(r#type) {
+        None => panic!("Unhandled database type '{}' '{}'.", r#type, regex),
+        Some(captures) => {
+            let name = captures.get(1).unwrap().as_str();
+            let arg = captures.get(3).map(|m| m.as_str());
+            match name {
+                "bit" => DatabaseType::Bool,
+                "tinyint" => DatabaseType::TinyInt { m: None, u: false },
+                "smallint" => DatabaseType::SmallInt { m: None, u: false },
+                "int" => DatabaseType::Int { m: None, u: false },
+                "bigint" => DatabaseType::BigInt { m: None, u: false },
+                "real" => DatabaseType::Real,
+                "float" => if arg.is_some_and(|a| u8::from_str(a).unwrap() <= 24) {
+                    DatabaseType::Real
+                } else {
+                    DatabaseType::Double { m: None, d: None }
+                },
+                "char" | "nchar" => DatabaseType::Char { m: arg.map(|a| u8::from_str(a).unwrap()), n: None, c: None },
+                "varchar" | "nvarchar" => match arg {
+                    Some("max") | Some("-1") | None => DatabaseType::Text { m: None, n: None, c: None },
+                    Some(a) => DatabaseType::VarChar { m: u16::from_str(a).unwrap(), n: None, c: None },
+                },
+                "text" | "ntext" => DatabaseType::Text { m: None, n: None, c: None },
+                "varbinary" => match arg {
+                    Some("max") | Some("-1") | None => DatabaseType::VarBinary(None),
+                    Some(a) => DatabaseType::VarBinary(Some(u16::from_str(a).unwrap())),
+                },
+                "date" => DatabaseType::Date,
+                "datetime2" => DatabaseType::DateTime(arg.map(|a| u8::from_str(a).unwrap()).unwrap_or(7)),
+                "datetime" => DatabaseType::DateTime(3),
+                "time" => DatabaseType::Time(arg.map(|a| u8::from_str(a).unwrap()).unwrap_or(7), false),
+                "decimal" | "numeric" => {
+                    if let Some(args) = arg {
+                        let args = args.split(",").map(|a| a.trim()).collect::<Vec<&str>>();
+                        DatabaseType::Decimal { m: Some(args.get(0).unwrap().parse().unwrap()), d: Some(args.get(1).unwrap().parse().unwrap()) }
+                    } else {
+                        DatabaseType::Decimal { m: None, d: None }
+                    }
+                }
+                _ => panic!("Unhandled type '{}' '{:?}'.", name, arg)
+            }
+        }
     }
 }
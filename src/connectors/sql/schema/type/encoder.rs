@@ -57,6 +57,8 @@ impl ToSQLString for DatabaseType {
             DatabaseType::Double { m: _m, d: _d } => {
                 if dialect == SQLDialect::PostgreSQL {
                     "DOUBLE PRECISION".to_string()
+                } else if dialect == SQLDialect::MSSQL {
+                    "FLOAT(53)".to_string()
                 } else {
                     "DOUBLE".to_string()
                 }
@@ -69,7 +71,11 @@ impl ToSQLString for DatabaseType {
                 }
             }
             DatabaseType::Date => "DATE".to_string(),
-            DatabaseType::DateTime(fsp) => format!("DATETIME({fsp})"),
+            DatabaseType::DateTime(fsp) => if dialect == SQLDialect::MSSQL {
+                format!("DATETIME2({fsp})")
+            } else {
+                format!("DATETIME({fsp})")
+            },
             DatabaseType::Timestamp { p, z } => {
                 if dialect == SQLDialect::PostgreSQL {
                     let tzinfo = if *z { " WITH TIME ZONE" } else { "" };
@@ -78,7 +84,11 @@ impl ToSQLString for DatabaseType {
                     format!("TIMESTAMP({p})")
                 }
             }
-            DatabaseType::Time(_, _) => todo!(),
+            DatabaseType::Time(fsp, z) => if dialect == SQLDialect::PostgreSQL && *z {
+                format!("TIME({fsp}) WITH TIME ZONE")
+            } else {
+                format!("TIME({fsp})")
+            },
             DatabaseType::Year => "YEAR".to_string(),
             DatabaseType::Char { m, n, c } => {
                 let arg = if let Some(m) = m {
@@ -96,6 +106,12 @@ impl ToSQLString for DatabaseType {
             }
             DatabaseType::VarChar { m, n, c } => {
                 let arg = format!("({})", m);
+                if dialect == SQLDialect::MSSQL {
+                    let collation = if let Some(v) = c {
+                        Cow::Owned(format!(" COLLATE {v}"))
+                    } else { Cow::Borrowed("") };
+                    return format!("NVARCHAR{arg}{collation}");
+                }
                 let charset = if let Some(v) = n {
                     Cow::Owned(format!(" CHARACTER SET {v}"))
                 } else { Cow::Borrowed("") };
@@ -140,17 +156,25 @@ impl ToSQLString for DatabaseType {
                         format!(" COLLATION {v}")
                     } else { "".to_string() };
                     format!("TEXT{charset}{collation}")
+                } else if dialect == SQLDialect::MSSQL {
+                    "NVARCHAR(MAX)".to_string()
                 } else {
                     "TEXT".to_string()
                 }
             }
             DatabaseType::Binary(l) => format!("BINARY({l})"),
-            DatabaseType::VarBinary(l) => format!("VARBINARY({l})"),
+            DatabaseType::VarBinary(Some(l)) => format!("VARBINARY({l})"),
+            DatabaseType::VarBinary(None) => "VARBINARY(MAX)".to_string(),
             DatabaseType::TinyBlob => "TINYBLOB".to_string(),
             DatabaseType::MediumBlob => "MEDIUMBLOB".to_string(),
-            DatabaseType::LongBlob => "LONGBLOB".to_string(),
+            DatabaseType::LongBlob => match dialect {
+                SQLDialect::SQLite => "BLOB".to_string(),
+                _ => "LONGBLOB".to_string(),
+            }
             DatabaseType::Blob(l) => format!("BLOB({l})"),
             DatabaseType::ByteA => "bytea".to_string(),
+            DatabaseType::Json => "JSON".to_string(),
+            DatabaseType::JsonB => "JSONB".to_string(),
             DatabaseType::Int32 => panic!("SQL databases don't support Int32."),
             DatabaseType::Int64 => panic!("SQL databases don't support Int64."),
             DatabaseType::String => panic!("SQL databases don't support String."),
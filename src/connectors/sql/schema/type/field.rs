@@ -1,13 +1,15 @@
 use crate::connectors::sql::schema::dialect::SQLDialect;
 use crate::core::database::r#type::DatabaseType;
+use crate::core::error::Error;
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
+use crate::core::result::Result;
 
 pub trait ToDatabaseType {
-    fn to_database_type(&self, dialect: SQLDialect) -> DatabaseType;
+    fn to_database_type(&self, dialect: SQLDialect) -> Result<DatabaseType>;
 }
 
 impl ToDatabaseType for FieldType {
-    fn to_database_type(&self, dialect: SQLDialect) -> DatabaseType {
+    fn to_database_type(&self, dialect: SQLDialect) -> Result<DatabaseType> {
         match dialect {
             SQLDialect::SQLite => default_database_type_sqlite(self),
             SQLDialect::MySQL => default_database_type_mysql(self),
@@ -17,14 +19,27 @@ impl ToDatabaseType for FieldType {
     }
 }
 
-fn default_database_type_mssql(field_type: &FieldType) -> DatabaseType {
-    match field_type {
-        _ => panic!("Unhandled."),
-    }
+fn default_database_type_mssql(field_type: &FieldType) -> Result<DatabaseType> {
+    Ok(match field_type {
+        FieldType::Bool => DatabaseType::Bool,
+        FieldType::I32 => DatabaseType::Int { m: None, u: false },
+        FieldType::I64 => DatabaseType::BigInt { m: None, u: false },
+        FieldType::F32 => DatabaseType::Real,
+        FieldType::F64 => DatabaseType::Double { m: None, d: None },
+        FieldType::String => DatabaseType::VarChar { m: 191, n: None, c: None },
+        FieldType::Date => DatabaseType::Date,
+        FieldType::DateTime => DatabaseType::DateTime(3),
+        FieldType::Time => DatabaseType::Time(3, false),
+        FieldType::Bytes => DatabaseType::VarBinary(None),
+        FieldType::Json => DatabaseType::Text { m: None, n: None, c: None },
+        FieldType::Decimal => DatabaseType::Decimal { m: Some(32), d: Some(16) },
+        FieldType::Enum(_) => DatabaseType::VarChar { m: 191, n: None, c: None },
+        _ => return Err(unsupported_field_type("SQL Server", field_type)),
+    })
 }
 
-fn default_database_type_mysql(field_type: &FieldType) -> DatabaseType {
-    match field_type {
+fn default_database_type_mysql(field_type: &FieldType) -> Result<DatabaseType> {
+    Ok(match field_type {
         FieldType::Bool => DatabaseType::TinyInt { m: Some(1), u: false },
         FieldType::I32 => DatabaseType::Int { m: None, u: false },
         FieldType::I64 => DatabaseType::BigInt { m: None, u: false },
@@ -33,18 +48,17 @@ fn default_database_type_mysql(field_type: &FieldType) -> DatabaseType {
         FieldType::String => DatabaseType::VarChar { m: 191, n: None, c: None },
         FieldType::Date => DatabaseType::Date,
         FieldType::DateTime => DatabaseType::DateTime(3),
+        FieldType::Time => DatabaseType::Time(3, false),
+        FieldType::Bytes => DatabaseType::LongBlob,
+        FieldType::Json => DatabaseType::Json,
         FieldType::Enum(enum_def) => DatabaseType::Enum(enum_def.into()),
         FieldType::Decimal => DatabaseType::Decimal { m: Some(65), d: Some(30) },
-        FieldType::Vec(_) => panic!(),
-        FieldType::HashMap(_) => panic!(),
-        FieldType::BTreeMap(_) => panic!(),
-        FieldType::Object(_) => panic!(),
-        _ => panic!(),
-    }
+        _ => return Err(unsupported_field_type("MySQL", field_type)),
+    })
 }
 
-fn default_database_type_postgresql(field_type: &FieldType) -> DatabaseType {
-    match field_type {
+fn default_database_type_postgresql(field_type: &FieldType) -> Result<DatabaseType> {
+    Ok(match field_type {
         FieldType::Bool => DatabaseType::Bool,
         FieldType::I32 => DatabaseType::Int { m: None, u: false },
         FieldType::I64 => DatabaseType::BigInt { m: None, u: false },
@@ -53,18 +67,18 @@ fn default_database_type_postgresql(field_type: &FieldType) -> DatabaseType {
         FieldType::String => DatabaseType::Text { m: None, n: None, c: None },
         FieldType::Date => DatabaseType::Date,
         FieldType::DateTime => DatabaseType::Timestamp { p: 3, z: false },
+        FieldType::Time => DatabaseType::Time(3, false),
+        FieldType::Bytes => DatabaseType::ByteA,
+        FieldType::Json => DatabaseType::JsonB,
         FieldType::Decimal => DatabaseType::Decimal { m: Some(65), d: Some(30) },
         FieldType::Enum(_) => DatabaseType::Text { m: None, n: None, c: None },
-        FieldType::Vec(inner) => DatabaseType::Vec(Box::new(default_database_type_postgresql(inner.field_type()))),
-        FieldType::HashMap(_) => panic!(),
-        FieldType::BTreeMap(_) => panic!(),
-        FieldType::Object(_) => panic!(),
-        _ => panic!(),
-    }
+        FieldType::Vec(inner) => DatabaseType::Vec(Box::new(default_database_type_postgresql(inner.field_type())?)),
+        _ => return Err(unsupported_field_type("PostgreSQL", field_type)),
+    })
 }
 
-fn default_database_type_sqlite(field_type: &FieldType) -> DatabaseType {
-    match field_type {
+fn default_database_type_sqlite(field_type: &FieldType) -> Result<DatabaseType> {
+    Ok(match field_type {
         FieldType::Bool => DatabaseType::Int { m: None, u: false, },
         FieldType::I32 => DatabaseType::Int { m: None, u: false },
         FieldType::I64 => DatabaseType::Int { m: None, u: false },
@@ -73,12 +87,21 @@ fn default_database_type_sqlite(field_type: &FieldType) -> DatabaseType {
         FieldType::String => DatabaseType::Text { m: None, n: None, c: None },
         FieldType::Date => DatabaseType::Text { m: None, n: None, c: None },
         FieldType::DateTime => DatabaseType::Text { m: None, n: None, c: None },
+        FieldType::Time => DatabaseType::Text { m: None, n: None, c: None },
+        FieldType::Bytes => DatabaseType::LongBlob,
+        FieldType::Json => DatabaseType::Text { m: None, n: None, c: None },
         FieldType::Decimal => DatabaseType::Decimal { m: None, d: None },
         FieldType::Enum(_) => DatabaseType::Text { m: None, n: None, c: None },
-        FieldType::Vec(_) => panic!(),
-        FieldType::HashMap(_) => panic!(),
-        FieldType::BTreeMap(_) => panic!(),
-        FieldType::Object(_) => panic!(),
-        _ => panic!(),
-    }
+        _ => return Err(unsupported_field_type("SQLite", field_type)),
+    })
+}
+
+fn unsupported_field_type(database: &str, field_type: &FieldType) -> Error {
+    let description = match field_type {
+        FieldType::Vec(_) => "array",
+        FieldType::HashMap(_) | FieldType::BTreeMap(_) => "dictionary",
+        FieldType::Object(_) => "object",
+        _ => "ObjectId",
+    };
+    Error::invalid_operation(format!("{} doesn't support {} fields.", database, description))
 }
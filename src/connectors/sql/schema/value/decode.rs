@@ -1,7 +1,10 @@
 use crate::connectors::sql::schema::dialect::SQLDialect;
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
+use crate::core::teon::decoder::Decoder;
+use crate::core::error::Error;
+use crate::core::result::Result;
 use crate::core::teon::Value;
-use chrono::{NaiveDate, DateTime, Utc};
+use chrono::{NaiveDate, NaiveTime, DateTime, Utc};
 use indexmap::IndexMap;
 use quaint_forked::prelude::{ResultRow, ResultSet, Value as QuaintValue};
 
@@ -47,6 +50,12 @@ impl RowDecoder {
                     None => Value::Null,
                 }
             }
+            quaint_forked::Value::Time(t) => {
+                match t {
+                    Some(t) => Value::Time(*t),
+                    None => Value::Null,
+                }
+            }
             quaint_forked::Value::Int32(i) => {
                 match i {
                     Some(i) => Value::I32(*i),
@@ -65,6 +74,18 @@ impl RowDecoder {
                     None => Value::Null,
                 }
             }
+            quaint_forked::Value::Bytes(b) => {
+                match b {
+                    Some(b) => Value::Bytes(b.to_vec()),
+                    None => Value::Null,
+                }
+            }
+            quaint_forked::Value::Json(j) => {
+                match j {
+                    Some(j) => Decoder::decode_json(j),
+                    None => Value::Null,
+                }
+            }
             _ => unreachable!()
         }
     }
@@ -100,131 +121,173 @@ impl RowDecoder {
         }
     }
 
-    pub(crate) fn decode_value(r#type: &FieldType, optional: bool, value: Option<&quaint_forked::Value>, dialect: SQLDialect) -> Value {
+    pub(crate) fn decode_value(r#type: &FieldType, optional: bool, value: Option<&quaint_forked::Value>, dialect: SQLDialect) -> Result<Value> {
         if optional {
             if value.is_none() {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         let value = value.unwrap();
         if r#type.is_bool() {
             if let Some(v) = value.as_bool() {
-                return Value::Bool(v)
+                return Ok(Value::Bool(v))
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_string() {
             if let Some(v) = value.as_str() {
-                return Value::String(v.to_owned())
+                return Ok(Value::String(v.to_owned()))
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_int32() {
             if let Some(v) = value.as_i32() {
-                return Value::I32(v);
+                return Ok(Value::I32(v));
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_int64() {
             if let Some(v) = value.as_i64() {
-                return Value::I64(v);
+                return Ok(Value::I64(v));
             } else if let Some(v) = value.as_i32() {
-                return Value::I32(v);
+                return Ok(Value::I32(v));
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_float32() || r#type.is_float64() {
             if let Some(f64_val) = value.as_f64() {
-                return Value::number_from_f64(f64_val, r#type);
+                return Ok(Value::number_from_f64(f64_val, r#type));
             } else if let Some(f32_val) = value.as_f32() {
-                return Value::number_from_f32(f32_val, r#type);
+                return Ok(Value::number_from_f32(f32_val, r#type));
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_date() {
             if dialect == SQLDialect::PostgreSQL {
                 if let Some(naive_date) = value.as_date() {
-                    return Value::Date(naive_date);
+                    return Ok(Value::Date(naive_date));
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             } else if dialect == SQLDialect::SQLite {
                 if let Some(timestamp) = value.as_str() {
-                    let naive_date = NaiveDate::parse_from_str(timestamp, "%Y-%m-%d").unwrap();
-                    return Value::Date(naive_date);
+                    return match NaiveDate::parse_from_str(timestamp, "%Y-%m-%d") {
+                        Ok(naive_date) => Ok(Value::Date(naive_date)),
+                        Err(_) => Err(unreadable_value("date", timestamp)),
+                    };
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             } else if dialect == SQLDialect::MySQL {
                 if let Some(datetime) = value.as_datetime() {
                     let naive_date = datetime.date_naive();
-                    return Value::Date(naive_date);
+                    return Ok(Value::Date(naive_date));
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             } else {
                 if let Some(naive_date) = value.as_date() {
-                    return Value::Date(naive_date);
+                    return Ok(Value::Date(naive_date));
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             }
         }
         if r#type.is_datetime() {
             if dialect == SQLDialect::PostgreSQL {
                 if let Some(datetime) = value.as_datetime() {
-                    return Value::DateTime(datetime);
+                    return Ok(Value::DateTime(datetime));
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             } else if dialect == SQLDialect::SQLite {
                 if let Some(timestamp) = value.as_str() {
-                    return Value::DateTime(DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc));
+                    return match DateTime::parse_from_rfc3339(timestamp) {
+                        Ok(datetime) => Ok(Value::DateTime(datetime.with_timezone(&Utc))),
+                        Err(_) => Err(unreadable_value("datetime", timestamp)),
+                    };
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             } else {
                 if let Some(datetime) = value.as_datetime() {
-                    return Value::DateTime(datetime);
+                    return Ok(Value::DateTime(datetime));
+                } else {
+                    return Ok(Value::Null);
+                }
+            }
+        }
+        if r#type.is_time() {
+            if dialect == SQLDialect::SQLite {
+                if let Some(time) = value.as_str() {
+                    return match NaiveTime::parse_from_str(time, "%H:%M:%S%.f") {
+                        Ok(time) => Ok(Value::Time(time)),
+                        Err(_) => Err(unreadable_value("time", time)),
+                    };
+                } else {
+                    return Ok(Value::Null);
+                }
+            } else {
+                if let Some(time) = value.as_time() {
+                    return Ok(Value::Time(time));
                 } else {
-                    return Value::Null;
+                    return Ok(Value::Null);
                 }
             }
         }
+        if r#type.is_bytes() {
+            if let Some(bytes) = value.to_bytes() {
+                return Ok(Value::Bytes(bytes));
+            } else {
+                return Ok(Value::Null);
+            }
+        }
+        if r#type.is_json() {
+            if let Some(json) = value.as_json() {
+                return Ok(Decoder::decode_json(json));
+            } else if let Some(text) = value.as_str() {
+                return match serde_json::from_str(text) {
+                    Ok(json) => Ok(Decoder::decode_json(&json)),
+                    Err(_) => Err(unreadable_value("JSON", text)),
+                };
+            } else {
+                return Ok(Value::Null);
+            }
+        }
         if r#type.is_decimal() {
             if let Some(val) = value.as_numeric() {
-                return Value::Decimal(val.clone());
+                return Ok(Value::Decimal(val.clone()));
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_vec() {
             if let Some(vals) = value.as_array() {
                 let inner = r#type.element_field().unwrap();
-                return Value::Vec(vals.iter().map(|v| Self::decode_value(inner.field_type(), inner.is_optional(), Some(v), dialect)).collect());
+                return Ok(Value::Vec(vals.iter().map(|v| Self::decode_value(inner.field_type(), inner.is_optional(), Some(v), dialect)).collect::<Result<Vec<Value>>>()?));
             } else {
-                return Value::Null;
+                return Ok(Value::Null);
             }
         }
         if r#type.is_enum() {
             match value {
                 QuaintValue::Enum(v) => {
                     if let Some(v) = v {
-                        return Value::String(v.as_ref().to_owned());
+                        return Ok(Value::String(v.as_ref().to_owned()));
                     } else {
-                        return Value::Null;
+                        return Ok(Value::Null);
                     }
                 }
                 QuaintValue::Text(v) => {
                     if let Some(v) = v {
-                        return Value::String(v.as_ref().to_owned());
+                        return Ok(Value::String(v.as_ref().to_owned()));
                     } else {
-                        return Value::Null;
+                        return Ok(Value::Null);
                     }
                 }
                 _ => panic!("unhandled enum variant"),
@@ -233,8 +296,35 @@ impl RowDecoder {
         panic!("Unhandled database when decoding type.")
     }
 
-    pub(crate) fn decode(r#type: &FieldType, optional: bool, row: &ResultRow, column_name: &str, dialect: SQLDialect) -> Value {
+    pub(crate) fn decode(r#type: &FieldType, optional: bool, row: &ResultRow, column_name: &str, dialect: SQLDialect) -> Result<Value> {
         let result = row.get(column_name);
         Self::decode_value(r#type, optional, result.clone(), dialect)
     }
 }
+
+fn unreadable_value(r#type: &str, text: &str) -> Error {
+    Error::internal_server_error(format!("Cannot read {} value '{}' from database.", r#type, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use quaint_forked::prelude::Value as QuaintValue;
+    use crate::connectors::sql::schema::dialect::SQLDialect;
+    use crate::core::field::r#type::FieldType;
+    use crate::core::teon::Value;
+    use super::RowDecoder;
+
+    #[test]
+    fn sqlite_time_is_parsed() {
+        let value = QuaintValue::text("21:45:10.250");
+        let decoded = RowDecoder::decode_value(&FieldType::Time, false, Some(&value), SQLDialect::SQLite).unwrap();
+        assert_eq!(decoded, Value::Time(NaiveTime::from_hms_milli_opt(21, 45, 10, 250).unwrap()));
+    }
+
+    #[test]
+    fn unreadable_sqlite_time_is_an_error() {
+        let value = QuaintValue::text("quarter past nine");
+        assert!(RowDecoder::decode_value(&FieldType::Time, false, Some(&value), SQLDialect::SQLite).is_err());
+    }
+}
@@ -1,9 +1,14 @@
+use std::borrow::Cow;
 use bigdecimal::BigDecimal;
-use chrono::{NaiveDate, Utc, DateTime, SecondsFormat};
+use chrono::{NaiveDate, NaiveTime, Utc, DateTime, SecondsFormat};
 use itertools::Itertools;
+use quaint_forked::prelude::Value as QuaintValue;
+use serde_json::Value as JsonValue;
 use crate::connectors::sql::schema::dialect::SQLDialect;
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
-use crate::prelude::{Graph, Value};
+use crate::core::error::Error;
+use crate::prelude::Value;
+use crate::core::result::Result;
 
 pub trait ToSQLString {
     fn to_string(&self, dialect: SQLDialect) -> String;
@@ -19,90 +24,53 @@ impl TypeOrNull for &str {
     }
 }
 
-pub(crate) trait ValueToSQLString {
-    fn to_sql_string<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String;
-    fn to_sql_string_array_arg<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String;
+pub(crate) trait ToSQLParam {
+    fn to_sql_param(&self, r#type: &FieldType, dialect: SQLDialect) -> Result<QuaintValue<'static>>;
 }
 
-impl ValueToSQLString for Value {
-    fn to_sql_string<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String {
-        if optional {
-            if self.is_null() {
-                return "NULL".to_owned()
-            }
-        }
-        match r#type {
+impl ToSQLParam for Value {
+    fn to_sql_param(&self, r#type: &FieldType, dialect: SQLDialect) -> Result<QuaintValue<'static>> {
+        Ok(match r#type {
             #[cfg(feature = "data-source-mongodb")]
-            FieldType::ObjectId => panic!("SQL doesn't support `ObjectId`."),
-            FieldType::String => ToSQLInput::to_sql_input(&self.as_str().unwrap()),
-            FieldType::Bool => self.as_bool().unwrap().to_sql_input(),
-            FieldType::I32 | FieldType::I64 |
-            FieldType::F32 | FieldType::F64 => if let Some(val) = self.as_f64() {
-                val.to_string()
-            } else if let Some(val) = self.as_i64() {
-                val.to_string()
+            FieldType::ObjectId => return Err(Error::internal_server_error("SQL doesn't support `ObjectId`.")),
+            FieldType::String | FieldType::Enum(_) => QuaintValue::Text(self.as_str().map(|s| Cow::Owned(s.to_owned()))),
+            FieldType::Bool => QuaintValue::Boolean(self.as_bool()),
+            FieldType::I32 => QuaintValue::Int32(self.as_i32()),
+            FieldType::I64 => QuaintValue::Int64(self.as_i64()),
+            FieldType::F32 => QuaintValue::Float(self.as_f32()),
+            FieldType::F64 => QuaintValue::Double(self.as_f64()),
+            FieldType::Decimal => QuaintValue::Numeric(self.as_decimal()),
+            FieldType::Date => if dialect == SQLDialect::SQLite {
+                QuaintValue::Text(self.as_date().map(|d| Cow::Owned(d.format("%Y-%m-%d").to_string())))
             } else {
-                panic!("Uncoded number.")
-            }
-            FieldType::Enum(_) => ToSQLInput::to_sql_input(&self.as_str().unwrap()),
-            FieldType::Vec(element_field) => {
-                let val = self.as_vec().unwrap();
-                let mut result: Vec<String> = vec![];
-                for (_i, v) in val.iter().enumerate() {
-                    result.push(v.to_sql_string(element_field.field_type(), element_field.is_optional(), graph));
-                }
-                result.join(", ").wrap_in_array()
-            }
-            FieldType::Date => self.as_date().unwrap().to_string().to_sql_input(),
-            FieldType::DateTime => self.as_datetime().unwrap().to_string().to_sql_input(),
-            FieldType::Decimal => self.as_decimal().unwrap().to_string().to_sql_input(),
-            _ => { panic!() }
-        }
-    }
-
-    fn to_sql_string_array_arg<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String {
-        if optional {
-            if self.is_null() {
-                return "NULL".to_owned()
-            }
-        }
-        match r#type {
-            #[cfg(feature = "data-source-mongodb")]
-            FieldType::ObjectId => panic!("SQL doesn't support `ObjectId`."),
-            FieldType::String => ToSQLInputDialect::to_sql_input(&self.as_str().unwrap(), SQLDialect::PostgreSQL),
-            FieldType::Bool => self.as_bool().unwrap().to_sql_input(),
-            FieldType::I32 | FieldType::I64 |
-            FieldType::F32 | FieldType::F64 => if let Some(val) = self.as_f64() {
-                val.to_string()
-            } else if let Some(val) = self.as_i64() {
-                val.to_string()
+                QuaintValue::Date(self.as_date().cloned())
+            },
+            FieldType::DateTime => if dialect == SQLDialect::SQLite {
+                QuaintValue::Text(self.as_datetime().map(|d| Cow::Owned(d.to_rfc3339_opts(SecondsFormat::Millis, true))))
             } else {
-                panic!("Uncoded number.")
-            }
-            FieldType::Enum(_) => ToSQLInput::to_sql_input(&self.as_str().unwrap()),
-            FieldType::Vec(element_field) => {
-                let val = self.as_vec().unwrap();
-                let mut result: Vec<String> = vec![];
-                for (_i, v) in val.iter().enumerate() {
-                    result.push(v.to_sql_string_array_arg(element_field.field_type(), element_field.is_optional(), graph));
-                }
-                result.join(",").wrap_in_array()
-            }
-            FieldType::Date => self.as_date().unwrap().to_string(),
-            FieldType::DateTime => self.as_datetime().unwrap().to_string(),
-            FieldType::Decimal => self.as_decimal().unwrap().to_string(),
-            _ => { panic!() }
-        }
-    }
-}
-
-impl ValueToSQLString for &Value {
-    fn to_sql_string<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String {
-        (*self).to_sql_string(r#type, optional, graph)
-    }
-
-    fn to_sql_string_array_arg<'a>(&self, r#type: &FieldType, optional: bool, graph: &Graph) -> String {
-        (*self).to_sql_string_array_arg(r#type, optional, graph)
+                QuaintValue::DateTime(self.as_datetime().cloned())
+            },
+            // quaint drops the fraction of a second when binding a MySQL time
+            FieldType::Time => if dialect == SQLDialect::SQLite || dialect == SQLDialect::MySQL {
+                QuaintValue::Text(self.as_time().map(|t| Cow::Owned(t.format("%H:%M:%S%.f").to_string())))
+            } else {
+                QuaintValue::Time(self.as_time().cloned())
+            },
+            FieldType::Bytes => QuaintValue::Bytes(self.as_bytes().map(|b| Cow::Owned(b.clone()))),
+            // JSON parameters are bound as JSONB by quaint, other databases parse the text
+            FieldType::Json => if self.is_null() {
+                QuaintValue::Text(None)
+            } else if dialect == SQLDialect::PostgreSQL {
+                QuaintValue::Json(Some(self.into()))
+            } else {
+                QuaintValue::Text(Some(Cow::Owned(Into::<JsonValue>::into(self).to_string())))
+            },
+            FieldType::Vec(element_field) => QuaintValue::Array(match self.as_vec() {
+                Some(values) => Some(values.iter().map(|v| v.to_sql_param(element_field.field_type(), dialect)).collect::<Result<Vec<QuaintValue<'static>>>>()?),
+                None => None,
+            }),
+            FieldType::HashMap(_) | FieldType::BTreeMap(_) | FieldType::Object(_) => return Err(Error::internal_server_error(format!("Values of type `{:?}` can't be bound as SQL parameters.", r#type))),
+        })
     }
 }
 
@@ -115,48 +83,22 @@ impl ToSQLString for Value {
             Value::I64(i) => i.to_string(),
             Value::F32(i) => i.to_string(),
             Value::F64(i) => i.to_string(),
-            Value::Bool(b) => b.to_sql_input(),
+            Value::Bool(b) => if dialect == SQLDialect::MSSQL {
+                (if *b { "1" } else { "0" }).to_owned()
+            } else {
+                b.to_sql_input()
+            },
             Value::Date(d) => d.to_sql_input(dialect),
             Value::DateTime(d) => d.to_sql_input(dialect),
+            Value::Time(t) => t.to_sql_input(dialect),
             Value::Decimal(d) => d.to_sql_input(dialect),
+            Value::Bytes(b) => b.to_sql_input(dialect),
             Value::Vec(values) => format!("array[{}]", values.iter().map(|v| v.to_string(dialect)).join(",")),
             _ => panic!("unhandled"),
         }
     }
 }
 
-pub(crate) trait PSQLArrayToSQLString {
-    fn to_string_with_ft(&self, dialect: SQLDialect, field_type: &FieldType) -> String;
-}
-
-fn field_type_to_psql(field_type: &FieldType) -> &'static str {
-    match field_type {
-        FieldType::Decimal => "decimal",
-        FieldType::I32 | FieldType::I64 => "integer",
-        FieldType::F32 | FieldType::F64 => "double precision",
-        FieldType::String => "text",
-        FieldType::Bool => "boolean",
-        FieldType::Date => "date",
-        FieldType::DateTime => "timestamp",
-        _ => unreachable!(),
-    }
-}
-
-impl PSQLArrayToSQLString for Value {
-    fn to_string_with_ft(&self, dialect: SQLDialect, field_type: &FieldType) -> String {
-        match self {
-            Value::Vec(values) => if values.is_empty() {
-                format!("array[]::{}[]", field_type_to_psql(field_type.element_field().unwrap().field_type()))
-            } else {
-                format!("array[{}]", values.iter().map(|v| {
-                    v.to_string(dialect)
-                }).join(","))
-            },
-            _ => self.to_string(dialect),
-        }
-    }
-}
-
 pub trait ToWrapped {
     fn to_wrapped(&self) -> String;
 }
@@ -205,22 +147,6 @@ impl ToSQLInput for &str {
     }
 }
 
-impl ToSQLInputDialect for &str {
-    fn to_sql_input(&self, _dialect: SQLDialect) -> String {
-        let mut result = String::with_capacity(self.len() + 2);
-        result.push('\"');
-        for ch in self.chars() {
-            match ch {
-                '\'' => result.push_str("\\\""),
-                _ => result.push(ch)
-            }
-        }
-        result.push('\"');
-        result
-    }
-}
-
-
 impl ToSQLInput for bool {
     fn to_sql_input(&self) -> String {
         if *self { "TRUE".to_owned() } else { "FALSE".to_owned() }
@@ -264,6 +190,28 @@ impl ToSQLInputDialect for DateTime<Utc> {
     }
 }
 
+impl ToSQLInputDialect for NaiveTime {
+    fn to_sql_input(&self, dialect: SQLDialect) -> String {
+        let result = self.format("%H:%M:%S%.f").to_string().to_sql_input();
+        if dialect == SQLDialect::PostgreSQL {
+            result + "::time"
+        } else {
+            result
+        }
+    }
+}
+
+impl ToSQLInputDialect for Vec<u8> {
+    fn to_sql_input(&self, dialect: SQLDialect) -> String {
+        let hex = self.iter().map(|b| format!("{:02x}", b)).join("");
+        match dialect {
+            SQLDialect::PostgreSQL => format!("'\\x{hex}'::bytea"),
+            SQLDialect::MSSQL => format!("0x{hex}"),
+            _ => format!("X'{hex}'"),
+        }
+    }
+}
+
 pub trait IfIMode {
     fn to_i_mode(&self, i_mode: bool) -> String;
 }
@@ -284,48 +232,6 @@ impl IfIMode for String {
     }
 }
 
-pub trait ToLike {
-    fn to_like(&self, left: bool, right: bool) -> String;
-}
-
-impl ToLike for &str {
-    fn to_like(&self, left: bool, right: bool) -> String {
-        let mut retval = "".to_owned();
-        retval.push(self.chars().nth(0).unwrap());
-        if left {
-            retval.push('%');
-        }
-        retval += &self[1..self.len() - 1];
-        if right {
-            retval.push('%');
-        }
-        retval.push(self.chars().nth(self.len() - 1).unwrap());
-        retval
-    }
-}
-
-impl ToLike for String {
-    fn to_like(&self, left: bool, right: bool) -> String {
-        self.as_str().to_like(left, right)
-    }
-}
-
-pub trait WrapInArray {
-    fn wrap_in_array(&self) -> String;
-}
-
-impl WrapInArray for &str {
-    fn wrap_in_array(&self) -> String {
-        "\'{".to_owned() + self + "}\'"
-    }
-}
-
-impl WrapInArray for String {
-    fn wrap_in_array(&self) -> String {
-        self.as_str().wrap_in_array()
-    }
-}
-
 pub trait SQLEscape {
     fn escape(&self, dialect: SQLDialect) -> String;
 }
@@ -335,6 +241,7 @@ impl SQLEscape for &str {
         match dialect {
             SQLDialect::MySQL => format!("`{}`", self),
             SQLDialect::PostgreSQL => format!("\"{}\"", self),
+            SQLDialect::MSSQL => format!("[{}]", self),
             _ => format!("`{}`", self),
         }
     }
@@ -0,0 +1,64 @@
+use quaint_forked::prelude::Value as QuaintValue;
+use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::value::encode::ToSQLParam;
+use crate::core::error::Error;
+use crate::core::field::r#type::FieldType;
+use crate::core::result::Result;
+use crate::prelude::Value;
+
+/// Collects the bound parameters of a statement while it's being built.
+///
+/// Each pushed value returns the dialect's own placeholder: `$n` for PostgreSQL, `@Pn` for SQL
+/// Server, `?n` for SQLite and a positional `?` for MySQL. As MySQL placeholders carry no
+/// index, fragments must push their values in the order they appear in the statement.
+pub(crate) struct SQLParams {
+    dialect: SQLDialect,
+    values: Vec<QuaintValue<'static>>,
+    error: Option<Error>,
+}
+
+impl SQLParams {
+
+    pub(crate) fn new(dialect: SQLDialect) -> Self {
+        Self { dialect, values: vec![], error: None }
+    }
+
+    pub(crate) fn push(&mut self, value: QuaintValue<'static>) -> String {
+        self.values.push(value);
+        let index = self.values.len();
+        match self.dialect {
+            SQLDialect::PostgreSQL => format!("${}", index),
+            SQLDialect::MSSQL => format!("@P{}", index),
+            SQLDialect::SQLite => format!("?{}", index),
+            SQLDialect::MySQL => "?".to_owned(),
+        }
+    }
+
+    /// Binds `value` as a parameter of `type`. Values which can't be bound are recorded and
+    /// reported by `finalize`, so that fragments can keep being built inline.
+    pub(crate) fn bind(&mut self, value: &Value, r#type: &FieldType) -> String {
+        match value.to_sql_param(r#type, self.dialect) {
+            Ok(value) => self.push(value),
+            Err(err) => {
+                self.fail(err);
+                self.push(QuaintValue::Text(None))
+            }
+        }
+    }
+
+    /// Records the error of a fragment which can't be built. It's reported by `finalize`, the
+    /// returned fragment only keeps the statement well formed.
+    pub(crate) fn fail(&mut self, err: Error) -> String {
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+        "NULL".to_owned()
+    }
+
+    pub(crate) fn finalize(self, sql: String) -> Result<(String, Vec<QuaintValue<'static>>)> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok((sql, self.values)),
+        }
+    }
+}
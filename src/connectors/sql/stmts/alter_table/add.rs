@@ -11,7 +11,8 @@ impl ToSQLString for SQLAlterTableAddStatement {
     fn to_string(&self, dialect: SQLDialect) -> String {
         let table = &self.table;
         let def = self.column_def.to_string(dialect);
-        let escape = if dialect == SQLDialect::PostgreSQL { "\"" } else { "`" };
-        format!("ALTER TABLE {escape}{table}{escape} ADD {def}")
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        format!("ALTER TABLE {escape}{table}{escape_end} ADD {def}")
     }
 }
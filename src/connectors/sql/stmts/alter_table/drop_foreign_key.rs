@@ -0,0 +1,21 @@
+use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::value::encode::ToSQLString;
+
+pub struct SQLAlterTableDropForeignKeyStatement {
+    pub(crate) table: String,
+    pub(crate) name: String,
+}
+
+impl ToSQLString for SQLAlterTableDropForeignKeyStatement {
+    fn to_string(&self, dialect: SQLDialect) -> String {
+        let table = &self.table;
+        let name = &self.name;
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        if dialect == SQLDialect::PostgreSQL || dialect == SQLDialect::MSSQL {
+            format!("ALTER TABLE {escape}{table}{escape_end} DROP CONSTRAINT {escape}{name}{escape_end}")
+        } else {
+            format!("ALTER TABLE {escape}{table}{escape_end} DROP FOREIGN KEY {escape}{name}{escape_end}")
+        }
+    }
+}
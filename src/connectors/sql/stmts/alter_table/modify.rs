@@ -12,13 +12,19 @@ impl ToSQLString for SQLAlterTableModifyStatement {
         let table = &self.table;
         let def = self.column.to_string(dialect);
         let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
         if dialect == SQLDialect::SQLite {
-            format!("ALTER TABLE {escape}{table}{escape} ({def})")
+            format!("ALTER TABLE {escape}{table}{escape_end} ({def})")
         } else if dialect == SQLDialect::PostgreSQL {
             let c_name = self.column.name();
-            format!("ALTER TABLE {escape}{table}{escape} ALTER COLUMN {escape}{c_name}{escape} TYPE column_definition;")
+            format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{c_name}{escape_end} TYPE column_definition;")
+        } else if dialect == SQLDialect::MSSQL {
+            let c_name = self.column.name();
+            let t = self.column.r#type().to_string(dialect);
+            let null = if self.column.not_null() { "NOT NULL" } else { "NULL" };
+            format!("ALTER TABLE {escape}{table}{escape_end} ALTER COLUMN {escape}{c_name}{escape_end} {t} {null}")
         } else {
-            format!("ALTER TABLE {escape}{table}{escape} MODIFY {def}")
+            format!("ALTER TABLE {escape}{table}{escape_end} MODIFY {def}")
         }
     }
 }
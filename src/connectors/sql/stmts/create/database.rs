@@ -19,6 +19,13 @@ impl ToSQLString for SQLCreateDatabaseStatement {
         let if_not_exists = if self.if_not_exists { " IF NOT EXISTS" } else { "" };
         if dialect == SQLDialect::PostgreSQL {
             format!("CREATE DATABASE{if_not_exists} {database};")
+        } else if dialect == SQLDialect::MSSQL {
+            let create = format!("CREATE DATABASE [{database}];");
+            if self.if_not_exists {
+                format!("IF DB_ID(N'{database}') IS NULL {create}")
+            } else {
+                create
+            }
         } else {
             format!("CREATE DATABASE{if_not_exists} `{database}`;")
         }
@@ -1,11 +1,15 @@
+use itertools::Itertools;
 use crate::connectors::sql::schema::column::SQLColumn;
 use crate::connectors::sql::schema::dialect::SQLDialect;
+use crate::connectors::sql::schema::foreign_key::SQLForeignKey;
 use crate::connectors::sql::schema::value::encode::ToSQLString;
 
 pub(crate) struct SQLCreateTableStatement {
     pub(crate) table: String,
     pub(crate) if_not_exists: bool,
-    pub(crate) columns: Vec<SQLColumn>
+    pub(crate) columns: Vec<SQLColumn>,
+    pub(crate) primary_key: Vec<String>,
+    pub(crate) foreign_keys: Vec<SQLForeignKey>,
 }
 
 impl SQLCreateTableStatement {
@@ -23,17 +27,43 @@ impl SQLCreateTableStatement {
         self.columns.extend(defs);
         self
     }
+
+    /// Declares a table level primary key. Used for compound primary keys, single column primary
+    /// keys are declared inline with the column.
+    pub(crate) fn primary_key(&mut self, columns: Vec<String>) -> &mut Self {
+        self.primary_key = columns;
+        self
+    }
+
+    pub(crate) fn foreign_keys(&mut self, defs: Vec<SQLForeignKey>) -> &mut Self {
+        self.foreign_keys.extend(defs);
+        self
+    }
 }
 
 impl ToSQLString for SQLCreateTableStatement {
     fn to_string(&self, dialect: SQLDialect) -> String {
         let if_not_exists = if self.if_not_exists { " IF NOT EXISTS" } else { "" };
         let table_name = &self.table;
+        let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
+        let primary_key = if self.primary_key.is_empty() { None } else {
+            Some(format!("PRIMARY KEY ({})", self.primary_key.iter().map(|c| format!("{escape}{c}{escape_end}")).join(", ")))
+        };
         let columns = self.columns.iter().map(|c| {
             c.to_string(dialect)
-        }).collect::<Vec<String>>().join(", ");
+        }).chain(primary_key).chain(self.foreign_keys.iter().map(|f| {
+            f.to_string(dialect)
+        })).collect::<Vec<String>>().join(", ");
         if dialect == SQLDialect::PostgreSQL {
             format!("CREATE TABLE{if_not_exists} \"{table_name}\"( {columns} );")
+        } else if dialect == SQLDialect::MSSQL {
+            let create = format!("CREATE TABLE [{table_name}]( {columns} );");
+            if self.if_not_exists {
+                format!("IF OBJECT_ID(N'{table_name}', N'U') IS NULL {create}")
+            } else {
+                create
+            }
         } else {
             format!("CREATE TABLE{if_not_exists} `{table_name}`( {columns} );")
         }
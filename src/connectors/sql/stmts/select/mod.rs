@@ -39,6 +39,31 @@ impl<'a> SQLSelectStatement<'a> {
         self.limit = Some((limit, skip));
         self
     }
+
+    // SQL Server has no LIMIT clause. A plain take becomes TOP, anything with a skip needs
+    // OFFSET FETCH, which is only allowed after an ORDER BY.
+    fn mssql_to_string(&self, columns: String, left_join: String, inner_join: String, r#where: String) -> String {
+        let mut top = "".to_owned();
+        let mut order_by = if let Some(order_by) = &self.order_by {
+            " ORDER BY ".to_owned() + order_by
+        } else {
+            "".to_owned()
+        };
+        if let Some((limit, skip)) = self.limit {
+            if skip == 0 && limit < i64::MAX as u64 {
+                top = format!("TOP ({limit}) ");
+            } else {
+                if order_by.is_empty() {
+                    order_by = " ORDER BY (SELECT NULL)".to_owned();
+                }
+                order_by += &format!(" OFFSET {skip} ROWS");
+                if limit < i64::MAX as u64 {
+                    order_by += &format!(" FETCH NEXT {limit} ROWS ONLY");
+                }
+            }
+        }
+        format!("SELECT {top}{columns} from {}{}{}{}{}", self.from, left_join, inner_join, r#where, order_by)
+    }
 }
 
 impl<'a> ToSQLString for SQLSelectStatement<'a> {
@@ -59,6 +84,9 @@ impl<'a> ToSQLString for SQLSelectStatement<'a> {
         } else {
             "".to_owned()
         };
+        if dialect == SQLDialect::MSSQL {
+            return self.mssql_to_string(columns, left_join, inner_join, r#where);
+        }
         let order_by = if let Some(order_by) = &self.order_by {
             " ORDER BY ".to_owned() + order_by
         } else {
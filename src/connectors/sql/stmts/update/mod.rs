@@ -30,6 +30,8 @@ impl<'a> ToSQLString for SQLUpdateStatement<'a> {
         for (k, v) in self.values.iter() {
             if dialect == SQLDialect::PostgreSQL {
                 exprs.push(format!("\"{}\" = {}", k, v));
+            } else if dialect == SQLDialect::MSSQL {
+                exprs.push(format!("[{}] = {}", k, v));
             } else {
                 exprs.push(format!("`{}` = {}", k, v));
             }
@@ -41,6 +43,8 @@ impl<'a> ToSQLString for SQLUpdateStatement<'a> {
         };
         if dialect == SQLDialect::PostgreSQL {
             format!("UPDATE \"{}\" SET {}{};", self.table, exprs.join(","), r#where)
+        } else if dialect == SQLDialect::MSSQL {
+            format!("UPDATE [{}] SET {}{};", self.table, exprs.join(","), r#where)
         } else {
             format!("UPDATE `{}` SET {}{};", self.table, exprs.join(","), r#where)
         }
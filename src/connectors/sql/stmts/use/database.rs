@@ -6,8 +6,12 @@ pub(crate) struct SQLUseDatabaseStatement {
 }
 
 impl ToSQLString for SQLUseDatabaseStatement {
-    fn to_string(&self, _dialect: SQLDialect) -> String {
+    fn to_string(&self, dialect: SQLDialect) -> String {
         let database = &self.database;
-        format!("USE `{database}`")
+        if dialect == SQLDialect::MSSQL {
+            format!("USE [{database}]")
+        } else {
+            format!("USE `{database}`")
+        }
     }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod source;
+pub(crate) mod request;
 
 use std::collections::HashSet;
 use std::slice::Iter;
@@ -20,6 +21,8 @@ pub(crate) const COUNT: u32 = 1 << 11;
 pub(crate) const AGGREGATE: u32 = 1 << 12;
 pub(crate) const GROUP_BY: u32 = 1 << 13;
 pub(crate) const PROGRAM_CODE: u32 = 1 << 14;
+pub(crate) const REFRESH_TOKEN: u32 = 1 << 21;
+pub(crate) const SIGN_OUT: u32 = 1 << 22;
 
 pub(crate) const UPSERT: u32 = CREATE | UPDATE;
 pub(crate) const CONNECT_OR_CREATE: u32 = CONNECT | CREATE;
@@ -35,7 +38,7 @@ pub(crate) const SINGLE: u32 = 1 << 18;
 pub(crate) const MANY: u32 = 1 << 19;
 pub(crate) const INTERNAL_AMOUNT: u32 = 1 << 20;
 
-const ALL_NAMES: u32 = CREATE | UPDATE | UPSERT | DELETE | FIND | FIND_FIRST | CONNECT | CONNECT_OR_CREATE | DISCONNECT | SET | JOIN_CREATE | JOIN_DELETE | IDENTITY | SIGN_IN | COUNT | AGGREGATE | GROUP_BY;
+const ALL_NAMES: u32 = CREATE | UPDATE | UPSERT | DELETE | FIND | FIND_FIRST | CONNECT | CONNECT_OR_CREATE | DISCONNECT | SET | JOIN_CREATE | JOIN_DELETE | IDENTITY | SIGN_IN | SIGN_OUT | REFRESH_TOKEN | COUNT | AGGREGATE | GROUP_BY;
 const ALL_POSITIONS: u32 = ENTRY | NESTED | INTERNAL_POSITION;
 const ALL_AMOUNTS: u32 = SINGLE | MANY | INTERNAL_AMOUNT;
 
@@ -58,6 +61,8 @@ pub(crate) const AGGREGATE_HANDLER: u32 = AGGREGATE | ENTRY;
 pub(crate) const GROUP_BY_HANDLER: u32 = GROUP_BY | ENTRY;
 pub(crate) const SIGN_IN_HANDLER: u32 = SIGN_IN | ENTRY;
 pub(crate) const IDENTITY_HANDLER: u32 = IDENTITY | ENTRY;
+pub(crate) const REFRESH_TOKEN_HANDLER: u32 = REFRESH_TOKEN | ENTRY;
+pub(crate) const SIGN_OUT_HANDLER: u32 = SIGN_OUT | ENTRY;
 
 pub(crate) const NESTED_CREATE_ACTION: u32 = CREATE | NESTED | SINGLE;
 pub(crate) const NESTED_UPDATE_ACTION: u32 = UPDATE | NESTED | SINGLE;
@@ -109,6 +114,8 @@ impl Action {
                 "internalAmount" => INTERNAL_AMOUNT,
                 "programCode" => PROGRAM_CODE,
                 "identity" => IDENTITY,
+                "refreshToken" => REFRESH_TOKEN,
+                "signOut" => SIGN_OUT,
                 _ => panic!("Unrecognized action option name '{}'.", name)
             }
         }
@@ -227,6 +234,8 @@ impl Action {
             GROUP_BY_HANDLER => &GROUP_BY_INPUT_JSON_KEYS,
             SIGN_IN_HANDLER => &SIGN_IN_INPUT_JSON_KEYS,
             IDENTITY_HANDLER => &IDENTITY_INPUT_JSON_KEYS,
+            REFRESH_TOKEN_HANDLER => &REFRESH_TOKEN_INPUT_JSON_KEYS,
+            SIGN_OUT_HANDLER => &SIGN_OUT_INPUT_JSON_KEYS,
             _ => unreachable!()
         }
     }
@@ -290,6 +299,8 @@ impl Action {
             GROUP_BY_HANDLER => ResMeta::NoMeta,
             SIGN_IN_HANDLER => ResMeta::TokenInfo,
             IDENTITY_HANDLER => ResMeta::NoMeta,
+            REFRESH_TOKEN_HANDLER => ResMeta::TokenInfo,
+            SIGN_OUT_HANDLER => ResMeta::NoMeta,
             _ => unreachable!()
         }
     }
@@ -311,6 +322,8 @@ impl Action {
             GROUP_BY_HANDLER => ResData::Other,
             SIGN_IN_HANDLER => ResData::Single,
             IDENTITY_HANDLER => ResData::Single,
+            REFRESH_TOKEN_HANDLER => ResData::Single,
+            SIGN_OUT_HANDLER => ResData::Single,
             _ => unreachable!()
         }
     }
@@ -332,6 +345,8 @@ impl Action {
             GROUP_BY_HANDLER => "groupBy",
             SIGN_IN_HANDLER => "signIn",
             IDENTITY_HANDLER => "identity",
+            REFRESH_TOKEN_HANDLER => "refreshToken",
+            SIGN_OUT_HANDLER => "signOut",
             _ => unreachable!()
         }
     }
@@ -354,13 +369,15 @@ impl Action {
                 "groupBy" => GROUP_BY_HANDLER,
                 "signIn" => SIGN_IN_HANDLER,
                 "identity" => IDENTITY_HANDLER,
+                "refreshToken" => REFRESH_TOKEN_HANDLER,
+                "signOut" => SIGN_OUT_HANDLER,
                 _ => None?
             }
         })
     }
 
     pub(crate) fn handlers_iter() -> Iter<'static, Action> {
-        static HANDLER_TYPES: [Action; 15] = [
+        static HANDLER_TYPES: [Action; 17] = [
             Action::from_u32(FIND_UNIQUE_HANDLER),
             Action::from_u32(FIND_FIRST_HANDLER),
             Action::from_u32(FIND_MANY_HANDLER),
@@ -376,6 +393,8 @@ impl Action {
             Action::from_u32(GROUP_BY_HANDLER),
             Action::from_u32(SIGN_IN_HANDLER),
             Action::from_u32(IDENTITY_HANDLER),
+            Action::from_u32(REFRESH_TOKEN_HANDLER),
+            Action::from_u32(SIGN_OUT_HANDLER),
         ];
         HANDLER_TYPES.iter()
     }
@@ -397,6 +416,8 @@ impl Action {
             Action::from_u32(GROUP_BY_HANDLER),
             Action::from_u32(SIGN_IN_HANDLER),
             Action::from_u32(IDENTITY_HANDLER),
+            Action::from_u32(REFRESH_TOKEN_HANDLER),
+            Action::from_u32(SIGN_OUT_HANDLER),
         ].iter().map(|x| *x))
     }
 
@@ -481,3 +502,9 @@ static SIGN_IN_INPUT_JSON_KEYS: Lazy<HashSet<&str>> = Lazy::new(|| {
 static IDENTITY_INPUT_JSON_KEYS: Lazy<HashSet<&str>> = Lazy::new(|| {
     hashset! {"include", "select"}
 });
+static REFRESH_TOKEN_INPUT_JSON_KEYS: Lazy<HashSet<&str>> = Lazy::new(|| {
+    hashset! {"include", "select"}
+});
+static SIGN_OUT_INPUT_JSON_KEYS: Lazy<HashSet<&str>> = Lazy::new(|| {
+    hashset! {"include", "select"}
+});
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The HTTP request an action is performed for. Pipelines read it with `$requestId`,
+/// `$requestHeader` and `$clientIp`.
+#[derive(Debug, Clone)]
+pub struct RequestCtx {
+    inner: Arc<RequestCtxInner>,
+}
+
+#[derive(Debug)]
+struct RequestCtxInner {
+    id: String,
+    headers: HashMap<String, String>,
+    client_ip: Option<String>,
+}
+
+impl RequestCtx {
+
+    /// Header names are matched case-insensitively.
+    pub(crate) fn new(id: String, headers: HashMap<String, String>, client_ip: Option<String>) -> Self {
+        let headers = headers.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect();
+        Self { inner: Arc::new(RequestCtxInner { id, headers, client_ip }) }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.inner.id
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.inner.headers.get(&name.to_lowercase()).map(|v| v.as_str())
+    }
+
+    pub fn client_ip(&self) -> Option<&str> {
+        self.inner.client_ip.as_deref()
+    }
+}
@@ -1,9 +1,12 @@
+use crate::core::action::request::RequestCtx;
 use crate::prelude::{Object, Value};
 use self::ActionSource::*;
 
+/// Where an action comes from. Requests served by the server carry the identity of their auth
+/// token and the request.
 #[derive(Clone)]
-pub(crate) enum ActionSource {
-    Identity(Option<Object>),
+pub enum ActionSource {
+    Identity(Option<Object>, Option<RequestCtx>),
     DataClient,
     ProgramCode,
 }
@@ -19,7 +22,7 @@ impl ActionSource {
 
     pub(crate) fn is_identity(&self) -> bool {
         match self {
-            Identity(_) => true,
+            Identity(_, _) => true,
             _ => false,
         }
     }
@@ -31,17 +34,24 @@ impl ActionSource {
         }
     }
 
-    pub(crate) fn as_identity(&self) -> Option<&Object> {
+    pub fn as_identity(&self) -> Option<&Object> {
         match self {
-            Identity(identity) => identity.as_ref(),
+            Identity(identity, _) => identity.as_ref(),
             _ => None,
         }
     }
 
     pub(crate) fn as_identity_value(&self) -> Option<Value> {
         match self {
-            Identity(_) => Some(self.as_identity().cloned().into()),
+            Identity(_, _) => Some(self.as_identity().cloned().into()),
             _ => None
         }
     }
+
+    pub fn as_request(&self) -> Option<&RequestCtx> {
+        match self {
+            Identity(_, request) => request.as_ref(),
+            _ => None,
+        }
+    }
 }
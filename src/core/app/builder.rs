@@ -13,10 +13,14 @@ use crate::core::result::Result;
 use crate::connectors::mongodb::connector::MongoDBConnector;
 use crate::connectors::sql::connector::SQLConnector;
 use crate::connectors::sql::schema::dialect::SQLDialect;
-use crate::core::app::command::{CLI, CLICommand, GenerateClientCommand, GenerateCommand, GenerateEntityCommand, MigrateCommand, ServeCommand};
+use crate::core::app::command::{CLI, CLICommand, GenerateClientCommand, GenerateCommand, GenerateEntityCommand, MigrateCommand, MigrateGenerateCommand, MigrateSubcommand, SeedCommand, ServeCommand};
 use crate::core::app::conf::{ClientGeneratorConf, EntityGeneratorConf, ServerConf};
 use crate::core::app::entrance::Entrance;
 use crate::core::app::environment::EnvironmentVersion;
+use crate::core::app::rate_limit::{MemoryRateLimitStore, RateLimitStore};
+use crate::core::app::revocation::{DatabaseTokenRevocationStore, TokenRevocationStore};
+use crate::core::app::route::{Method, Route, RouteHandler};
+use crate::core::app::seed::data_set::{DataSet, Group, Record};
 use crate::core::connector::Connector;
 use crate::core::field::Field;
 use crate::core::database::name::DatabaseName;
@@ -44,7 +48,7 @@ pub(crate) struct CallbackLookupTable {
 }
 
 impl CallbackLookupTable {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self { transforms: HashMap::new(), validators: HashMap::new(), callbacks: HashMap::new(), compares: HashMap::new() }
     }
 }
@@ -61,6 +65,15 @@ impl<F, Fut> AsyncCallbackWithoutArgs for F where
     }
 }
 
+/// Callbacks around the lifecycle of the server. `before_server_stop` runs when the process
+/// receives SIGINT or SIGTERM, while the server still accepts requests.
+#[derive(Clone, Default)]
+pub(crate) struct ServerHooks {
+    pub(crate) before_server_start: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+    pub(crate) after_server_start: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+    pub(crate) before_server_stop: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+}
+
 #[derive(ToMut)]
 pub struct AppBuilder {
     pub(crate) connector: Option<Arc<dyn Connector>>,
@@ -69,7 +82,12 @@ pub struct AppBuilder {
     pub(crate) entity_generator_confs: Vec<EntityGeneratorConf>,
     pub(crate) client_generator_confs: Vec<ClientGeneratorConf>,
     pub(crate) callback_lookup_table: Arc<Mutex<CallbackLookupTable>>,
-    pub(crate) before_server_start: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+    pub(crate) server_hooks: ServerHooks,
+    pub(crate) token_revocation_store: Option<Arc<dyn TokenRevocationStore>>,
+    pub(crate) rate_limit_store: Option<Arc<dyn RateLimitStore>>,
+    pub(crate) routes: Vec<Route>,
+    pub(crate) custom_actions: HashMap<(String, String), Arc<dyn RouteHandler>>,
+    pub(crate) data_sets: Vec<DataSet>,
     pub(crate) environment_version: EnvironmentVersion,
     pub(crate) entrance: Entrance,
     pub(crate) args: Arc<CLI>,
@@ -98,7 +116,12 @@ impl AppBuilder {
             entity_generator_confs: vec![],
             client_generator_confs: vec![],
             callback_lookup_table: Arc::new(Mutex::new(CallbackLookupTable::new())),
-            before_server_start: None,
+            server_hooks: ServerHooks::default(),
+            token_revocation_store: None,
+            rate_limit_store: None,
+            routes: vec![],
+            custom_actions: HashMap::new(),
+            data_sets: vec![],
             environment_version: environment_version.clone(),
             entrance,
             args: Arc::new(Self::parse_cli_args(environment_version.clone(), entrance.clone())),
@@ -169,11 +192,36 @@ impl AppBuilder {
                         .num_args(1..))))
             .subcommand(ClapCommand::new("migrate")
                 .about("Run migration")
+                .args_conflicts_with_subcommands(true)
                 .arg(Arg::new("dry")
                     .short('d')
                     .long("dry")
                     .help("Dry run")
-                    .action(ArgAction::SetTrue)))
+                    .action(ArgAction::SetTrue))
+                .subcommand(ClapCommand::new("generate")
+                    .about("Generate a migration file from schema changes")
+                    .arg(Arg::new("NAME")
+                        .help("Migration name")
+                        .required(true)
+                        .num_args(1)))
+                .subcommand(ClapCommand::new("apply")
+                    .about("Apply unapplied migration files"))
+                .subcommand(ClapCommand::new("rollback")
+                    .about("Roll back the last applied migration file")))
+            .subcommand(ClapCommand::new("seed")
+                .about("Seed data sets")
+                .arg_required_else_help(false)
+                .arg(Arg::new("all")
+                    .short('a')
+                    .long("all")
+                    .help("Seed all data sets")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with("NAME"))
+                .arg(Arg::new("NAME")
+                    .action(ArgAction::Append)
+                    .conflicts_with("all")
+                    .help("Data set names to seed")
+                    .num_args(1..)))
             .get_matches_from(match environment_version {
                 EnvironmentVersion::Python(_) | EnvironmentVersion::NodeJS(_) => {
                     env::args_os().enumerate().filter(|(i, x)| (*i != 1) && (!x.to_str().unwrap().ends_with("ts-node") && !x.to_str().unwrap().ends_with(".ts"))).map(|(_i, x)| x).collect::<Vec<OsString>>()
@@ -202,7 +250,20 @@ impl AppBuilder {
                 }
             }
             Some(("migrate", submatches)) => {
-                CLICommand::Migrate(MigrateCommand { dry: submatches.get_flag("dry") })
+                let subcommand = match submatches.subcommand() {
+                    Some(("generate", submatches)) => {
+                        let name: &String = submatches.get_one("NAME").unwrap();
+                        Some(MigrateSubcommand::Generate(MigrateGenerateCommand { name: name.to_string() }))
+                    }
+                    Some(("apply", _)) => Some(MigrateSubcommand::Apply),
+                    Some(("rollback", _)) => Some(MigrateSubcommand::Rollback),
+                    _ => None,
+                };
+                CLICommand::Migrate(MigrateCommand { dry: submatches.get_flag("dry"), subcommand })
+            }
+            Some(("seed", submatches)) => {
+                let names: Option<Vec<String>> = submatches.get_many::<String>("NAME").map(|s| s.map(|v| v.to_string()).collect::<Vec<String>>());
+                CLICommand::Seed(SeedCommand { all: submatches.get_flag("all") || names.is_none(), names })
             }
             _ => unreachable!()
         };
@@ -246,7 +307,39 @@ impl AppBuilder {
     }
 
     pub fn before_server_start<F>(&mut self, f: F) -> &mut Self where F: AsyncCallbackWithoutArgs + 'static {
-        self.before_server_start = Some(Arc::new(f));
+        self.server_hooks.before_server_start = Some(Arc::new(f));
+        self
+    }
+
+    pub fn after_server_start<F>(&mut self, f: F) -> &mut Self where F: AsyncCallbackWithoutArgs + 'static {
+        self.server_hooks.after_server_start = Some(Arc::new(f));
+        self
+    }
+
+    pub fn before_server_stop<F>(&mut self, f: F) -> &mut Self where F: AsyncCallbackWithoutArgs + 'static {
+        self.server_hooks.before_server_stop = Some(Arc::new(f));
+        self
+    }
+
+    pub fn token_revocation_store<S>(&mut self, store: S) -> &mut Self where S: TokenRevocationStore + 'static {
+        self.token_revocation_store = Some(Arc::new(store));
+        self
+    }
+
+    pub fn rate_limit_store<S>(&mut self, store: S) -> &mut Self where S: RateLimitStore + 'static {
+        self.rate_limit_store = Some(Arc::new(store));
+        self
+    }
+
+    pub fn route<F>(&mut self, method: Method, path: impl Into<String>, f: F) -> &mut Self where F: RouteHandler + 'static {
+        self.routes.push(Route::new(method, path, Arc::new(f)));
+        self
+    }
+
+    /// Registers the handler of `@customAction(name)` on `model`. Models may declare custom
+    /// actions of the same name, each has its own handler.
+    pub fn custom_action<F>(&mut self, model: impl Into<String>, name: impl Into<String>, f: F) -> &mut Self where F: RouteHandler + 'static {
+        self.custom_actions.insert((model.into(), name.into()), Arc::new(f));
         self
     }
 
@@ -270,7 +363,8 @@ impl AppBuilder {
             environment_version: self.environment_version.clone(),
             entrance: self.entrance.clone(),
             args: self.args.clone(),
-            before_server_start: self.before_server_start.clone(),
+            server_hooks: self.server_hooks.clone(),
+            data_sets: self.data_sets.clone(),
         }
     }
 
@@ -286,16 +380,21 @@ impl AppBuilder {
         let connector: Arc<dyn Connector> = match connector_declaration.provider.unwrap() {
             DatabaseName::MySQL => {
                 #[cfg(feature = "data-source-mysql")]
-                Arc::new(SQLConnector::new(SQLDialect::MySQL, url, false).await)
+                Arc::new(SQLConnector::new(SQLDialect::MySQL, url, connector_declaration.foreign_keys).await)
             },
             DatabaseName::PostgreSQL => {
                 #[cfg(feature = "data-source-postgres")]
-                Arc::new(SQLConnector::new(SQLDialect::PostgreSQL, url, false).await)
+                Arc::new(SQLConnector::new(SQLDialect::PostgreSQL, url, connector_declaration.foreign_keys).await)
             },
             #[cfg(feature = "data-source-sqlite")]
             DatabaseName::SQLite => {
                 #[cfg(feature = "data-source-sqlite")]
-                Arc::new(SQLConnector::new(SQLDialect::SQLite, url, false).await)
+                Arc::new(SQLConnector::new(SQLDialect::SQLite, url, connector_declaration.foreign_keys).await)
+            },
+            #[cfg(feature = "data-source-mssql")]
+            DatabaseName::MSSQL => {
+                #[cfg(feature = "data-source-mssql")]
+                Arc::new(SQLConnector::new(SQLDialect::MSSQL, url, connector_declaration.foreign_keys).await)
             },
             DatabaseName::MongoDB => {
                 #[cfg(feature = "data-source-mongodb")]
@@ -319,7 +418,27 @@ impl AppBuilder {
                 Some(jwt_secret.clone())
             } else {
                 None
-            }
+            },
+            jwt: config.jwt.clone().unwrap_or_default(),
+            token_revocation_store: match &self.token_revocation_store {
+                Some(store) => store.clone(),
+                None => Arc::new(DatabaseTokenRevocationStore { }),
+            },
+            cors: config.cors.clone().unwrap_or_default(),
+            body_limit: config.body_limit.unwrap_or(ServerConf::DEFAULT_BODY_LIMIT),
+            routes: self.routes.clone(),
+            custom_actions: self.custom_actions.clone(),
+            health: config.health.unwrap_or(true),
+            ready: config.ready.unwrap_or(true),
+            meta: config.meta.unwrap_or(false),
+            environment_version: self.environment_version.clone(),
+            logging: config.logging.clone().unwrap_or_default(),
+            rate_limit: config.rate_limit.clone(),
+            shutdown_timeout: config.shutdown_timeout.unwrap_or(ServerConf::DEFAULT_SHUTDOWN_TIMEOUT),
+            rate_limit_store: match &self.rate_limit_store {
+                Some(store) => store.clone(),
+                None => Arc::new(MemoryRateLimitStore::new()),
+            },
         });
         // entity generators
         for entity_generator_ref in parser.generators.iter() {
@@ -346,6 +465,22 @@ impl AppBuilder {
                 git_commit: client.git_commit,
             })
         }
+        // data sets
+        for data_set_ref in parser.data_sets.iter() {
+            let source = parser.get_source(data_set_ref.0);
+            let data_set = source.get_data_set(data_set_ref.1);
+            self.data_sets.push(DataSet {
+                name: data_set.identifier.name.clone(),
+                autoseed: data_set.auto_seed,
+                groups: data_set.groups.iter().map(|g| Group {
+                    name: g.identifier.name.clone(),
+                    records: g.records.iter().map(|r| Record {
+                        name: r.identifier.name.clone(),
+                        value: r.resolved.clone().unwrap(),
+                    }).collect(),
+                }).collect(),
+            });
+        }
         // load enums
         for enum_ref in parser.enums.clone() {
             let source = parser.get_source(enum_ref.0);
@@ -563,6 +698,9 @@ impl AppBuilder {
             "Float" | "Float64" => field.field_type = Some(FieldType::F64),
             "Date" => field.field_type = Some(FieldType::Date),
             "DateTime" => field.field_type = Some(FieldType::DateTime),
+            "Time" => field.field_type = Some(FieldType::Time),
+            "Bytes" => field.field_type = Some(FieldType::Bytes),
+            "Json" => field.field_type = Some(FieldType::Json),
             "Decimal" => field.field_type = Some(FieldType::Decimal),
             #[cfg(feature = "data-source-mongodb")]
             "ObjectId" => field.field_type = Some(FieldType::ObjectId),
@@ -581,6 +719,9 @@ impl AppBuilder {
             "Float" | "Float64" =>  property.field_type = Some(FieldType::F64),
             "Date" =>  property.field_type = Some(FieldType::Date),
             "DateTime" =>  property.field_type = Some(FieldType::DateTime),
+            "Time" =>  property.field_type = Some(FieldType::Time),
+            "Bytes" =>  property.field_type = Some(FieldType::Bytes),
+            "Json" =>  property.field_type = Some(FieldType::Json),
             "Decimal" => property.field_type = Some(FieldType::Decimal),
             #[cfg(feature = "data-source-mongodb")]
             "ObjectId" =>  property.field_type = Some(FieldType::ObjectId),
@@ -9,6 +9,7 @@ pub(crate) enum CLICommand {
     Serve(ServeCommand),
     Generate(GenerateCommand),
     Migrate(MigrateCommand),
+    Seed(SeedCommand),
 }
 
 #[derive(Debug)]
@@ -37,4 +38,23 @@ pub(crate) struct GenerateEntityCommand {
 #[derive(Debug)]
 pub(crate) struct MigrateCommand {
     pub(crate) dry: bool,
+    pub(crate) subcommand: Option<MigrateSubcommand>,
+}
+
+#[derive(Debug)]
+pub(crate) enum MigrateSubcommand {
+    Generate(MigrateGenerateCommand),
+    Apply,
+    Rollback,
+}
+
+#[derive(Debug)]
+pub(crate) struct MigrateGenerateCommand {
+    pub(crate) name: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct SeedCommand {
+    pub(crate) all: bool,
+    pub(crate) names: Option<Vec<String>>,
 }
@@ -1,12 +1,124 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
-use crate::core::app::environment::Environment;
+use std::sync::Arc;
+use jsonwebtoken::Algorithm;
+use log::LevelFilter;
+use crate::core::app::environment::{Environment, EnvironmentVersion};
+use crate::core::app::rate_limit::{RateLimit, RateLimitStore};
+use crate::core::app::revocation::TokenRevocationStore;
+use crate::core::app::route::{Route, RouteHandler};
 use crate::parser::ast::client::ClientLanguage;
 
 #[derive(Clone)]
 pub struct ServerConf {
     pub(crate) bind: (String, u16),
     pub(crate) jwt_secret: Option<String>,
+    pub(crate) jwt: JwtConf,
+    pub(crate) token_revocation_store: Arc<dyn TokenRevocationStore>,
     pub(crate) path_prefix: Option<String>,
+    pub(crate) cors: CorsConf,
+    pub(crate) body_limit: usize,
+    pub(crate) routes: Vec<Route>,
+    pub(crate) custom_actions: HashMap<(String, String), Arc<dyn RouteHandler>>,
+    pub(crate) health: bool,
+    pub(crate) ready: bool,
+    pub(crate) meta: bool,
+    pub(crate) environment_version: EnvironmentVersion,
+    pub(crate) logging: LogConf,
+    pub(crate) rate_limit: Option<RateLimit>,
+    pub(crate) rate_limit_store: Arc<dyn RateLimitStore>,
+    pub(crate) shutdown_timeout: u64,
+}
+
+impl ServerConf {
+    /// The default maximum size of a request body in bytes.
+    pub(crate) const DEFAULT_BODY_LIMIT: usize = 262_144;
+
+    /// The default number of seconds in-flight requests are given to finish when the server
+    /// stops.
+    pub(crate) const DEFAULT_SHUTDOWN_TIMEOUT: u64 = 30;
+}
+
+/// The CORS policy declared by the `cors` block of the server config. An origin, method or
+/// header list containing `"*"` allows any value.
+#[derive(Clone, Debug)]
+pub struct CorsConf {
+    pub(crate) origins: Vec<String>,
+    pub(crate) methods: Vec<String>,
+    pub(crate) headers: Vec<String>,
+    pub(crate) expose_headers: Vec<String>,
+    pub(crate) credentials: bool,
+    pub(crate) max_age: Option<u32>,
+}
+
+impl Default for CorsConf {
+    fn default() -> Self {
+        Self {
+            origins: vec!["*".to_owned()],
+            methods: vec!["OPTIONS".to_owned(), "POST".to_owned(), "GET".to_owned()],
+            headers: vec!["*".to_owned()],
+            expose_headers: vec![],
+            credentials: false,
+            max_age: Some(86400),
+        }
+    }
+}
+
+/// Token options declared by the `jwt` block of the server config. HMAC tokens are signed with
+/// `jwtSecret`, RSA and ECDSA tokens with the PEM encoded key pair.
+#[derive(Clone, Debug)]
+pub struct JwtConf {
+    pub(crate) algorithm: Algorithm,
+    pub(crate) expires_in: i64,
+    pub(crate) issuer: Option<String>,
+    pub(crate) audience: Option<String>,
+    pub(crate) private_key: Option<Vec<u8>>,
+    pub(crate) public_key: Option<Vec<u8>>,
+}
+
+impl JwtConf {
+    /// The default lifetime of an issued token in seconds.
+    pub(crate) const DEFAULT_EXPIRES_IN: i64 = 365 * 24 * 60 * 60;
+
+    pub(crate) fn uses_key_pair(&self) -> bool {
+        !matches!(self.algorithm, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512)
+    }
+}
+
+impl Default for JwtConf {
+    fn default() -> Self {
+        Self {
+            algorithm: Algorithm::HS256,
+            expires_in: Self::DEFAULT_EXPIRES_IN,
+            issuer: None,
+            audience: None,
+            private_key: None,
+            public_key: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+/// Request logging options declared by the `logging` block of the server config. Records are
+/// emitted through the `log` facade with the `teo::request` target.
+#[derive(Clone, Debug)]
+pub struct LogConf {
+    pub(crate) format: LogFormat,
+    pub(crate) level: LevelFilter,
+}
+
+impl Default for LogConf {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Pretty,
+            level: LevelFilter::Info,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::Utc;
+use crate::core::error::Error;
+use crate::core::result::Result;
+use crate::prelude::Graph;
+
+/// The directory which migration files are generated into and loaded from.
+pub(crate) const MIGRATIONS_DIR: &str = "migrations";
+
+/// A generated migration. Each migration is a directory named by its version, containing an
+/// `up.sql` and a `down.sql` file.
+pub(crate) struct MigrationFile {
+    pub(crate) version: String,
+    pub(crate) dir: PathBuf,
+}
+
+impl MigrationFile {
+
+    fn up(&self) -> Result<String> {
+        read(&self.dir.join("up.sql"))
+    }
+
+    fn down(&self) -> Result<String> {
+        read(&self.dir.join("down.sql"))
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| Error::invalid_operation(format!("Cannot read migration file '{}': {}", path.display(), err)))
+}
+
+/// Returns migration files in the migrations directory ordered by version.
+pub(crate) fn migration_files() -> Result<Vec<MigrationFile>> {
+    let dir = Path::new(MIGRATIONS_DIR);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(dir).map_err(|err| Error::invalid_operation(format!("Cannot read migrations directory: {}", err)))?;
+    let mut files = vec![];
+    for entry in entries {
+        let path = entry.map_err(|err| Error::invalid_operation(format!("Cannot read migrations directory: {}", err)))?.path();
+        if path.is_dir() && path.join("up.sql").exists() {
+            files.push(MigrationFile { version: path.file_name().unwrap().to_str().unwrap().to_owned(), dir: path });
+        }
+    }
+    files.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(files)
+}
+
+/// Returns migration files which are not applied to the database yet.
+pub(crate) async fn unapplied_migration_files(graph: &Graph) -> Result<Vec<MigrationFile>> {
+    let files = migration_files()?;
+    if files.is_empty() {
+        return Ok(files);
+    }
+    let applied = graph.connector().applied_migration_versions().await?;
+    Ok(files.into_iter().filter(|f| !applied.contains(&f.version)).collect())
+}
+
+pub(crate) async fn generate_migration_file(graph: &Graph, name: &str) -> Result<()> {
+    if !unapplied_migration_files(graph).await?.is_empty() {
+        return Err(Error::invalid_operation("There are unapplied migration files. Apply them before generating a new one."));
+    }
+    let (up, down) = graph.connector().migration_file_contents(graph.models()).await?;
+    if up.trim().is_empty() {
+        println!("Database is up to date.");
+        return Ok(());
+    }
+    let version = format!("{}_{}", Utc::now().format("%Y%m%d%H%M%S"), name);
+    let dir = Path::new(MIGRATIONS_DIR).join(&version);
+    let write = |file: &str, content: &str| -> Result<()> {
+        fs::write(dir.join(file), content).map_err(|err| Error::invalid_operation(format!("Cannot write migration file: {}", err)))
+    };
+    fs::create_dir_all(&dir).map_err(|err| Error::invalid_operation(format!("Cannot create migration directory: {}", err)))?;
+    write("up.sql", &up)?;
+    write("down.sql", &down)?;
+    println!("Generated {}", dir.display());
+    Ok(())
+}
+
+pub(crate) async fn apply_migration_files(graph: &Graph) -> Result<()> {
+    let files = unapplied_migration_files(graph).await?;
+    if files.is_empty() {
+        println!("No migration files to apply.");
+    }
+    for file in files {
+        graph.connector().apply_migration_file(graph.models(), &file.version, &file.up()?).await?;
+        println!("Applied {}", file.version);
+    }
+    Ok(())
+}
+
+pub(crate) async fn rollback_migration_file(graph: &Graph) -> Result<()> {
+    let applied = graph.connector().applied_migration_versions().await?;
+    let version = match applied.last() {
+        Some(version) => version,
+        None => {
+            println!("No migration files to roll back.");
+            return Ok(());
+        }
+    };
+    let file = match migration_files()?.into_iter().find(|f| &f.version == version) {
+        Some(file) => file,
+        None => return Err(Error::invalid_operation(format!("Migration file '{}' is not found.", version))),
+    };
+    graph.connector().revert_migration_file(&file.version, &file.down()?).await?;
+    println!("Rolled back {}", file.version);
+    Ok(())
+}
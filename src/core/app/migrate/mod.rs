@@ -1,8 +1,18 @@
+pub(crate) mod files;
+
+use crate::core::result::Result;
 use crate::prelude::{Graph};
 
-pub(crate) async fn migrate(graph: &mut Graph, _dry_run: bool) {
-    let result = graph.connector_mut().migrate(graph.models(), false).await;
-    if result.is_err() {
-        panic!("Migration error");
+pub(crate) async fn migrate(graph: &mut Graph, dry_run: bool) -> Result<()> {
+    if dry_run {
+        let plan = graph.connector().migration_plan(graph.models()).await?;
+        if plan.is_empty() {
+            println!("Database is up to date.");
+        }
+        for operation in plan {
+            println!("{}", operation);
+        }
+        return Ok(())
     }
+    graph.connector_mut().migrate(graph.models(), false).await
 }
@@ -1,19 +1,26 @@
 pub mod builder;
 pub mod environment;
 pub mod entrance;
+pub mod revocation;
+pub mod route;
+pub mod rate_limit;
 pub(crate) mod conf;
 pub(crate) mod serve;
 pub(crate) mod command;
 pub(crate) mod migrate;
+pub(crate) mod seed;
 
 use std::sync::Arc;
 use to_mut::ToMut;
-use crate::core::app::builder::AsyncCallbackWithoutArgs;
-use crate::core::app::command::{CLI, CLICommand, GenerateCommand};
+use crate::core::app::builder::ServerHooks;
+use crate::core::app::command::{CLI, CLICommand, GenerateCommand, MigrateSubcommand};
 use crate::core::app::conf::{ClientGeneratorConf, EntityGeneratorConf, ServerConf};
 use crate::core::app::entrance::Entrance;
 use crate::core::app::environment::EnvironmentVersion;
+use crate::core::app::migrate::files::{apply_migration_files, generate_migration_file, rollback_migration_file};
 use crate::core::app::migrate::migrate;
+use crate::core::app::seed::data_set::DataSet;
+use crate::core::app::seed::seed;
 use crate::core::app::serve::serve;
 use crate::core::graph::Graph;
 use crate::gen::generators::client::generate_client;
@@ -27,7 +34,8 @@ pub struct App {
     environment_version: EnvironmentVersion,
     entrance: Entrance,
     args: Arc<CLI>,
-    before_server_start: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+    server_hooks: ServerHooks,
+    data_sets: Vec<DataSet>,
 }
 
 impl App {
@@ -45,7 +53,8 @@ impl App {
                     self.environment_version.clone(),
                     self.entrance.clone(),
                     serve_command.no_migration,
-                    self.before_server_start.clone(),
+                    self.server_hooks.clone(),
+                    self.data_sets.clone(),
                 ).await?
             }
             CLICommand::Generate(cmd) => {
@@ -91,7 +100,22 @@ impl App {
                 }
             }
             CLICommand::Migrate(migrate_command) => {
-                migrate(self.graph.to_mut(), migrate_command.dry).await;
+                let result = match &migrate_command.subcommand {
+                    None => migrate(self.graph.to_mut(), migrate_command.dry).await,
+                    Some(MigrateSubcommand::Generate(generate_command)) => generate_migration_file(&self.graph, &generate_command.name).await,
+                    Some(MigrateSubcommand::Apply) => apply_migration_files(&self.graph).await,
+                    Some(MigrateSubcommand::Rollback) => rollback_migration_file(&self.graph).await,
+                };
+                if let Err(err) = result {
+                    return Err(std::io::Error::new(std::io::ErrorKind::Other, err.message()));
+                }
+            }
+            CLICommand::Seed(seed_command) => {
+                let names = if seed_command.all { None } else { seed_command.names.as_ref() };
+                match seed(&self.graph, &self.data_sets, names).await {
+                    Ok(()) => (),
+                    Err(err) => return Err(std::io::Error::new(std::io::ErrorKind::Other, err.message())),
+                }
             }
         }
         Ok(())
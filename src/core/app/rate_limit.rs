@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use crate::core::action::Action;
+use crate::core::result::Result;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RateLimitKey {
+    Ip,
+    Identity,
+}
+
+impl RateLimitKey {
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ip" => Some(RateLimitKey::Ip),
+            "identity" => Some(RateLimitKey::Identity),
+            _ => None,
+        }
+    }
+}
+
+/// Allows `max` requests per `window` seconds for each client. Clients are told apart by their
+/// IP address or by their identity, anonymous requests fall back to the IP address. When
+/// `handlers` is set, only those handlers are limited and they share the budget.
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    pub(crate) window: u64,
+    pub(crate) max: u32,
+    pub(crate) key: RateLimitKey,
+    pub(crate) handlers: Option<Vec<Action>>,
+}
+
+impl RateLimit {
+
+    pub(crate) fn applies_to(&self, action: Action) -> bool {
+        match &self.handlers {
+            Some(handlers) => handlers.contains(&action),
+            None => true,
+        }
+    }
+}
+
+/// The state of a rate limit bucket after a request was counted.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimitHit {
+    pub count: u32,
+    pub reset_after: u64,
+}
+
+/// Counts requests in fixed windows. Implement it on top of a shared store like Redis when the
+/// app runs on more than one instance.
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+
+    async fn hit(&self, key: &str, window: u64) -> Result<RateLimitHit>;
+}
+
+/// How often the memory store drops the buckets of clients which stopped sending requests.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+struct Bucket {
+    start: Instant,
+    window: Duration,
+    count: u32,
+}
+
+impl Bucket {
+
+    fn expired(&self, now: Instant) -> bool {
+        now.duration_since(self.start) >= self.window
+    }
+}
+
+struct Buckets {
+    map: HashMap<String, Bucket>,
+    last_sweep: Instant,
+}
+
+/// The default store, counters are kept in the memory of the process. An expired bucket is reset
+/// when its key is hit again, the others are swept once every `SWEEP_INTERVAL`.
+pub(crate) struct MemoryRateLimitStore {
+    buckets: Mutex<Buckets>,
+}
+
+impl MemoryRateLimitStore {
+
+    pub(crate) fn new() -> Self {
+        Self { buckets: Mutex::new(Buckets { map: HashMap::new(), last_sweep: Instant::now() }) }
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+
+    async fn hit(&self, key: &str, window: u64) -> Result<RateLimitHit> {
+        let now = Instant::now();
+        let window = Duration::from_secs(window);
+        let mut buckets = self.buckets.lock().unwrap();
+        if now.duration_since(buckets.last_sweep) >= SWEEP_INTERVAL {
+            buckets.map.retain(|_, bucket| !bucket.expired(now));
+            buckets.last_sweep = now;
+        }
+        let bucket = buckets.map.entry(key.to_owned()).or_insert(Bucket { start: now, window, count: 0 });
+        if bucket.expired(now) {
+            *bucket = Bucket { start: now, window, count: 0 };
+        }
+        bucket.count += 1;
+        let reset_after = (bucket.window - now.duration_since(bucket.start)).as_secs_f64().ceil() as u64;
+        Ok(RateLimitHit { count: bucket.count, reset_after })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+    use super::{MemoryRateLimitStore, RateLimitStore, SWEEP_INTERVAL};
+
+    #[tokio::test]
+    async fn expired_bucket_is_reset_on_hit() {
+        let store = MemoryRateLimitStore::new();
+        assert_eq!(store.hit("a", 0).await.unwrap().count, 1);
+        assert_eq!(store.hit("a", 0).await.unwrap().count, 1);
+        assert_eq!(store.hit("b", 60).await.unwrap().count, 1);
+        assert_eq!(store.hit("b", 60).await.unwrap().count, 2);
+    }
+
+    #[tokio::test]
+    async fn expired_buckets_are_swept() {
+        let store = MemoryRateLimitStore::new();
+        store.hit("a", 0).await.unwrap();
+        store.hit("b", 60).await.unwrap();
+        assert_eq!(store.buckets.lock().unwrap().map.len(), 2);
+        store.buckets.lock().unwrap().last_sweep = Instant::now() - SWEEP_INTERVAL;
+        store.hit("c", 60).await.unwrap();
+        let buckets = store.buckets.lock().unwrap();
+        assert!(!buckets.map.contains_key("a"));
+        assert!(buckets.map.contains_key("b"));
+        assert!(buckets.map.contains_key("c"));
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use crate::core::graph::Graph;
+use crate::core::result::Result;
+
+/// Keeps track of revoked auth tokens by their `jti` claim. Every authenticated request asks the
+/// store whether its token is revoked. A record is only needed until `expires_at`, after that
+/// the token is rejected for being expired anyway.
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+
+    async fn revoke(&self, jti: &str, expires_at: i64) -> Result<()>;
+
+    async fn is_revoked(&self, jti: &str) -> Result<bool>;
+}
+
+/// The default store, revoked tokens are recorded in a table of the app's database.
+pub(crate) struct DatabaseTokenRevocationStore { }
+
+#[async_trait]
+impl TokenRevocationStore for DatabaseTokenRevocationStore {
+
+    async fn revoke(&self, jti: &str, expires_at: i64) -> Result<()> {
+        Graph::current().connector().revoke_token(jti, expires_at).await
+    }
+
+    async fn is_revoked(&self, jti: &str) -> Result<bool> {
+        Graph::current().connector().is_token_revoked(jti).await
+    }
+}
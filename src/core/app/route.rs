@@ -0,0 +1,40 @@
+use std::future::Future;
+use std::sync::Arc;
+use futures_util::future::BoxFuture;
+pub use actix_http::Method;
+use crate::core::graph::Graph;
+use crate::core::action::source::ActionSource;
+use crate::core::result::Result;
+use crate::core::teon::Value;
+
+/// Handles a custom route or a custom model action. The handler receives the JSON request body,
+/// the source of the request, which carries the identity of the auth token if there is one and
+/// the request itself, and the graph. The returned value is sent back as the `data` of the
+/// response.
+pub trait RouteHandler: Send + Sync {
+    fn call(&self, input: Value, source: ActionSource, graph: &'static Graph) -> BoxFuture<'static, Result<Value>>;
+}
+
+impl<F, Fut> RouteHandler for F where
+    F: Fn(Value, ActionSource, &'static Graph) -> Fut + Sync + Send,
+    Fut: Future<Output = Result<Value>> + Send + 'static {
+    fn call(&self, input: Value, source: ActionSource, graph: &'static Graph) -> BoxFuture<'static, Result<Value>> {
+        Box::pin(self(input, source, graph))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Route {
+    pub(crate) method: Method,
+    pub(crate) path: String,
+    pub(crate) handler: Arc<dyn RouteHandler>,
+}
+
+impl Route {
+
+    pub(crate) fn new(method: Method, path: impl Into<String>, handler: Arc<dyn RouteHandler>) -> Self {
+        let path = path.into();
+        let path = format!("/{}", path.trim_matches('/'));
+        Self { method, path, handler }
+    }
+}
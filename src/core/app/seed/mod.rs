@@ -0,0 +1,231 @@
+pub(crate) mod data_set;
+
+use std::collections::{HashMap, HashSet};
+use async_recursion::async_recursion;
+use key_path::path;
+use serde_json::{Value as JsonValue};
+use crate::core::action::{Action, CREATE, INTERNAL_AMOUNT, INTERNAL_POSITION, JOIN_CREATE, PROGRAM_CODE, SINGLE};
+use crate::core::action::source::ActionSource;
+use crate::core::error::Error;
+use crate::core::field::r#type::FieldTypeOwner;
+use crate::core::graph::Graph;
+use crate::core::object::Object;
+use crate::core::relation::Relation;
+use crate::core::result::Result;
+use crate::core::teon::decoder::Decoder;
+use crate::prelude::Value;
+use self::data_set::{DataSet, Record};
+
+type RecordKey = (String, String);
+
+/// The relation values of a record, as the names of the records they link to.
+type RecordRelations = Vec<(String, Vec<String>)>;
+
+/// A link between two records which is written after both records are known.
+struct Link {
+    owner: RecordKey,
+    relation: String,
+    target: RecordKey,
+}
+
+/// Seeds the data sets in a single transaction, a failing record leaves the database untouched.
+pub(crate) async fn seed(graph: &Graph, data_sets: &[DataSet], names: Option<&Vec<String>>) -> Result<()> {
+    graph.transaction(|graph| async move {
+        for data_set in data_sets {
+            if let Some(names) = names {
+                if !names.contains(&data_set.name) { continue }
+            }
+            seed_data_set(&graph, data_set).await?;
+        }
+        Ok(())
+    }).await
+}
+
+async fn seed_data_set(graph: &Graph, data_set: &DataSet) -> Result<()> {
+    let mut objects: HashMap<RecordKey, Object> = HashMap::new();
+    let mut links: Vec<Link> = vec![];
+    // load or create every record with its scalar values
+    for group in data_set.groups.iter() {
+        let model = match graph.model(&group.name) {
+            Some(model) => model,
+            None => return Err(Error::invalid_operation(format!("Model '{}' in data set '{}' is not defined.", group.name, data_set.name))),
+        };
+        for record in group.records.iter() {
+            let key = (group.name.clone(), record.name.clone());
+            let (scalars, relations) = decode_record(graph, &group.name, record)?;
+            let object = match find_existing_object(graph, &group.name, &scalars).await? {
+                Some(object) => object,
+                None => graph.new_object(model.name(), Action::from_u32(PROGRAM_CODE | CREATE | SINGLE | INTERNAL_POSITION), ActionSource::ProgramCode)?,
+            };
+            object.set_teon(&Value::HashMap(scalars)).await?;
+            for (relation_name, targets) in relations {
+                let relation = model.relation(&relation_name).unwrap();
+                for target in targets {
+                    links.push(Link { owner: key.clone(), relation: relation_name.clone(), target: (relation.model().to_string(), target) });
+                }
+            }
+            objects.insert(key, object);
+        }
+    }
+    for link in links.iter() {
+        if !objects.contains_key(&link.target) {
+            return Err(Error::invalid_operation(format!("Record '{}' of model '{}' referenced in data set '{}' is not defined.", link.target.1, link.target.0, data_set.name)));
+        }
+    }
+    // normalize links into foreign key dependencies and join records
+    let mut dependencies: HashMap<RecordKey, Vec<(String, RecordKey)>> = HashMap::new();
+    let mut join_links: Vec<(RecordKey, String, RecordKey)> = vec![];
+    let mut joined: HashSet<(RecordKey, RecordKey)> = HashSet::new();
+    for link in links.iter() {
+        let relation = graph.model(&link.owner.0).unwrap().relation(&link.relation).unwrap();
+        if relation.has_join_table() {
+            if joined.insert((link.owner.clone(), link.target.clone())) && !joined.contains(&(link.target.clone(), link.owner.clone())) {
+                join_links.push((link.owner.clone(), relation.name().to_string(), link.target.clone()));
+            }
+        } else if owns_foreign_key(graph, relation) {
+            dependencies.entry(link.owner.clone()).or_insert(vec![]).push((relation.name().to_string(), link.target.clone()));
+        } else {
+            let opposite = match graph.opposite_relation(relation).1 {
+                Some(opposite) => opposite,
+                None => return Err(Error::invalid_operation(format!("Relation '{}' of model '{}' cannot be seeded.", relation.name(), link.owner.0))),
+            };
+            dependencies.entry(link.target.clone()).or_insert(vec![]).push((opposite.name().to_string(), link.owner.clone()));
+        }
+    }
+    // save records in dependency order
+    let mut saved: HashSet<RecordKey> = HashSet::new();
+    let mut visiting: HashSet<RecordKey> = HashSet::new();
+    for group in data_set.groups.iter() {
+        for record in group.records.iter() {
+            let key = (group.name.clone(), record.name.clone());
+            save_record(&key, &objects, &dependencies, &mut saved, &mut visiting).await?;
+        }
+    }
+    // create missing join records
+    for (owner, relation_name, target) in join_links.iter() {
+        let relation = graph.model(&owner.0).unwrap().relation(relation_name).unwrap();
+        create_join_record_if_needed(graph, relation, objects.get(owner).unwrap(), objects.get(target).unwrap()).await?;
+    }
+    Ok(())
+}
+
+fn decode_record(graph: &Graph, model_name: &str, record: &Record) -> Result<(HashMap<String, Value>, RecordRelations)> {
+    let model = graph.model(model_name).unwrap();
+    let mut scalars: HashMap<String, Value> = HashMap::new();
+    let mut relations: RecordRelations = vec![];
+    let values = match record.value.as_hashmap() {
+        Some(values) => values,
+        None => return Err(Error::invalid_operation(format!("Record '{}' of model '{}' should be a dictionary.", record.name, model_name))),
+    };
+    for (key, value) in values {
+        if let Some(field) = model.field(key) {
+            let json_value: JsonValue = value.clone().into();
+            let value = Decoder::decode_value_for_field_type(graph, field.field_type(), field.is_optional(), &json_value, path![key.as_str()])?;
+            scalars.insert(key.to_string(), value);
+        } else if let Some(relation) = model.relation(key) {
+            let targets: Vec<String> = match value {
+                Value::Null => vec![],
+                Value::String(name) => vec![name.clone()],
+                Value::Vec(names) => {
+                    let mut targets = vec![];
+                    for name in names {
+                        match name.as_str() {
+                            Some(name) => targets.push(name.to_string()),
+                            None => return Err(Error::invalid_operation(format!("Value of relation '{}' of record '{}' is invalid.", relation.name(), record.name))),
+                        }
+                    }
+                    targets
+                }
+                _ => return Err(Error::invalid_operation(format!("Value of relation '{}' of record '{}' is invalid.", relation.name(), record.name))),
+            };
+            relations.push((key.to_string(), targets));
+        } else {
+            return Err(Error::invalid_key(key, model));
+        }
+    }
+    Ok((scalars, relations))
+}
+
+async fn find_existing_object(graph: &Graph, model_name: &str, scalars: &HashMap<String, Value>) -> Result<Option<Object>> {
+    let model = graph.model(model_name).unwrap();
+    for unique_keys in model.unique_query_keys() {
+        if unique_keys.iter().all(|k| scalars.get(k).is_some_and(|v| !v.is_null())) {
+            let mut r#where: HashMap<String, Value> = HashMap::new();
+            for k in unique_keys {
+                r#where.insert(k.to_string(), scalars.get(k).unwrap().clone());
+            }
+            let finder = Value::HashMap(HashMap::from([("where".to_owned(), Value::HashMap(r#where))]));
+            return match graph.find_first_internal(model_name, &finder, true, Action::from_u32(PROGRAM_CODE | INTERNAL_AMOUNT | INTERNAL_POSITION), ActionSource::ProgramCode).await {
+                Ok(object) => Ok(Some(object)),
+                Err(err) if err.is_object_not_found() => Ok(None),
+                Err(err) => Err(err),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Whether the foreign key of a non-join relation is stored on the relation's own model.
+fn owns_foreign_key(graph: &Graph, relation: &Relation) -> bool {
+    if relation.has_foreign_key() { return true }
+    match graph.opposite_relation(relation).1 {
+        None => !relation.is_vec(),
+        Some(opposite) => {
+            if opposite.has_foreign_key() { return false }
+            if relation.is_vec() != opposite.is_vec() { return !relation.is_vec() }
+            let model = graph.model(opposite.model()).unwrap();
+            relation.fields().iter().any(|f| !model.primary_field_names().contains(&f.as_str()))
+        }
+    }
+}
+
+#[async_recursion]
+async fn save_record(
+    key: &RecordKey,
+    objects: &HashMap<RecordKey, Object>,
+    dependencies: &HashMap<RecordKey, Vec<(String, RecordKey)>>,
+    saved: &mut HashSet<RecordKey>,
+    visiting: &mut HashSet<RecordKey>,
+) -> Result<()> {
+    if saved.contains(key) { return Ok(()) }
+    if !visiting.insert(key.clone()) {
+        return Err(Error::invalid_operation(format!("Record '{}' of model '{}' has circular references in data set.", key.1, key.0)));
+    }
+    let object = objects.get(key).unwrap();
+    if let Some(record_dependencies) = dependencies.get(key) {
+        for (relation_name, target) in record_dependencies {
+            save_record(target, objects, dependencies, saved, visiting).await?;
+            let relation = object.model().relation(relation_name).unwrap();
+            let target_object = objects.get(target).unwrap();
+            for (field, reference) in relation.iter() {
+                let value = target_object.get_value(reference)?;
+                if object.get_value(field)? != value {
+                    object.set_value(field, value)?;
+                }
+            }
+        }
+    }
+    object.save().await?;
+    visiting.remove(key);
+    saved.insert(key.clone());
+    Ok(())
+}
+
+async fn create_join_record_if_needed(graph: &Graph, relation: &Relation, owner: &Object, target: &Object) -> Result<()> {
+    let (join_model, local_relation) = graph.through_relation(relation);
+    let (_, foreign_relation) = graph.through_opposite_relation(relation);
+    let mut r#where: HashMap<String, Value> = HashMap::new();
+    for (field, reference) in local_relation.iter() {
+        r#where.insert(field.to_string(), owner.get_value(reference)?);
+    }
+    for (field, reference) in foreign_relation.iter() {
+        r#where.insert(field.to_string(), target.get_value(reference)?);
+    }
+    let finder = Value::HashMap(HashMap::from([("where".to_owned(), Value::HashMap(r#where.clone()))]));
+    if graph.count(join_model.name(), &finder).await? > 0 {
+        return Ok(());
+    }
+    let join_object = graph.new_object(join_model.name(), Action::from_u32(JOIN_CREATE | CREATE | SINGLE), ActionSource::ProgramCode)?;
+    join_object.set_teon(&Value::HashMap(r#where)).await?;
+    join_object.save().await
+}
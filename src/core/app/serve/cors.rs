@@ -0,0 +1,108 @@
+use actix_http::header::{HeaderMap, HeaderName, HeaderValue};
+use actix_http::Method;
+use actix_web::{HttpRequest, HttpResponse};
+use crate::core::app::conf::CorsConf;
+
+const ALLOW_ORIGIN: &str = "access-control-allow-origin";
+const ALLOW_METHODS: &str = "access-control-allow-methods";
+const ALLOW_HEADERS: &str = "access-control-allow-headers";
+const ALLOW_CREDENTIALS: &str = "access-control-allow-credentials";
+const EXPOSE_HEADERS: &str = "access-control-expose-headers";
+const MAX_AGE: &str = "access-control-max-age";
+const REQUEST_METHOD: &str = "access-control-request-method";
+const REQUEST_HEADERS: &str = "access-control-request-headers";
+
+fn is_wildcard(list: &[String]) -> bool {
+    list.iter().any(|v| v == "*")
+}
+
+fn header_str<'a>(r: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    r.headers().get(name).and_then(|v| v.to_str().ok())
+}
+
+pub(crate) fn request_origin(r: &HttpRequest) -> Option<String> {
+    header_str(r, "Origin").map(|o| o.to_owned())
+}
+
+pub(crate) fn is_preflight(r: &HttpRequest) -> bool {
+    r.method() == Method::OPTIONS && r.headers().contains_key(REQUEST_METHOD)
+}
+
+// Browsers reject a wildcard origin on credentialed requests, the request origin is echoed back
+// instead.
+fn allowed_origin(conf: &CorsConf, origin: Option<&str>) -> Option<String> {
+    if is_wildcard(&conf.origins) {
+        if conf.credentials {
+            origin.map(|o| o.to_owned())
+        } else {
+            Some("*".to_owned())
+        }
+    } else {
+        origin.filter(|o| conf.origins.iter().any(|a| a == o)).map(|o| o.to_owned())
+    }
+}
+
+fn varies_on_origin(conf: &CorsConf) -> bool {
+    !is_wildcard(&conf.origins) || conf.credentials
+}
+
+/// Answers a preflight request. When the origin or the requested method isn't allowed, the
+/// response carries no CORS headers and the browser blocks the actual request.
+pub(crate) fn preflight_response(conf: &CorsConf, r: &HttpRequest) -> HttpResponse {
+    let mut builder = HttpResponse::NoContent();
+    if varies_on_origin(conf) {
+        builder.insert_header(("Vary", "Origin"));
+    }
+    let origin = match allowed_origin(conf, header_str(r, "Origin")) {
+        Some(origin) => origin,
+        None => return builder.finish(),
+    };
+    let method = header_str(r, REQUEST_METHOD).unwrap_or("").to_uppercase();
+    if !is_wildcard(&conf.methods) && !conf.methods.contains(&method) {
+        return builder.finish();
+    }
+    builder.insert_header((ALLOW_ORIGIN, origin));
+    if is_wildcard(&conf.methods) {
+        builder.insert_header((ALLOW_METHODS, method));
+    } else {
+        builder.insert_header((ALLOW_METHODS, conf.methods.join(", ")));
+    }
+    if is_wildcard(&conf.headers) {
+        if !conf.credentials {
+            builder.insert_header((ALLOW_HEADERS, "*"));
+        } else if let Some(requested) = header_str(r, REQUEST_HEADERS) {
+            builder.insert_header((ALLOW_HEADERS, requested.to_owned()));
+        }
+    } else if !conf.headers.is_empty() {
+        builder.insert_header((ALLOW_HEADERS, conf.headers.join(", ")));
+    }
+    if conf.credentials {
+        builder.insert_header((ALLOW_CREDENTIALS, "true"));
+    }
+    if let Some(max_age) = conf.max_age {
+        builder.insert_header((MAX_AGE, max_age.to_string()));
+    }
+    builder.finish()
+}
+
+/// Adds the CORS headers of an actual request to its response.
+pub(crate) fn apply_headers(conf: &CorsConf, origin: Option<&str>, headers: &mut HeaderMap) {
+    if varies_on_origin(conf) {
+        headers.append(HeaderName::from_static("vary"), HeaderValue::from_static("Origin"));
+    }
+    let origin = match allowed_origin(conf, origin) {
+        Some(origin) => origin,
+        None => return,
+    };
+    if let Ok(value) = HeaderValue::from_str(&origin) {
+        headers.insert(HeaderName::from_static(ALLOW_ORIGIN), value);
+    }
+    if conf.credentials {
+        headers.insert(HeaderName::from_static(ALLOW_CREDENTIALS), HeaderValue::from_static("true"));
+    }
+    if !conf.expose_headers.is_empty() {
+        if let Ok(value) = HeaderValue::from_str(&conf.expose_headers.join(", ")) {
+            headers.insert(HeaderName::from_static(EXPOSE_HEADERS), value);
+        }
+    }
+}
@@ -1,28 +1,89 @@
-use jsonwebtoken::{encode, decode, Header, Validation, EncodingKey, DecodingKey};
+use chrono::Utc;
+use jsonwebtoken::{encode, decode, Algorithm, Header, Validation, EncodingKey, DecodingKey};
 use serde::{Serialize, Deserialize};
 use serde_json::{Value as JsonValue};
+use uuid::Uuid;
+use crate::core::app::conf::ServerConf;
 use crate::core::error::Error;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub id: JsonValue,
     pub model: String,
-    pub exp: usize
+    pub exp: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
 }
 
-pub fn encode_token(claims: Claims, secret: &str) -> String {
-    let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(secret.as_ref()));
-    token.unwrap()
+impl Claims {
+    pub(crate) fn new(id: JsonValue, model: impl Into<String>, conf: &ServerConf) -> Self {
+        let exp = Utc::now().timestamp() + conf.jwt.expires_in;
+        Self {
+            id,
+            model: model.into(),
+            exp: exp as usize,
+            jti: Some(Uuid::new_v4().to_string()),
+            iss: conf.jwt.issuer.clone(),
+            aud: conf.jwt.audience.clone(),
+        }
+    }
 }
 
-pub fn decode_token(token: &String, secret: &str) -> Result<Claims, Error> {
-    let token = decode::<Claims>(&token, &DecodingKey::from_secret(secret.as_ref()), &Validation::default());
-    return match token {
-        Ok(token) => {
-            Ok(token.claims)
-        }
-        Err(_) => {
-            Err(Error::invalid_auth_token())
-        }
+fn encoding_key(conf: &ServerConf) -> Result<EncodingKey, Error> {
+    if conf.jwt.uses_key_pair() {
+        let pem = conf.jwt.private_key.as_ref().ok_or(Error::internal_server_error("Missing JWT private key."))?;
+        let key = if matches!(conf.jwt.algorithm, Algorithm::ES256 | Algorithm::ES384) {
+            EncodingKey::from_ec_pem(pem)
+        } else {
+            EncodingKey::from_rsa_pem(pem)
+        };
+        key.map_err(|_| Error::internal_server_error("Invalid JWT private key."))
+    } else {
+        let secret = conf.jwt_secret.as_ref().ok_or(Error::internal_server_error("Missing JWT secret."))?;
+        Ok(EncodingKey::from_secret(secret.as_ref()))
+    }
+}
+
+fn decoding_key(conf: &ServerConf) -> Result<DecodingKey, Error> {
+    if conf.jwt.uses_key_pair() {
+        let pem = conf.jwt.public_key.as_ref().ok_or(Error::internal_server_error("Missing JWT public key."))?;
+        let key = if matches!(conf.jwt.algorithm, Algorithm::ES256 | Algorithm::ES384) {
+            DecodingKey::from_ec_pem(pem)
+        } else {
+            DecodingKey::from_rsa_pem(pem)
+        };
+        key.map_err(|_| Error::internal_server_error("Invalid JWT public key."))
+    } else {
+        let secret = conf.jwt_secret.as_ref().ok_or(Error::internal_server_error("Missing JWT secret."))?;
+        Ok(DecodingKey::from_secret(secret.as_ref()))
+    }
+}
+
+pub fn encode_token(claims: Claims, conf: &ServerConf) -> Result<String, Error> {
+    let key = encoding_key(conf)?;
+    encode(&Header::new(conf.jwt.algorithm), &claims, &key).map_err(|err| Error::internal_server_error(err.to_string()))
+}
+
+pub fn decode_token(token: &str, conf: &ServerConf) -> Result<Claims, Error> {
+    let key = match decoding_key(conf) {
+        Ok(key) => key,
+        Err(_) => return Err(Error::invalid_auth_token()),
+    };
+    let mut validation = Validation::new(conf.jwt.algorithm);
+    if let Some(issuer) = &conf.jwt.issuer {
+        validation.set_issuer(&[issuer]);
+        validation.required_spec_claims.insert("iss".to_owned());
+    }
+    if let Some(audience) = &conf.jwt.audience {
+        validation.set_audience(&[audience]);
+        validation.required_spec_claims.insert("aud".to_owned());
+    }
+    match decode::<Claims>(token, &key, &validation) {
+        Ok(token) => Ok(token.claims),
+        Err(_) => Err(Error::invalid_auth_token()),
     }
 }
@@ -0,0 +1,183 @@
+use std::time::SystemTime;
+use actix_web::HttpRequest;
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use colored::Colorize;
+use log::{Level, Log, Metadata, Record};
+use log::kv::{self, Key, Value, VisitSource};
+use serde_json::{json, Map, Value as JsonValue};
+use crate::core::app::conf::{LogConf, LogFormat};
+use crate::core::app::serve::request::current_request_id;
+
+pub(crate) const TARGET: &str = "teo::request";
+
+/// Prints request records to stdout. It's only installed when the host application hasn't set a
+/// logger of its own.
+struct StdoutLogger {
+    format: LogFormat,
+}
+
+impl Log for StdoutLogger {
+
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == TARGET
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let mut fields = Fields(Map::new());
+        let _ = record.key_values().visit(&mut fields);
+        let fields = fields.0;
+        match self.format {
+            LogFormat::Pretty => {
+                let local: DateTime<Local> = Local::now();
+                println!("{} {}", format!("{local}").dimmed(), pretty(&fields));
+            }
+            LogFormat::Json => {
+                let mut json = Map::new();
+                json.insert("timestamp".to_owned(), json!(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)));
+                json.insert("level".to_owned(), json!(record.level().as_str().to_lowercase()));
+                json.extend(fields);
+                println!("{}", JsonValue::Object(json));
+            }
+        }
+    }
+
+    fn flush(&self) { }
+}
+
+/// Collects the key-values of a record. Values captured by `Display` are JSON texts of
+/// structured values, like the identity.
+struct Fields(Map<String, JsonValue>);
+
+impl<'kvs> VisitSource<'kvs> for Fields {
+
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        let value = match (value.to_bool(), value.to_u64(), value.to_i64(), value.to_borrowed_str()) {
+            (Some(b), _, _, _) => json!(b),
+            (_, Some(n), _, _) => json!(n),
+            (_, _, Some(n), _) => json!(n),
+            (_, _, _, Some(s)) => json!(s),
+            _ => {
+                let text = value.to_string();
+                serde_json::from_str(&text).unwrap_or(JsonValue::String(text))
+            }
+        };
+        self.0.insert(key.as_str().to_owned(), value);
+        Ok(())
+    }
+}
+
+fn pretty(fields: &Map<String, JsonValue>) -> String {
+    let field = |key: &str| fields.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+    let status = fields.get("status").and_then(|v| v.as_u64()).unwrap_or_default();
+    let code_string = match status {
+        0..=199 => status.to_string().purple().bold(),
+        200..=299 => status.to_string().green().bold(),
+        300..=399 => status.to_string().yellow().bold(),
+        _ => status.to_string().red().bold(),
+    };
+    let ms_str = format!("{}ms", fields.get("latency").and_then(|v| v.as_u64()).unwrap_or_default()).dimmed();
+    if fields.get("handled") != Some(&JsonValue::Bool(true)) {
+        return format!("{} {} on {} - {} {}", "Unhandled".red(), field("method").bold(), field("path"), code_string, ms_str);
+    }
+    let handler = match (fields.get("handler"), fields.get("redirectedHandler")) {
+        (Some(_), Some(_)) => format!("{} -> {}", field("handler").bold(), field("redirectedHandler").bold()),
+        (Some(_), None) => field("handler").bold().to_string(),
+        (None, _) => field("method").bold().to_string(),
+    };
+    let target = if fields.contains_key("model") { field("model") } else { field("path") };
+    format!("{} on {} - {} {}", handler, target, code_string, ms_str)
+}
+
+pub(crate) fn init(conf: &LogConf) {
+    if log::set_boxed_logger(Box::new(StdoutLogger { format: conf.format })).is_ok() {
+        log::set_max_level(conf.level);
+    }
+}
+
+/// Fields of the log record of a single request. They're filled in while the request is being
+/// dispatched.
+pub(crate) struct RequestLog<'a> {
+    start: SystemTime,
+    method: &'a str,
+    path: &'a str,
+    pub(crate) request_id: Option<String>,
+    pub(crate) model: Option<&'a str>,
+    pub(crate) handler: Option<&'a str>,
+    pub(crate) redirected_handler: Option<&'a str>,
+    pub(crate) identity: Option<JsonValue>,
+}
+
+impl<'a> RequestLog<'a> {
+
+    pub(crate) fn new(start: SystemTime, r: &'a HttpRequest) -> Self {
+        Self {
+            start,
+            method: r.method().as_str(),
+            path: r.path(),
+            request_id: current_request_id(r),
+            model: None,
+            handler: None,
+            redirected_handler: None,
+            identity: None,
+        }
+    }
+
+    /// Logs a request which wasn't dispatched to a handler.
+    pub(crate) fn unhandled(&self, conf: &LogConf, status: u16) {
+        self.emit(conf, status, false)
+    }
+
+    pub(crate) fn handled(&self, conf: &LogConf, status: u16) {
+        self.emit(conf, status, true)
+    }
+
+    fn emit(&self, conf: &LogConf, status: u16, handled: bool) {
+        let level = match status {
+            500.. => Level::Error,
+            400..=499 => Level::Warn,
+            _ => Level::Info,
+        };
+        if level > conf.level || !log::log_enabled!(target: TARGET, level) {
+            return;
+        }
+        let ms = SystemTime::now().duration_since(self.start).unwrap().as_millis() as u64;
+        let identity = self.identity.as_ref();
+        let mut fields = vec![
+            ("method", Value::from(self.method)),
+            ("path", Value::from(self.path)),
+            ("status", Value::from(status)),
+            ("latency", Value::from(ms)),
+            ("handled", Value::from(handled)),
+        ];
+        let optional = [
+            ("requestId", self.request_id.as_deref().map(Value::from)),
+            ("model", self.model.map(Value::from)),
+            ("handler", self.handler.map(Value::from)),
+            ("redirectedHandler", self.redirected_handler.map(Value::from)),
+            ("identity", identity.map(Value::from_display)),
+        ];
+        fields.extend(optional.into_iter().filter_map(|(key, value)| value.map(|value| (key, value))));
+        log::logger().log(&Record::builder()
+            .args(format_args!("{}", self.message(status, ms, handled)))
+            .level(level)
+            .target(TARGET)
+            .key_values(&fields)
+            .build());
+    }
+
+    /// The plain message of the record, formatters which don't read the key-values print it.
+    fn message(&self, status: u16, ms: u64, handled: bool) -> String {
+        if !handled {
+            return format!("Unhandled {} on {} - {} {}ms", self.method, self.path, status, ms);
+        }
+        let handler = match (self.handler, self.redirected_handler) {
+            (Some(handler), Some(redirected)) => format!("{} -> {}", handler, redirected),
+            (Some(handler), None) => handler.to_owned(),
+            (None, _) => self.method.to_owned(),
+        };
+        format!("{} on {} - {} {}ms", handler, self.model.unwrap_or(self.path), status, ms)
+    }
+}
@@ -1,13 +1,13 @@
 use std::io::ErrorKind;
 use std::sync::Arc;
 use futures_util::future;
+use futures_util::future::Either;
 use std::time::SystemTime;
 use actix_http::body::BoxBody;
 use actix_http::{Method};
-use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
-use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
-use actix_web::middleware::DefaultHeaders;
-use chrono::{DateTime, Duration, Local, Utc};
+use actix_web::{App, HttpMessage, HttpRequest, HttpResponse, HttpServer, web};
+use actix_web::dev::{Service, ServiceFactory, ServiceRequest, ServiceResponse};
+use chrono::{DateTime, Local};
 use colored::Colorize;
 use futures_util::StreamExt;
 use key_path::{KeyPath, path};
@@ -17,14 +17,20 @@ use crate::core::action::{
     Action, CREATE, DELETE, ENTRY, FIND, IDENTITY, MANY, SINGLE, UPDATE, UPSERT,
     FIND_UNIQUE_HANDLER, FIND_FIRST_HANDLER, FIND_MANY_HANDLER, CREATE_HANDLER, UPDATE_HANDLER,
     UPSERT_HANDLER, DELETE_HANDLER, CREATE_MANY_HANDLER, UPDATE_MANY_HANDLER, DELETE_MANY_HANDLER,
-    COUNT_HANDLER, AGGREGATE_HANDLER, GROUP_BY_HANDLER, SIGN_IN_HANDLER, IDENTITY_HANDLER,
+    COUNT_HANDLER, AGGREGATE_HANDLER, GROUP_BY_HANDLER, SIGN_IN_HANDLER, IDENTITY_HANDLER, REFRESH_TOKEN_HANDLER, SIGN_OUT_HANDLER,
 };
 use crate::core::action::source::ActionSource;
-use crate::core::app::builder::AsyncCallbackWithoutArgs;
+use crate::core::app::builder::ServerHooks;
 use crate::core::app::conf::ServerConf;
+use crate::core::app::rate_limit::RateLimit;
+use crate::core::app::route::RouteHandler;
+use self::logger::RequestLog;
 use crate::core::app::entrance::Entrance;
 use crate::core::app::environment::EnvironmentVersion;
+use crate::core::app::migrate::files::unapplied_migration_files;
 use crate::core::app::migrate::migrate;
+use crate::core::app::seed::data_set::DataSet;
+use crate::core::app::seed::seed;
 use crate::core::connector::SaveSession;
 use self::jwt_token::{Claims, decode_token, encode_token};
 use crate::core::graph::Graph;
@@ -38,6 +44,11 @@ use crate::teon;
 
 pub(crate) mod response;
 pub(crate) mod jwt_token;
+pub(crate) mod cors;
+pub(crate) mod logger;
+pub(crate) mod request;
+pub(crate) mod throttle;
+pub(crate) mod probes;
 
 fn j(v: Value) -> JsonValue {
     v.into()
@@ -57,40 +68,7 @@ fn path_components(path: &str) -> Vec<&str> {
     retval
 }
 
-fn log_unhandled(start: SystemTime, method: &str, path: &str, code: u16) {
-    let now = SystemTime::now();
-    let local: DateTime<Local> = Local::now();
-    let code_string = match code {
-        0..=199 => code.to_string().purple().bold(),
-        200..=299 => code.to_string().green().bold(),
-        300..=399 => code.to_string().yellow().bold(),
-        _ => code.to_string().red().bold(),
-    };
-    let elapsed = now.duration_since(start).unwrap();
-    let ms = elapsed.as_millis();
-    let ms_str = format!("{ms}ms").dimmed();
-    let local_formatted = format!("{local}").dimmed();
-    let unhandled = "Unhandled".red();
-    println!("{} {} {} on {} - {} {}", local_formatted, unhandled, method.bold(), path, code_string, ms_str);
-}
-
-fn log_request(start: SystemTime, action: &str, model: &str, code: u16) {
-    let now = SystemTime::now();
-    let local: DateTime<Local> = Local::now();
-    let code_string = match code {
-        0..=199 => code.to_string().purple().bold(),
-        200..=299 => code.to_string().green().bold(),
-        300..=399 => code.to_string().yellow().bold(),
-        _ => code.to_string().red().bold(),
-    };
-    let elapsed = now.duration_since(start).unwrap();
-    let ms = elapsed.as_millis();
-    let ms_str = format!("{ms}ms").normal().clear();
-    let local_formatted = format!("{local}").dimmed();
-    println!("{} {} on {} - {} {}", local_formatted, action.bold(), model, code_string, ms_str.dimmed());
-}
-
-async fn get_identity(r: &HttpRequest, graph: &Graph, conf: &ServerConf) -> Result<Option<Object>, Error> {
+fn get_claims(r: &HttpRequest, conf: &ServerConf) -> Result<Option<Claims>, Error> {
     let header_value = r.headers().get("authorization");
     if let None = header_value {
         return Ok(None);
@@ -100,17 +78,35 @@ async fn get_identity(r: &HttpRequest, graph: &Graph, conf: &ServerConf) -> Resu
         return Err(Error::invalid_auth_token());
     }
     let token_str = &auth_str[7..];
-    let claims_result = decode_token(&token_str.to_string(), &conf.jwt_secret.as_ref().unwrap());
+    let claims_result = decode_token(token_str, conf);
     if let Err(_) = claims_result {
         return Err(Error::invalid_auth_token());
     }
-    let claims = claims_result.unwrap();
-    let json_identifier = claims.id;
-    let tson_identifier = Decoder::decode_object(graph.model(&claims.model).unwrap(), graph, &json_identifier)?;
-    // Decoder::
-    let _model = graph.model(claims.model.as_str()).unwrap();
+    Ok(Some(claims_result.unwrap()))
+}
+
+async fn get_identity(r: &HttpRequest, graph: &Graph, conf: &ServerConf) -> Result<Option<Object>, Error> {
+    let claims = match get_claims(r, conf)? {
+        Some(claims) => claims,
+        None => return Ok(None),
+    };
+    if let Some(jti) = &claims.jti {
+        match conf.token_revocation_store.is_revoked(jti).await {
+            Ok(false) => (),
+            Ok(true) => return Err(Error::invalid_auth_token()),
+            Err(err) => return Err(err),
+        }
+    }
+    let model = match graph.model(&claims.model) {
+        Some(model) => model,
+        None => return Err(Error::invalid_auth_token()),
+    };
+    let tson_identifier = match Decoder::decode_identifier(model, graph, &claims.id) {
+        Ok(tson_identifier) => tson_identifier,
+        Err(_) => return Err(Error::invalid_auth_token()),
+    };
     let identity = graph.find_unique_internal(
-        graph.model(claims.model.as_str()).unwrap().name(),
+        model.name(),
         &teon!({
             "where": tson_identifier
         }),
@@ -220,8 +216,9 @@ async fn handle_create(graph: &Graph, input: &Value, model: &Model, source: Acti
     let create = input.get("create");
     let include = input.get("include");
     let select = input.get("select");
-    let session = graph.connector().new_save_session();
-    let result = handle_create_internal(graph, create, include, select, model, &path!["create"], action, source, session).await;
+    let result = graph.run_in_save_session(|graph, session| async move {
+        handle_create_internal(&graph, create, include, select, model, &path!["create"], action, source, session).await
+    }).await;
     match result {
         Ok(val) => {
             let json_val: JsonValue = val.into();
@@ -242,112 +239,89 @@ async fn handle_update_internal(_graph: &Graph, object: Object, update: Option<&
 
 async fn handle_update(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> HttpResponse {
     let action = Action::from_u32(UPDATE | ENTRY | SINGLE);
-    let result = graph.find_unique_internal(model.name(), input, true, action, source).await;
-    if result.is_err() {
-        return HttpResponse::NotFound().json(json!({"error": result.err()}));
-    }
-    let result = result.unwrap();
     let update = input.get("update");
     let include = input.get("include");
     let select = input.get("select");
     let r#where = input.get("where");
-    let update_result = handle_update_internal(graph, result.clone(), update, include, select, r#where, model).await;
-    match update_result {
-        Ok(value) => {
+    // a record which is not found is reported as is, the session is left unused
+    let result = graph.run_in_save_session(|graph, _| async move {
+        let object = match graph.find_unique_internal(model.name(), input, true, action, source).await {
+            Ok(object) => object,
+            Err(err) => return Ok(Err(err)),
+        };
+        handle_update_internal(&graph, object, update, include, select, r#where, model).await.map(Ok)
+    }).await;
+    match result {
+        Ok(Ok(value)) => {
             let json_val: JsonValue = value.into();
             HttpResponse::Ok().json(json!({"data": json_val}))
         }
+        Ok(Err(err)) => HttpResponse::NotFound().json(json!({"error": err})),
         Err(err) => {
             HttpResponse::BadRequest().json(json!({"error": err}))
         }
     }
 }
 
-async fn handle_upsert(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> HttpResponse {
+async fn handle_upsert_internal(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> Result<Value, Error> {
     let action = Action::from_u32(UPSERT | UPDATE | ENTRY | SINGLE);
     let result = graph.find_unique_internal(model.name(), input, true, action, source.clone()).await;
     let include = input.get("include");
     let select = input.get("select");
-    return match result {
+    let empty = teon!({});
+    let obj = match result {
         Ok(obj) => {
             // find the object here
-            let update = input.get("update");
-            let set_json_result = match update {
-                Some(update) => {
-                    obj.set_teon_with_path(update, &path!["update"]).await
-                }
-                None => {
-                    let empty = teon!({});
-                    obj.set_teon_with_path(&empty, &path!["update"]).await
-                }
-            };
-            match set_json_result {
-                Ok(_) => {
-                    match obj.save().await {
-                        Ok(_) => {
-                            // refetch here
-                            let refetched = obj.refreshed(include, select).await.unwrap();
-                            let json_val: JsonValue = refetched.to_json_internal(&path!["data"]).await.unwrap().into();
-                            HttpResponse::Ok().json(json!({"data": json_val}))
-                        }
-                        Err(err) => {
-                            HttpResponse::BadRequest().json(json!({"error": err}))
-                        }
-                    }
-                }
-                Err(err) => {
-                    HttpResponse::BadRequest().json(json!({"error": err}))
-                }
-            }
+            let update = input.get("update").unwrap_or(&empty);
+            obj.set_teon_with_path(update, &path!["update"]).await?;
+            obj
         }
         Err(_) => {
-            let create = input.get("create");
+            let create = input.get("create").unwrap_or(&empty);
             let action = Action::from_u32(UPSERT | CREATE | ENTRY | SINGLE);
-            let obj = graph.new_object(model.name(), action, source).unwrap();
-            let set_json_result = match create {
-                Some(create) => {
-                    obj.set_teon_with_path(create, &path!["create"]).await
-                }
-                None => {
-                    let empty = teon!({});
-                    obj.set_teon_with_path(&empty, &path!["create"]).await
-                }
-            };
-            match set_json_result {
-                Ok(_) => {
-                    match obj.save().await {
-                        Ok(_) => {
-                            // refetch here
-                            let refetched = obj.refreshed(include, select).await.unwrap();
-                            let json_data: JsonValue = refetched.to_json_internal(&path!["data"]).await.unwrap().into();
-                            return HttpResponse::Ok().json(json!({"data": json_data}));
-                        }
-                        Err(err) => {
-                            HttpResponse::BadRequest().json(json!({"error": err}))
-                        }
-                    }
-                }
-                Err(err) => {
-                    HttpResponse::BadRequest().json(json!({"error": err}))
-                }
-            }
+            let obj = graph.new_object(model.name(), action, source)?;
+            obj.set_teon_with_path(create, &path!["create"]).await?;
+            obj
+        }
+    };
+    obj.save().await?;
+    // refetch here
+    let refetched = obj.refreshed(include, select).await?;
+    refetched.to_json_internal(&path!["data"]).await
+}
+
+async fn handle_upsert(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> HttpResponse {
+    let result = graph.run_in_save_session(|graph, _| async move {
+        handle_upsert_internal(&graph, input, model, source).await
+    }).await;
+    match result {
+        Ok(value) => {
+            let json_val: JsonValue = value.into();
+            HttpResponse::Ok().json(json!({"data": json_val}))
+        }
+        Err(err) => {
+            HttpResponse::BadRequest().json(json!({"error": err}))
         }
     }
 }
 
 async fn handle_delete(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> HttpResponse {
     let action = Action::from_u32(DELETE | SINGLE | ENTRY);
-    let result = graph.find_unique_internal(model.name(), input, true, action, source).await;
-    if result.is_err() {
-        return HttpResponse::NotFound().json(json!({"error": result.err()}));
-    }
-    let result = result.unwrap();
-    // find the object here
-    return match result.delete_internal(path!["delete"]).await {
-        Ok(_) => {
-            let json_data: JsonValue = result.to_json_internal(&path!["data"]).await.unwrap().into();
+    // a record which is not found is reported as is, the session is left unused
+    let result = graph.run_in_save_session(|graph, _| async move {
+        let object = match graph.find_unique_internal(model.name(), input, true, action, source).await {
+            Ok(object) => object,
+            Err(err) => return Ok(Err(err)),
+        };
+        object.delete_internal(path!["delete"]).await?;
+        object.to_json_internal(&path!["data"]).await.map(Ok)
+    }).await;
+    match result {
+        Ok(Ok(value)) => {
+            let json_data: JsonValue = value.into();
             HttpResponse::Ok().json(json!({"data": json_data}))
         }
+        Ok(Err(err)) => HttpResponse::NotFound().json(json!({"error": err})),
         Err(err) => {
             err.into()
         }
@@ -370,26 +344,25 @@ async fn handle_create_many(graph: &Graph, input: &Value, model: &Model, source:
         return HttpResponse::BadRequest().json(json!({"error": err}));
     }
     let create = create.as_vec().unwrap();
-    let mut count = 0;
-    let mut ret_data: Vec<Value> = vec![];
-    let session = graph.connector().new_save_session();
-    for (index, val) in create.iter().enumerate() {
-        let result = handle_create_internal(graph, Some(val), include, select, model, &path!["create", index], action, source.clone(), session.clone()).await;
-        match result {
-            Err(err) => {
-                println!("{:?}", err.errors);
-            },
-            Ok(val) => {
-                count += 1;
-                ret_data.push(val);
-            }
+    // the whole batch shares one transaction, a failed record rolls back the others
+    let result = graph.run_in_save_session(|graph, session| async move {
+        let mut ret_data: Vec<Value> = vec![];
+        for (index, val) in create.iter().enumerate() {
+            ret_data.push(handle_create_internal(&graph, Some(val), include, select, model, &path!["create", index], action, source.clone(), session.clone()).await?);
+        }
+        Ok(ret_data)
+    }).await;
+    match result {
+        Ok(ret_data) => {
+            let count = ret_data.len();
+            let json_ret_data: JsonValue = Value::Vec(ret_data).into();
+            HttpResponse::Ok().json(json!({
+                "meta": {"count": count},
+                "data": json_ret_data
+            }))
         }
+        Err(err) => HttpResponse::BadRequest().json(json!({"error": err}))
     }
-    let json_ret_data: JsonValue = Value::Vec(ret_data).into();
-    HttpResponse::Ok().json(json!({
-        "meta": {"count": count},
-        "data": json_ret_data
-    }))
 }
 
 async fn handle_update_many(graph: &Graph, input: &Value, model: &Model, source: ActionSource) -> HttpResponse {
@@ -551,18 +524,11 @@ async fn handle_sign_in(graph: &Graph, input: &Value, model: &Model, conf: &Serv
             let select = input.get("select");
             let obj = obj.refreshed(include, select).await.unwrap();
             let json_data = obj.to_json_internal(&path!["data"]).await;
-            let exp: usize = (Utc::now() + Duration::days(365)).timestamp() as usize;
-            let tson_identifier = obj.identifier();
-            let json_identifier: JsonValue = tson_identifier.into();
-            let claims = Claims {
-                id: json_identifier,
-                model: obj.model().name().to_string(),
-                exp
+            let claims = Claims::new(obj.json_identifier(), obj.model().name(), conf);
+            let token = match encode_token(claims, conf) {
+                Ok(token) => token,
+                Err(err) => return err.into(),
             };
-            if conf.jwt_secret.as_ref().is_none() {
-                return super::super::error::Error::internal_server_error("Missing JWT secret.").into();
-            }
-            let token = encode_token(claims, &conf.jwt_secret.as_ref().unwrap());
             HttpResponse::Ok().json(json!({
             "meta": {
                 "token": token
@@ -593,6 +559,145 @@ async fn handle_identity(_graph: &Graph, input: &Value, model: &Model, _conf: &S
     }
 }
 
+async fn handle_refresh_token(_graph: &Graph, input: &Value, model: &Model, conf: &ServerConf, source: ActionSource, claims: Option<Claims>) -> HttpResponse {
+    let (identity, claims) = match (source.as_identity(), claims) {
+        (Some(identity), Some(claims)) => (identity, claims),
+        _ => return HttpResponse::Unauthorized().json(json!({"error": Error::missing_auth_token()})),
+    };
+    if identity.model() != model {
+        return HttpResponse::Unauthorized().json(json!({"error": Error::wrong_identity_model()}));
+    }
+    let jti = match &claims.jti {
+        Some(jti) => jti,
+        None => return HttpResponse::BadRequest().json(json!({"error": Error::invalid_operation("This auth token cannot be refreshed.")})),
+    };
+    let select = input.get("select");
+    let include = input.get("include");
+    let refreshed = identity.refreshed(include, select).await.unwrap();
+    let json_data = refreshed.to_json_internal(&path!["data"]).await;
+    let token = match encode_token(Claims::new(refreshed.json_identifier(), model.name(), conf), conf) {
+        Ok(token) => token,
+        Err(err) => return err.into(),
+    };
+    // the replaced token mustn't stay usable
+    if let Err(err) = conf.token_revocation_store.revoke(jti, claims.exp as i64).await {
+        return err.into();
+    }
+    HttpResponse::Ok().json(json!({
+        "meta": {
+            "token": token
+        },
+        "data": j(json_data.unwrap())
+    }))
+}
+
+async fn handle_sign_out(_graph: &Graph, input: &Value, model: &Model, conf: &ServerConf, source: ActionSource, claims: Option<Claims>) -> HttpResponse {
+    let (identity, claims) = match (source.as_identity(), claims) {
+        (Some(identity), Some(claims)) => (identity, claims),
+        _ => return HttpResponse::Unauthorized().json(json!({"error": Error::missing_auth_token()})),
+    };
+    if identity.model() != model {
+        return HttpResponse::Unauthorized().json(json!({"error": Error::wrong_identity_model()}));
+    }
+    let jti = match &claims.jti {
+        Some(jti) => jti,
+        None => return HttpResponse::BadRequest().json(json!({"error": Error::invalid_operation("This auth token cannot be revoked.")})),
+    };
+    if let Err(err) = conf.token_revocation_store.revoke(jti, claims.exp as i64).await {
+        return err.into();
+    }
+    let select = input.get("select");
+    let include = input.get("include");
+    let refreshed = identity.refreshed(include, select).await.unwrap();
+    let json_data = refreshed.to_json_internal(&path!["data"]).await;
+    HttpResponse::Ok().json(json!({
+        "data": j(json_data.unwrap())
+    }))
+}
+
+async fn read_body(r: &HttpRequest, payload: &mut web::Payload, conf: &ServerConf) -> Result<web::BytesMut, Error> {
+    let content_length = r.headers().get("Content-Length").and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<usize>().ok());
+    if content_length.is_some_and(|l| l > conf.body_limit) {
+        return Err(Error::payload_too_large(conf.body_limit));
+    }
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => return Err(Error::incomplete_request_body(err.to_string())),
+        };
+        // limit max size of in-memory payload
+        if (body.len() + chunk.len()) > conf.body_limit {
+            return Err(Error::payload_too_large(conf.body_limit));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+fn parse_json_body(body: &[u8]) -> Result<JsonValue, Error> {
+    let parsed_body: JsonValue = match serde_json::from_slice(body) {
+        Ok(b) => b,
+        Err(_) => return Err(Error::incorrect_json_format()),
+    };
+    if !parsed_body.is_object() {
+        return Err(Error::unexpected_input_root_type("object"));
+    }
+    Ok(parsed_body)
+}
+
+async fn custom_output(value: Value) -> Result<JsonValue, Error> {
+    match value {
+        Value::Object(object) => Ok(j(object.to_json_internal(&path!["data"]).await?)),
+        Value::Vec(values) if values.iter().any(|v| v.is_object()) => {
+            let mut result = vec![];
+            for (index, value) in values.into_iter().enumerate() {
+                match value {
+                    Value::Object(object) => result.push(j(object.to_json_internal(&path!["data", index]).await?)),
+                    value => result.push(j(value)),
+                }
+            }
+            Ok(JsonValue::Array(result))
+        }
+        value => Ok(j(value)),
+    }
+}
+
+/// Runs the handler of a custom route or a custom model action. An empty request body is
+/// passed to the handler as an empty object.
+async fn handle_custom(r: &HttpRequest, payload: &mut web::Payload, graph: &'static Graph, conf: &ServerConf, handler: &dyn RouteHandler, limit: Option<(String, &RateLimit)>, request_log: &mut RequestLog<'_>) -> HttpResponse {
+    let body = match read_body(r, payload, conf).await {
+        Ok(body) => body,
+        Err(err) => return err.into(),
+    };
+    let input = if body.is_empty() {
+        json!({})
+    } else {
+        match parse_json_body(&body) {
+            Ok(input) => input,
+            Err(err) => return err.into(),
+        }
+    };
+    let identity = match get_identity(r, graph, conf).await {
+        Ok(identity) => identity,
+        Err(err) => return HttpResponse::Unauthorized().json(json!({"error": err })),
+    };
+    request_log.identity = identity.as_ref().map(|identity| identity.json_identifier());
+    let request_ctx = request::request_ctx(r);
+    if let Err(res) = throttle::check(conf, limit, identity.as_ref(), &request_ctx).await {
+        return res;
+    }
+    let source = ActionSource::Identity(identity, Some(request_ctx));
+    let output = match handler.call(Value::from(input), source, graph).await {
+        Ok(value) => custom_output(value).await,
+        Err(err) => Err(err),
+    };
+    match output {
+        Ok(data) => HttpResponse::Ok().json(json!({"data": data})),
+        Err(err) => err.into(),
+    }
+}
+
 pub fn make_app(graph: Graph, conf: ServerConf) ->  App<impl ServiceFactory<
     ServiceRequest,
     Response = ServiceResponse<BoxBody>,
@@ -614,17 +719,37 @@ fn make_app_inner(graph: &'static Graph, conf: &'static ServerConf) -> App<impl
     Error = actix_web::Error,
 > + 'static> {
     let app = App::new()
-        .wrap(DefaultHeaders::new()
-            .add(("Access-Control-Allow-Origin", "*"))
-            .add(("Access-Control-Allow-Methods", "OPTIONS, POST, GET"))
-            .add(("Access-Control-Allow-Headers", "*"))
-            .add(("Access-Control-Max-Age", "86400")))
+        .wrap_fn(move |req, srv| {
+            if cors::is_preflight(req.request()) {
+                let response = cors::preflight_response(&conf.cors, req.request());
+                Either::Left(future::ok(req.into_response(response)))
+            } else {
+                let origin = cors::request_origin(req.request());
+                let fut = srv.call(req);
+                Either::Right(async move {
+                    let mut res = fut.await?;
+                    cors::apply_headers(&conf.cors, origin.as_deref(), res.headers_mut());
+                    Ok(res)
+                })
+            }
+        })
+        .wrap_fn(|req, srv| {
+            let id = request::request_id(req.headers());
+            req.extensions_mut().insert(request::RequestId(id.clone()));
+            let fut = srv.call(req);
+            async move {
+                let mut res = fut.await?;
+                request::apply_header(&id, res.headers_mut());
+                Ok(res)
+            }
+        })
+        .configure(|cfg| probes::configure(cfg, graph, conf))
         .default_service(web::route().to(move |r: HttpRequest, mut payload: web::Payload| async move {
-            let start = SystemTime::now();
+            let mut request_log = RequestLog::new(SystemTime::now(), &r);
             let mut path = r.path().to_string();
             if let Some(prefix) = &conf.path_prefix {
                 if !path.starts_with(prefix) {
-                    log_unhandled(start, r.method().as_str(), &path, 404);
+                    request_log.unhandled(&conf.logging, 404);
                     return Error::destination_not_found().into();
                 }
                 path = path.strip_prefix(prefix).unwrap().to_string();
@@ -634,14 +759,20 @@ fn make_app_inner(graph: &'static Graph, conf: &'static ServerConf) -> App<impl
             } else {
                 path
             };
+            if let Some(route) = conf.routes.iter().find(|route| route.method == r.method() && route.path == path) {
+                let limit = throttle::route_limit(conf, route);
+                let result = handle_custom(&r, &mut payload, graph, conf, route.handler.as_ref(), limit, &mut request_log).await;
+                request_log.handled(&conf.logging, result.status().as_u16());
+                return result;
+            }
             if (r.method() != Method::POST) && (r.method() != Method::OPTIONS) {
-                log_unhandled(start, r.method().as_str(), &path, 404);
+                request_log.unhandled(&conf.logging, 404);
                 return Error::destination_not_found().into();
             }
             let path_components = path_components(&path);
             let first_component = path_components.get(1).unwrap();
             if !(path_components.len() == 3 && first_component == &"action") {
-                log_unhandled(start, r.method().as_str(), &path, 404);
+                request_log.unhandled(&conf.logging, 404);
                 return Error::destination_not_found().into();
             }
             let model_url_segment_name = path_components[0];
@@ -650,56 +781,68 @@ fn make_app_inner(graph: &'static Graph, conf: &'static ServerConf) -> App<impl
             let action = match action {
                 Some(a) => a,
                 None => {
-                    log_unhandled(start, r.method().as_str(), &path, 404);
+                    if let Some(model_def) = graph.model(model_url_segment_name).filter(|m| m.has_custom_action(action_segment_name)) {
+                        if r.method() == Method::OPTIONS {
+                            return HttpResponse::NoContent().finish();
+                        }
+                        request_log.model = Some(model_def.name());
+                        request_log.handler = Some(action_segment_name);
+                        let result = match conf.custom_actions.get(&(model_def.name().to_owned(), action_segment_name.to_owned())) {
+                            Some(handler) => {
+                                let limit = throttle::custom_action_limit(conf, model_def, action_segment_name);
+                                handle_custom(&r, &mut payload, graph, conf, handler.as_ref(), limit, &mut request_log).await
+                            }
+                            None => Error::internal_server_error(format!("Custom action '{}' is not registered.", action_segment_name)).into(),
+                        };
+                        request_log.handled(&conf.logging, result.status().as_u16());
+                        return result;
+                    }
+                    request_log.unhandled(&conf.logging, 404);
                     return Error::destination_not_found().into();
                 }
             };
             let model_def = match graph.model(model_url_segment_name) {
                 Some(name) => name,
                 None => {
-                    log_unhandled(start, r.method().as_str(), &path, 404);
+                    request_log.unhandled(&conf.logging, 404);
                     return Error::destination_not_found().into();
                 }
             };
             if !model_def.has_action(action) {
-                log_unhandled(start, r.method().as_str(), &path, 400);
+                request_log.unhandled(&conf.logging, 400);
                 return Error::destination_not_found().into();
             }
             if r.method() == Method::OPTIONS {
-                return HttpResponse::Ok().json(json!({}));
-            }
-            // read body
-            let mut body = web::BytesMut::new();
-            while let Some(chunk) = payload.next().await {
-                let chunk = chunk.unwrap();
-                // limit max size of in-memory payload
-                if (body.len() + chunk.len()) > 262_144usize {
-                    return HttpResponse::InternalServerError()
-                        .json(json!({"error": Error::internal_server_error("Memory overflow.".to_string())}));
-                }
-                body.extend_from_slice(&chunk);
+                return HttpResponse::NoContent().finish();
             }
-            let parsed_body: Result<JsonValue, serde_json::Error> = serde_json::from_slice(&body);
-            let parsed_body = match parsed_body {
-                Ok(b) => b,
-                Err(_) => {
-                    log_unhandled(start, r.method().as_str(), &path, 400);
-                    return HttpResponse::BadRequest().json(json!({"error": Error::incorrect_json_format()}));
+            request_log.model = Some(model_def.name());
+            request_log.handler = Some(action.as_handler_str());
+            let parsed_body = match read_body(&r, &mut payload, conf).await.and_then(|body| parse_json_body(&body)) {
+                Ok(body) => body,
+                Err(err) => {
+                    request_log.unhandled(&conf.logging, err.r#type.code());
+                    return err.into();
                 }
             };
-
-            if !parsed_body.is_object() {
-                log_unhandled(start, r.method().as_str(), &path, 400);
-                return HttpResponse::BadRequest().json(json!({"error": Error::unexpected_input_root_type("object")}));
-            }
             let identity = match get_identity(&r, &graph, conf).await {
                 Ok(identity) => { identity },
-                Err(err) => return HttpResponse::Unauthorized().json(json!({"error": err }))
+                Err(err) => {
+                    request_log.unhandled(&conf.logging, 401);
+                    return HttpResponse::Unauthorized().json(json!({"error": err }));
+                }
             };
-
+            request_log.identity = identity.as_ref().map(|identity| identity.json_identifier());
+            let request_ctx = request::request_ctx(&r);
+            if let Err(res) = throttle::check(conf, throttle::action_limit(conf, model_def, action), identity.as_ref(), &request_ctx).await {
+                request_log.handled(&conf.logging, res.status().as_u16());
+                return res;
+            }
             let parsed_body = match Decoder::decode_action_arg(model_def, graph, action, &parsed_body) {
                 Ok(body) => body,
-                Err(err) => return err.into()
+                Err(err) => {
+                    request_log.unhandled(&conf.logging, err.r#type.code());
+                    return err.into();
+                }
             };
             let (transformed_body, transformed_action) = if model_def.has_action_transformers() || parsed_body.as_hashmap().unwrap().get("include").is_some() {
                 if ((action.to_u32() == CREATE_MANY_HANDLER) || (action.to_u32() == CREATE_HANDLER)) && (parsed_body.get("create").unwrap().is_vec()) {
@@ -708,107 +851,66 @@ fn make_app_inner(graph: &'static Graph, conf: &'static ServerConf) -> App<impl
                     let mut transformed_entries: Vec<Value> = vec![];
                     let mut new_action = action;
                     for (_index, entry) in entries.iter().enumerate() {
-                        let ctx = Ctx::initial_state_with_value(teon!({"create": entry})).with_action(action);
+                        let ctx = Ctx::initial_state_with_value(teon!({"create": entry})).with_action(action).with_request(Some(request_ctx.clone()));
                         match model_def.transformed_action(ctx).await {
                             Ok(result) => {
                                 transformed_entries.push(result.0.get("create").unwrap().clone());
                                 new_action = result.1;
                             },
-                            Err(err) => return err.into(),
+                            Err(err) => {
+                                request_log.handled(&conf.logging, err.r#type.code());
+                                return err.into();
+                            }
                         }
                     }
                     let mut new_val = parsed_body.clone();
                     new_val.as_hashmap_mut().unwrap().insert("create".to_owned(), Value::Vec(transformed_entries));
                     (new_val, new_action)
                 } else {
-                    let ctx = Ctx::initial_state_with_value(parsed_body).with_action(action);
+                    let ctx = Ctx::initial_state_with_value(parsed_body).with_action(action).with_request(Some(request_ctx.clone()));
                     match model_def.transformed_action(ctx).await {
                         Ok(result) => result,
-                        Err(err) => return err.into(),
+                        Err(err) => {
+                            request_log.handled(&conf.logging, err.r#type.code());
+                            return err.into();
+                        }
                     }
                 }
             } else {
                 (parsed_body, action)
             };
-            let source = ActionSource::Identity(identity);
-            match transformed_action.to_u32() {
-                FIND_UNIQUE_HANDLER => {
-                    let result = handle_find_unique(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    return result;
-                }
-                FIND_FIRST_HANDLER => {
-                    let result = handle_find_first(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                FIND_MANY_HANDLER => {
-                    let result = handle_find_many(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                CREATE_HANDLER => {
-                    let result = handle_create(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                UPDATE_HANDLER => {
-                    let result = handle_update(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                UPSERT_HANDLER => {
-                    let result = handle_upsert(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                DELETE_HANDLER => {
-                    let result = handle_delete(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                CREATE_MANY_HANDLER => {
-                    let result = handle_create_many(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                UPDATE_MANY_HANDLER => {
-                    let result = handle_update_many(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                DELETE_MANY_HANDLER => {
-                    let result = handle_delete_many(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                COUNT_HANDLER => {
-                    let result = handle_count(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                AGGREGATE_HANDLER => {
-                    let result = handle_aggregate(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                GROUP_BY_HANDLER => {
-                    let result = handle_group_by(&graph, &transformed_body, model_def, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
-                }
-                SIGN_IN_HANDLER => {
-                    let result = handle_sign_in(&graph, &transformed_body, model_def, conf).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
+            let source = ActionSource::Identity(identity, Some(request_ctx.clone()));
+            if transformed_action != action {
+                request_log.redirected_handler = Some(transformed_action.as_handler_str());
+            }
+            let result = match transformed_action.to_u32() {
+                FIND_UNIQUE_HANDLER => handle_find_unique(&graph, &transformed_body, model_def, source.clone()).await,
+                FIND_FIRST_HANDLER => handle_find_first(&graph, &transformed_body, model_def, source.clone()).await,
+                FIND_MANY_HANDLER => handle_find_many(&graph, &transformed_body, model_def, source.clone()).await,
+                CREATE_HANDLER => handle_create(&graph, &transformed_body, model_def, source.clone()).await,
+                UPDATE_HANDLER => handle_update(&graph, &transformed_body, model_def, source.clone()).await,
+                UPSERT_HANDLER => handle_upsert(&graph, &transformed_body, model_def, source.clone()).await,
+                DELETE_HANDLER => handle_delete(&graph, &transformed_body, model_def, source.clone()).await,
+                CREATE_MANY_HANDLER => handle_create_many(&graph, &transformed_body, model_def, source.clone()).await,
+                UPDATE_MANY_HANDLER => handle_update_many(&graph, &transformed_body, model_def, source.clone()).await,
+                DELETE_MANY_HANDLER => handle_delete_many(&graph, &transformed_body, model_def, source.clone()).await,
+                COUNT_HANDLER => handle_count(&graph, &transformed_body, model_def, source.clone()).await,
+                AGGREGATE_HANDLER => handle_aggregate(&graph, &transformed_body, model_def, source.clone()).await,
+                GROUP_BY_HANDLER => handle_group_by(&graph, &transformed_body, model_def, source.clone()).await,
+                SIGN_IN_HANDLER => handle_sign_in(&graph, &transformed_body, model_def, conf).await,
+                IDENTITY_HANDLER => handle_identity(&graph, &transformed_body, model_def, conf, source.clone()).await,
+                SIGN_OUT_HANDLER => {
+                    let claims = get_claims(&r, conf).ok().flatten();
+                    handle_sign_out(&graph, &transformed_body, model_def, conf, source.clone(), claims).await
                 }
-                IDENTITY_HANDLER => {
-                    let result = handle_identity(&graph, &transformed_body, model_def, conf, source.clone()).await;
-                    log_request(start, action.as_handler_str(), model_def.name(), result.status().as_u16());
-                    result
+                REFRESH_TOKEN_HANDLER => {
+                    let claims = get_claims(&r, conf).ok().flatten();
+                    handle_refresh_token(&graph, &transformed_body, model_def, conf, source.clone(), claims).await
                 }
                 _ => unreachable!()
-            }
+            };
+            request_log.handled(&conf.logging, result.status().as_u16());
+            result
         }));
     app
 }
@@ -835,27 +937,89 @@ pub(crate) async fn serve(
     environment_version: EnvironmentVersion,
     entrance: Entrance,
     no_migration: bool,
-    before_server_start: Option<Arc<dyn AsyncCallbackWithoutArgs>>,
+    hooks: ServerHooks,
+    data_sets: Vec<DataSet>,
 ) -> Result<(), std::io::Error> {
     if !no_migration {
-        migrate(graph.to_mut(), false).await;
+        match unapplied_migration_files(&graph).await {
+            Ok(files) => if !files.is_empty() {
+                return Err(std::io::Error::new(ErrorKind::Other, "There are unapplied migration files. Run `teo migrate apply` before serving."));
+            },
+            Err(err) => return Err(std::io::Error::new(ErrorKind::Other, err.message())),
+        }
+        if let Err(err) = migrate(graph.to_mut(), false).await {
+            return Err(std::io::Error::new(ErrorKind::Other, err.message()));
+        }
+        // autoseed only runs alongside migration, the schema may not match otherwise
+        let autoseed_data_sets: Vec<DataSet> = data_sets.into_iter().filter(|d| d.autoseed).collect();
+        if let Err(err) = seed(&graph, &autoseed_data_sets, None).await {
+            return Err(std::io::Error::new(ErrorKind::Other, err.message()));
+        }
+    } else if let Err(err) = graph.connector().ping().await {
+        // without migration the database isn't created, refuse to start instead of failing
+        // on each request
+        return Err(std::io::Error::new(ErrorKind::Other, format!("{}. Run `teo migrate` before serving with `--no-migration`.", err.message().trim_end_matches('.'))));
     }
     let leaked_graph = Box::leak(Box::new(graph.clone()));
     Graph::set_current(leaked_graph);
-    if let Some(cb) = before_server_start {
+    if let Some(cb) = &hooks.before_server_start {
         match cb.call().await {
             Ok(()) => (),
             Err(err) => return Err(std::io::Error::new(ErrorKind::Other, err.message())),
         }
     }
+    logger::init(&conf.logging);
     let bind = conf.bind.clone();
     let port = bind.1;
+    let shutdown_timeout = conf.shutdown_timeout;
+    let app_graph = graph.clone();
     let server = HttpServer::new(move || {
-        make_app(graph.clone(), conf.clone())
+        make_app(app_graph.clone(), conf.clone())
     })
+        .disable_signals()
+        .shutdown_timeout(shutdown_timeout)
         .bind(bind)
         .unwrap()
         .run();
-    let result = future::join(server, server_start_message(port, environment_version, entrance)).await;
-    result.0
+    let handle = server.handle();
+    let control = Box::pin(async move {
+        server_start_message(port, environment_version, entrance).await?;
+        if let Some(cb) = &hooks.after_server_start {
+            if let Err(err) = cb.call().await {
+                handle.stop(false).await;
+                return Err(std::io::Error::new(ErrorKind::Other, err.message()));
+            }
+        }
+        shutdown_signal().await;
+        let result = match &hooks.before_server_stop {
+            Some(cb) => cb.call().await,
+            None => Ok(()),
+        };
+        handle.stop(true).await;
+        result.map_err(|err| std::io::Error::new(ErrorKind::Other, err.message()))
+    });
+    let result = match future::select(server, control).await {
+        Either::Left((result, _)) => result,
+        Either::Right((control_result, server)) => server.await.and(control_result),
+    };
+    if let Err(err) = graph.connector().disconnect().await {
+        return Err(std::io::Error::new(ErrorKind::Other, err.message()));
+    }
+    result
+}
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut terminate = signal(SignalKind::terminate()).unwrap();
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => (),
+            _ = terminate.recv() => (),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
 }
@@ -0,0 +1,69 @@
+use actix_web::{HttpResponse, web};
+use serde_json::json;
+use crate::core::action::Action;
+use crate::core::app::conf::ServerConf;
+use crate::core::app::migrate::files::unapplied_migration_files;
+use crate::core::error::Error;
+use crate::core::graph::Graph;
+
+const HEALTH: &str = "/_health";
+const READY: &str = "/_ready";
+const META: &str = "/_meta";
+
+fn probe_path(conf: &ServerConf, path: &str) -> String {
+    match &conf.path_prefix {
+        Some(prefix) => format!("{}{}", prefix.trim_end_matches('/'), path),
+        None => path.to_owned(),
+    }
+}
+
+/// Registers the built-in `GET` endpoints enabled in the server config. They bypass the request
+/// log so that frequent probes don't flood it.
+pub(crate) fn configure(cfg: &mut web::ServiceConfig, graph: &'static Graph, conf: &'static ServerConf) {
+    if conf.health {
+        cfg.route(&probe_path(conf, HEALTH), web::get().to(|| async {
+            HttpResponse::Ok().json(json!({"status": "ok"}))
+        }));
+    }
+    if conf.ready {
+        cfg.route(&probe_path(conf, READY), web::get().to(move || async move {
+            match check_ready(graph).await {
+                Ok(()) => HttpResponse::Ok().json(json!({"status": "ready"})),
+                Err(err) => err.into(),
+            }
+        }));
+    }
+    if conf.meta {
+        cfg.route(&probe_path(conf, META), web::get().to(move || async move {
+            HttpResponse::Ok().json(meta(graph, conf))
+        }));
+    }
+}
+
+async fn check_ready(graph: &Graph) -> Result<(), Error> {
+    graph.connector().ping().await?;
+    let unapplied = match unapplied_migration_files(graph).await {
+        Ok(files) => files,
+        Err(err) => return Err(Error::service_unavailable(err.message())),
+    };
+    if !unapplied.is_empty() {
+        return Err(Error::service_unavailable("There are unapplied migration files."));
+    }
+    Ok(())
+}
+
+fn meta(graph: &Graph, conf: &ServerConf) -> serde_json::Value {
+    let models: Vec<serde_json::Value> = graph.models().iter().filter(|m| !m.r#virtual()).map(|model| {
+        let mut handlers: Vec<&str> = Action::handlers_iter()
+            .filter(|a| model.has_action(**a))
+            .map(|a| a.as_handler_str())
+            .collect();
+        handlers.extend(model.custom_actions().iter().map(|a| a.as_str()));
+        json!({"name": model.name(), "handlers": handlers})
+    }).collect();
+    json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "environment": conf.environment_version.to_string(),
+        "models": models,
+    })
+}
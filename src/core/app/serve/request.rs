@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use actix_http::header::{HeaderMap, HeaderName, HeaderValue};
+use actix_web::{HttpMessage, HttpRequest};
+use uuid::Uuid;
+use crate::core::action::request::RequestCtx;
+
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The id of the request being served, stored in the request extensions.
+#[derive(Clone)]
+pub(crate) struct RequestId(pub(crate) String);
+
+/// Propagates the `X-Request-Id` a client or proxy sent, ids which are empty or longer than 200
+/// characters are replaced with a generated one.
+pub(crate) fn request_id(headers: &HeaderMap) -> String {
+    match headers.get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok()) {
+        Some(id) if !id.is_empty() && id.len() <= 200 => id.to_owned(),
+        _ => Uuid::new_v4().to_string(),
+    }
+}
+
+pub(crate) fn apply_header(id: &str, headers: &mut HeaderMap) {
+    if let Ok(value) = HeaderValue::from_str(id) {
+        headers.insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+    }
+}
+
+pub(crate) fn current_request_id(r: &HttpRequest) -> Option<String> {
+    r.extensions().get::<RequestId>().map(|id| id.0.clone())
+}
+
+/// The client IP is the address of the peer connection. Behind a proxy, read the forwarded
+/// address with `$requestHeader` instead.
+pub(crate) fn request_ctx(r: &HttpRequest) -> RequestCtx {
+    let mut headers: HashMap<String, String> = HashMap::new();
+    for (name, value) in r.headers() {
+        if let Ok(value) = value.to_str() {
+            headers.entry(name.as_str().to_owned())
+                .and_modify(|v| { v.push_str(", "); v.push_str(value); })
+                .or_insert_with(|| value.to_owned());
+        }
+    }
+    let id = current_request_id(r).unwrap_or_else(|| request_id(r.headers()));
+    RequestCtx::new(id, headers, r.peer_addr().map(|addr| addr.ip().to_string()))
+}
@@ -0,0 +1,57 @@
+use actix_http::header::{HeaderValue, RETRY_AFTER};
+use actix_web::HttpResponse;
+use crate::core::action::Action;
+use crate::core::action::request::RequestCtx;
+use crate::core::app::conf::ServerConf;
+use crate::core::app::rate_limit::{RateLimit, RateLimitKey};
+use crate::core::app::route::Route;
+use crate::core::error::Error;
+use crate::core::model::Model;
+use crate::core::object::Object;
+
+/// Returns the limit of a builtin handler and the scope of its bucket. A limit declared on the
+/// model takes precedence over the default of the server config, which is counted for each
+/// handler separately.
+pub(crate) fn action_limit<'a>(conf: &'a ServerConf, model: &'a Model, action: Action) -> Option<(String, &'a RateLimit)> {
+    match model.rate_limit(action) {
+        Some((index, limit)) => Some((format!("{}#{}", model.name(), index), limit)),
+        None => conf.rate_limit.as_ref().map(|limit| (format!("{}#{}", model.name(), action.as_handler_str()), limit)),
+    }
+}
+
+/// Returns the limit of a custom model action and the scope of its bucket.
+pub(crate) fn custom_action_limit<'a>(conf: &'a ServerConf, model: &'a Model, name: &str) -> Option<(String, &'a RateLimit)> {
+    match model.custom_action_rate_limit() {
+        Some((index, limit)) => Some((format!("{}#{}", model.name(), index), limit)),
+        None => conf.rate_limit.as_ref().map(|limit| (format!("{}#{}", model.name(), name), limit)),
+    }
+}
+
+/// Returns the default limit of the server config for a custom route.
+pub(crate) fn route_limit<'a>(conf: &'a ServerConf, route: &Route) -> Option<(String, &'a RateLimit)> {
+    conf.rate_limit.as_ref().map(|limit| (format!("{} {}", route.method, route.path), limit))
+}
+
+/// Counts the request against `limit`. The error response carries `Retry-After`.
+pub(crate) async fn check(conf: &ServerConf, limit: Option<(String, &RateLimit)>, identity: Option<&Object>, request: &RequestCtx) -> Result<(), HttpResponse> {
+    let (scope, limit) = match limit {
+        Some(limit) => limit,
+        None => return Ok(()),
+    };
+    let subject = match (limit.key, identity) {
+        (RateLimitKey::Identity, Some(identity)) => {
+            format!("identity:{}:{}", identity.model().name(), identity.json_identifier())
+        }
+        _ => format!("ip:{}", request.client_ip().unwrap_or("unknown")),
+    };
+    let hit = match conf.rate_limit_store.hit(&format!("{}:{}", scope, subject), limit.window).await {
+        Ok(hit) => hit,
+        Err(err) => return Err(err.into()),
+    };
+    if hit.count <= limit.max {
+        return Ok(());
+    }
+    let mut res: HttpResponse = Error::too_many_requests(hit.reset_after).into();
+    res.headers_mut().insert(RETRY_AFTER, HeaderValue::from(hit.reset_after));
+    Err(res)
+}
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 use async_trait::async_trait;
@@ -11,20 +12,63 @@ use crate::core::object::Object;
 use crate::core::result::Result;
 use crate::prelude::Value;
 
+/// A save session groups database writes of a single action into one transaction. The
+/// transaction is started lazily by the connector and is finished with either `commit` or
+/// `abort`.
 #[async_trait]
-pub(crate) trait SaveSession: Debug + Send + Sync { }
+pub(crate) trait SaveSession: Debug + Send + Sync {
+
+    async fn commit(&self) -> Result<()>;
+
+    async fn abort(&self) -> Result<()>;
+
+    fn as_any(&self) -> &dyn Any;
+}
 
 #[async_trait]
 pub(crate) trait Connector: Send + Sync {
 
     // Query database types
 
-    fn default_database_type(&self, field_type: &FieldType) -> DatabaseType;
+    fn default_database_type(&self, field_type: &FieldType) -> Result<DatabaseType>;
 
     // Migration
 
     async fn migrate(&mut self, models: &Vec<Model>, reset_database: bool) -> Result<()>;
 
+    /// Returns the operations `migrate` would perform, in a printable form, without applying
+    /// them.
+    async fn migration_plan(&self, models: &Vec<Model>) -> Result<Vec<String>>;
+
+    // Migration files
+
+    /// Returns the content of the up and down migration files which bring the database up to
+    /// date with the models.
+    async fn migration_file_contents(&self, models: &Vec<Model>) -> Result<(String, String)>;
+
+    async fn applied_migration_versions(&self) -> Result<Vec<String>>;
+
+    /// Runs migration file `version`. The `@migration` actions it refers to are looked up in
+    /// `models`.
+    async fn apply_migration_file(&self, models: &Vec<Model>, version: &str, statements: &str) -> Result<()>;
+
+    async fn revert_migration_file(&self, version: &str, statements: &str) -> Result<()>;
+
+    // Connection
+
+    /// Checks that the database is reachable.
+    async fn ping(&self) -> Result<()>;
+
+    /// Closes the connections to the database when the server stops.
+    async fn disconnect(&self) -> Result<()>;
+
+    // Token revocation
+
+    /// Records auth token `jti` as revoked until `expires_at`, a unix timestamp.
+    async fn revoke_token(&self, jti: &str, expires_at: i64) -> Result<()>;
+
+    async fn is_token_revoked(&self, jti: &str) -> Result<bool>;
+
     // Raw query
 
     async fn query_raw(&self, query: &Value) -> Result<Value>;
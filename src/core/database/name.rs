@@ -4,5 +4,7 @@ pub enum DatabaseName {
     PostgreSQL,
     #[cfg(feature = "data-source-sqlite")]
     SQLite,
+    #[cfg(feature = "data-source-mssql")]
+    MSSQL,
     MongoDB,
 }
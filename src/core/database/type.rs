@@ -191,8 +191,9 @@ pub enum DatabaseType {
     // MySQL and MongoDB only
     Binary(u8),
 
-    // MySQL only
-    VarBinary(u16),
+    // MySQL and SQL Server
+    // Note: `None` is SQL Server's VARBINARY(MAX).
+    VarBinary(Option<u16>),
 
     // MySQL only
     TinyBlob,
@@ -200,7 +201,8 @@ pub enum DatabaseType {
     // MySQL only
     MediumBlob,
 
-    // MySQL only
+    // MySQL and SQLite
+    // Note: In SQLite, this is BLOB.
     LongBlob,
 
     // MySQL only
@@ -210,6 +212,19 @@ pub enum DatabaseType {
     // PostgreSQL only
     ByteA,
 
+    /// MARK: - JSON types
+
+    /// Json
+    /// Represents a JSON document.
+    /// Note: In MongoDB, the document is embedded.
+    /// Availability: MySQL, PostgreSQL, MongoDB
+    Json,
+
+    /// JsonB
+    /// Represents a JSON document stored in the decomposed binary format.
+    /// Availability: PostgreSQL
+    JsonB,
+
     Vec(Box<DatabaseType>),
 
     Enum(DbEnum),
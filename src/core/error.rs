@@ -26,6 +26,8 @@ pub(crate) enum ErrorType {
     DestinationNotFound,
 
     // request input
+    PayloadTooLarge,
+    IncompleteRequestBody,
     IncorrectJSONFormat,
     UnexpectedInputRootType,
     UnexpectedInputType,
@@ -36,10 +38,12 @@ pub(crate) enum ErrorType {
 
     // request token
     InvalidAuthToken,
+    MissingAuthToken,
 
     // request permission
     PermissionError,
     DeletionDenied,
+    UpdateDenied,
 
     // response destination
     ObjectNotFound,
@@ -57,6 +61,15 @@ pub(crate) enum ErrorType {
 
     // database
     RecordDecodingError,
+
+    // migration
+    MigrationError,
+
+    // readiness
+    ServiceUnavailable,
+
+    // throttling
+    TooManyRequests,
 }
 
 impl ErrorType {
@@ -64,6 +77,8 @@ impl ErrorType {
         match self {
             ErrorType::ValidationError => { 400 }
             ErrorType::IncorrectJSONFormat => { 400 }
+            ErrorType::PayloadTooLarge => { 413 }
+            ErrorType::IncompleteRequestBody => { 400 }
             ErrorType::UnknownDatabaseWriteError => { 500 }
             ErrorType::UnknownDatabaseDeleteError => { 500 }
             ErrorType::UnknownDatabaseFindError => { 500 }
@@ -73,6 +88,7 @@ impl ErrorType {
             ErrorType::InternalServerError => { 500 }
             ErrorType::ObjectNotFound => { 404 }
             ErrorType::InvalidAuthToken => { 401 }
+            ErrorType::MissingAuthToken => { 401 }
             ErrorType::CustomInternalServerError => { 500 }
             ErrorType::CustomValidationError => { 400 }
             ErrorType::WrongIdentityModel => { 401 }
@@ -87,7 +103,11 @@ impl ErrorType {
             ErrorType::PermissionError => { 401 }
             ErrorType::UnexpectedOutputException => { 500 }
             ErrorType::DeletionDenied => { 400 }
+            ErrorType::UpdateDenied => { 400 }
             ErrorType::RecordDecodingError => { 500 }
+            ErrorType::MigrationError => { 500 }
+            ErrorType::ServiceUnavailable => { 503 }
+            ErrorType::TooManyRequests => { 429 }
         }
     }
 }
@@ -223,6 +243,14 @@ impl Error {
         }
     }
 
+    pub(crate) fn missing_auth_token() -> Self {
+        Error {
+            r#type: ErrorType::MissingAuthToken,
+            message: "This action requires an auth token.".to_string(),
+            errors: None
+        }
+    }
+
     pub fn custom_internal_server_error(message: impl Into<String>) -> Self {
         Error {
             r#type: ErrorType::CustomInternalServerError,
@@ -257,6 +285,38 @@ impl Error {
 
     // new error types which should be used across the project
 
+    pub(crate) fn payload_too_large(limit: usize) -> Self {
+        Error {
+            r#type: ErrorType::PayloadTooLarge,
+            message: format!("Request body is larger than {} bytes.", limit),
+            errors: None
+        }
+    }
+
+    pub(crate) fn service_unavailable(reason: impl Into<String>) -> Self {
+        Error {
+            r#type: ErrorType::ServiceUnavailable,
+            message: reason.into(),
+            errors: None
+        }
+    }
+
+    pub(crate) fn too_many_requests(retry_after: u64) -> Self {
+        Error {
+            r#type: ErrorType::TooManyRequests,
+            message: format!("Too many requests, retry after {} seconds.", retry_after),
+            errors: None
+        }
+    }
+
+    pub(crate) fn incomplete_request_body(reason: impl Into<String>) -> Self {
+        Error {
+            r#type: ErrorType::IncompleteRequestBody,
+            message: format!("Request body cannot be read: {}", reason.into()),
+            errors: None
+        }
+    }
+
     pub(crate) fn incorrect_json_format() -> Self {
         Error {
             r#type: ErrorType::IncorrectJSONFormat,
@@ -361,6 +421,14 @@ impl Error {
         }
     }
 
+    pub(crate) fn update_denied(relation_name: impl AsRef<str>) -> Self {
+        Error {
+            r#type: ErrorType::UpdateDenied,
+            message: format!("Update denied by `{}'.", relation_name.as_ref()),
+            errors: None
+        }
+    }
+
     pub(crate) fn validation_error<'a>(path: impl AsRef<KeyPath<'a>>, reason: impl Into<String>) -> Self {
         Error {
             r#type: ErrorType::ValidationError,
@@ -385,6 +453,37 @@ impl Error {
         }
     }
 
+    pub(crate) fn migration_error(reason: impl Into<String>) -> Self {
+        Error {
+            r#type: ErrorType::MigrationError,
+            message: reason.into(),
+            errors: None
+        }
+    }
+
+    pub(crate) fn migration_table_error(table: impl AsRef<str>, column: Option<&str>, sql: Option<&str>, reason: impl AsRef<str>) -> Self {
+        let mut errors = hashmap!{"table".to_owned() => table.as_ref().to_owned()};
+        let mut message = format!("Migration failed on table `{}'", table.as_ref());
+        if let Some(column) = column {
+            errors.insert("column".to_owned(), column.to_owned());
+            message += &format!(", column `{}'", column);
+        }
+        message += &format!(": {}", reason.as_ref());
+        if let Some(sql) = sql {
+            errors.insert("sql".to_owned(), sql.to_owned());
+            message += &format!(" SQL: {}", sql);
+        }
+        Error {
+            r#type: ErrorType::MigrationError,
+            message,
+            errors: Some(errors)
+        }
+    }
+
+    pub(crate) fn is_object_not_found(&self) -> bool {
+        self.r#type == ErrorType::ObjectNotFound
+    }
+
     pub(crate) fn is_custom_internal_server_error(&self) -> bool {
         self.r#type == ErrorType::CustomInternalServerError
     }
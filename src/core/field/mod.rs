@@ -234,8 +234,9 @@ impl Field {
         self.on_output_pipeline.process(ctx).await
     }
 
-    pub(crate) fn finalize(&mut self, connector: Arc<dyn Connector>) {
-        self.database_type = Some(connector.default_database_type(self.field_type()));
+    pub(crate) fn finalize(&mut self, connector: Arc<dyn Connector>) -> Result<()> {
+        self.database_type = Some(connector.default_database_type(self.field_type())?);
+        Ok(())
     }
 
     pub(crate) fn set_required(&mut self) {
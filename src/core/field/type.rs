@@ -18,6 +18,9 @@ pub enum FieldType {
     String,
     Date,
     DateTime,
+    Time,
+    Bytes,
+    Json,
     Enum(Enum),
     Vec(Box<Field>),
     HashMap(Box<Field>),
@@ -130,6 +133,27 @@ impl FieldType {
         }
     }
 
+    pub fn is_time(&self) -> bool {
+        match self {
+            FieldType::Time => true,
+            _ => false
+        }
+    }
+
+    pub fn is_bytes(&self) -> bool {
+        match self {
+            FieldType::Bytes => true,
+            _ => false
+        }
+    }
+
+    pub fn is_json(&self) -> bool {
+        match self {
+            FieldType::Json => true,
+            _ => false
+        }
+    }
+
     pub fn is_vec(&self) -> bool {
         match self {
             FieldType::Vec(_) => true,
@@ -166,9 +190,11 @@ impl FieldType {
             FieldType::ObjectId => &DEFAULT_FILTERS,
             FieldType::Bool => &BOOL_FILTERS,
             FieldType::I32 | FieldType::I64 |
-            FieldType::F32 | FieldType::F64 | FieldType::Date | FieldType::DateTime |
+            FieldType::F32 | FieldType::F64 | FieldType::Date | FieldType::DateTime | FieldType::Time |
             FieldType::Decimal => &DEFAULT_FILTERS,
             FieldType::String => &STRING_FILTERS,
+            FieldType::Bytes => &BYTES_FILTERS,
+            FieldType::Json => &JSON_FILTERS,
             FieldType::Enum(_) => &ENUM_FILTERS,
             FieldType::Vec(_) => &VEC_FILTERS,
             FieldType::HashMap(_) => &MAP_FILTERS,
@@ -184,8 +210,10 @@ impl FieldType {
             FieldType::Bool => &BOOL_FILTERS,
             FieldType::I32 | FieldType::I64 |
             FieldType::F32 | FieldType::F64 | FieldType::Decimal => &NUMBER_FILTERS_WITH_AGGREGATE,
-            FieldType::Date | FieldType::DateTime => &DEFAULT_FILTERS_WITH_AGGREGATE,
+            FieldType::Date | FieldType::DateTime | FieldType::Time => &DEFAULT_FILTERS_WITH_AGGREGATE,
             FieldType::String => &STRING_FILTERS_WITH_AGGREGATE,
+            FieldType::Bytes => &BYTES_FILTERS_WITH_AGGREGATE,
+            FieldType::Json => &JSON_FILTERS,
             FieldType::Enum(_) => &ENUM_FILTERS_WITH_AGGREGATE,
             FieldType::Vec(_) => &VEC_FILTERS,
             FieldType::HashMap(_) => &MAP_FILTERS,
@@ -224,6 +252,12 @@ static ENUM_FILTERS: Lazy<HashSet<&str>> = Lazy::new(|| {
 static VEC_FILTERS: Lazy<HashSet<&str>> = Lazy::new(|| {
     hashset! {"equals", "has", "hasEvery", "hasSome", "isEmpty", "length"}
 });
+static BYTES_FILTERS: Lazy<HashSet<&str>> = Lazy::new(|| {
+    hashset! {"equals", "not", "in", "notIn"}
+});
+static JSON_FILTERS: Lazy<HashSet<&str>> = Lazy::new(|| {
+    hashset! {"equals", "not", "path", "contains"}
+});
 static MAP_FILTERS: Lazy<HashSet<&str>> = Lazy::new(|| {
     hashset! {"equals", "has", "hasEvery", "hasSome", "isEmpty", "length", "hasKey"}
 });
@@ -236,6 +270,9 @@ static NUMBER_FILTERS_WITH_AGGREGATE: Lazy<HashSet<&str>> = Lazy::new(|| {
 static DEFAULT_FILTERS_WITH_AGGREGATE: Lazy<HashSet<&str>> = Lazy::new(|| {
     DEFAULT_FILTERS.bitor(&hashset!{"_min", "_max", "_count"})
 });
+static BYTES_FILTERS_WITH_AGGREGATE: Lazy<HashSet<&str>> = Lazy::new(|| {
+    BYTES_FILTERS.bitor(&hashset!{"_count"})
+});
 static ENUM_FILTERS_WITH_AGGREGATE: Lazy<HashSet<&str>> = Lazy::new(|| {
     ENUM_FILTERS.bitor(&hashset!{"_count"})
 });
@@ -59,6 +59,6 @@ impl GraphBuilder {
         }
         graph.models_map = models_map;
         graph.connector = Some(connector.clone());
-        Graph { inner: Arc::new(graph) }
+        Graph { inner: Arc::new(graph), session: None }
     }
 }
@@ -6,7 +6,7 @@ use to_mut_proc_macro::ToMut;
 use to_mut::ToMut;
 use crate::core::action::{Action, CREATE, INTERNAL_AMOUNT, INTERNAL_POSITION, PROGRAM_CODE, SINGLE};
 use crate::core::action::source::ActionSource;
-use crate::core::connector::Connector;
+use crate::core::connector::{Connector, SaveSession};
 use crate::core::model::Model;
 use crate::core::object::Object;
 use crate::core::r#enum::Enum;
@@ -19,7 +19,8 @@ pub mod builder;
 
 #[derive(Clone, ToMut)]
 pub struct Graph {
-    inner: Arc<GraphInner>
+    inner: Arc<GraphInner>,
+    session: Option<Arc<dyn SaveSession>>,
 }
 
 pub(crate) struct GraphInner {
@@ -52,6 +53,62 @@ impl Graph {
         }
     }
 
+    // MARK: - Save session
+
+    /// Returns a graph which shares models and connector with this graph, but performs every
+    /// query and write inside `session`.
+    pub(crate) fn with_session(&self, session: Arc<dyn SaveSession>) -> Graph {
+        Graph { inner: self.inner.clone(), session: Some(session) }
+    }
+
+    pub(crate) fn session(&self) -> Option<Arc<dyn SaveSession>> {
+        self.session.clone()
+    }
+
+    /// Runs `f` inside the save session of this graph. If this graph isn't bound to a session,
+    /// a new one is created, which is committed when `f` succeeds and aborted when it fails.
+    pub(crate) async fn run_in_save_session<F, Fut, T>(&self, f: F) -> Result<T> where
+        F: FnOnce(Graph, Arc<dyn SaveSession>) -> Fut,
+        Fut: Future<Output = Result<T>> {
+        if let Some(session) = self.session() {
+            return f(self.clone(), session).await;
+        }
+        let session = self.connector().new_save_session();
+        let result = f(self.with_session(session.clone()), session.clone()).await;
+        match result {
+            Ok(value) => {
+                session.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                let _ = session.abort().await;
+                Err(err)
+            }
+        }
+    }
+
+    // MARK: - Transaction
+
+    /// Runs `f` inside a database transaction. Objects created, found, saved and deleted
+    /// through the graph passed to `f` share the transaction. It's committed when `f` returns
+    /// `Ok` and rolled back when `f` returns `Err`.
+    ///
+    /// Calling this on a graph which is already inside a transaction, for example the graph of
+    /// an object in a callback, joins the outer transaction instead of starting a new one.
+    ///
+    /// ```ignore
+    /// graph.transaction(|tx| async move {
+    ///     let user = tx.create_object("User", teon!({"email": "a@b.com"})).await?;
+    ///     user.save().await?;
+    ///     Ok(())
+    /// }).await?;
+    /// ```
+    pub async fn transaction<F, Fut, T>(&self, f: F) -> Result<T> where
+        F: FnOnce(Graph) -> Fut,
+        Fut: Future<Output = Result<T>> {
+        self.run_in_save_session(|graph, _| f(graph)).await
+    }
+
     // MARK: - Queries
 
     pub async fn find_unique<T: From<Object>>(&self, model: &str, finder: &Value) -> Result<T> {
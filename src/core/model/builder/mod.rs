@@ -2,7 +2,8 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use inflector::Inflector;
 use to_mut::ToMut;
-use crate::core::action::{Action, CREATE_HANDLER, CREATE_MANY_HANDLER, IDENTITY_HANDLER, SIGN_IN_HANDLER};
+use crate::core::action::{Action, CREATE_HANDLER, CREATE_MANY_HANDLER, IDENTITY_HANDLER, REFRESH_TOKEN_HANDLER, SIGN_IN_HANDLER, SIGN_OUT_HANDLER};
+use crate::core::app::rate_limit::RateLimit;
 use crate::core::connector::Connector;
 use crate::core::field::*;
 use crate::core::field::Field;
@@ -10,6 +11,7 @@ use crate::core::field::r#type::FieldTypeOwner;
 use crate::core::relation::Relation;
 use crate::core::property::Property;
 use crate::core::relation::delete_rule::DeleteRule;
+use crate::core::relation::update_rule::UpdateRule;
 use crate::core::model::index::{ModelIndex, ModelIndexItem, ModelIndexType};
 use crate::core::model::index::builder::{ModelIndexBuilder};
 use crate::core::model::{Model, ModelInner};
@@ -38,6 +40,8 @@ pub struct ModelBuilder {
     pub(crate) can_mutate_pipeline: Pipeline,
     pub(crate) disabled_actions: Option<Vec<Action>>,
     pub(crate) action_transformers: Vec<Pipeline>,
+    pub(crate) custom_actions: Vec<String>,
+    pub(crate) rate_limits: Vec<RateLimit>,
     pub(crate) migration: Option<ModelMigration>,
 }
 
@@ -66,6 +70,8 @@ impl ModelBuilder {
             can_mutate_pipeline: Pipeline::new(),
             disabled_actions: None,
             action_transformers: vec![],
+            custom_actions: vec![],
+            rate_limits: vec![],
             migration: None,
         }
     }
@@ -175,10 +181,22 @@ impl ModelBuilder {
         self
     }
 
+    fn finalize_field(&self, field: &mut Field, connector: Arc<dyn Connector>) {
+        if let Err(err) = field.finalize(connector) {
+            panic!("Field '{}' of model '{}': {}", field.name, self.name, err.message());
+        }
+    }
+
+    fn finalize_property(&self, property: &mut Property, connector: Arc<dyn Connector>) {
+        if let Err(err) = property.finalize(connector) {
+            panic!("Property '{}' of model '{}': {}", property.name, self.name, err.message());
+        }
+    }
+
     pub(crate) fn build(&self, connector: Arc<dyn Connector>) -> Model {
-        let fields_vec: Vec<Arc<Field>> = self.fields.clone().iter_mut().map(|fb| { Arc::new({ fb.finalize(connector.clone()); fb.clone()}) }).collect();
-        let dropped_fields_vec: Vec<Arc<Field>> = self.dropped_fields.clone().iter_mut().map(|fb| { Arc::new({ fb.finalize(connector.clone()); fb.clone()}) }).collect();
-        let properties_vec: Vec<Arc<Property>> = self.properties.clone().iter_mut().map(|pb| { Arc::new({ pb.finalize(connector.clone()); pb.clone() }) }).collect();
+        let fields_vec: Vec<Arc<Field>> = self.fields.clone().iter_mut().map(|fb| { Arc::new({ self.finalize_field(fb, connector.clone()); fb.clone()}) }).collect();
+        let dropped_fields_vec: Vec<Arc<Field>> = self.dropped_fields.clone().iter_mut().map(|fb| { Arc::new({ self.finalize_field(fb, connector.clone()); fb.clone()}) }).collect();
+        let properties_vec: Vec<Arc<Property>> = self.properties.clone().iter_mut().map(|pb| { Arc::new({ self.finalize_property(pb, connector.clone()); pb.clone() }) }).collect();
         let mut fields_map: HashMap<String, Arc<Field>> = HashMap::new();
         let mut dropped_fields_map: HashMap<String, Arc<Field>> = HashMap::new();
         let mut properties_map: HashMap<String, Arc<Property>> = HashMap::new();
@@ -217,6 +235,11 @@ impl ModelBuilder {
             Arc::new(rb.clone())
         }).collect();
         for relation in relations_vec.iter() {
+            if relation.has_foreign_key() && (relation.delete_rule() == DeleteRule::Nullify || relation.update_rule() == UpdateRule::Nullify) {
+                if let Some(field) = relation.fields().iter().find(|f| fields_map.get(f.as_str()).unwrap().is_required()) {
+                    panic!("Relation '{}' of model '{}' can't nullify required field '{}'.", relation.name(), self.name, field);
+                }
+            }
             relations_map.insert(relation.name().to_owned(), relation.clone());
         }
         for property in properties_vec.iter() {
@@ -226,10 +249,21 @@ impl ModelBuilder {
         if primary.is_none() && !self.r#virtual {
             panic!("Model '{}' must has a primary field.", self.name);
         }
-        // install recordPrevious for primary
+        // install recordPrevious for primary, fields of a compound primary key declared with
+        // `@@id` are marked as primary here, too
         for key in primary.as_ref().unwrap().keys() {
             let field = fields_map.get(key).unwrap();
             field.as_ref().to_mut().previous_value_rule = PreviousValueRule::Keep;
+            field.as_ref().to_mut().primary = true;
+        }
+        // install recordPrevious for keys referenced by foreign keys of related models
+        for relation in relations_vec.iter() {
+            if relation.through().is_some() || relation.has_foreign_key() { continue }
+            for key in relation.fields() {
+                if let Some(field) = fields_map.get(key) {
+                    field.as_ref().to_mut().previous_value_rule = PreviousValueRule::Keep;
+                }
+            }
         }
         let unique_query_keys = Self::unique_query_keys(self, &indices, primary.as_ref());
         let inner = ModelInner {
@@ -274,6 +308,8 @@ impl ModelBuilder {
             handler_actions: self.figure_out_actions(),
             disabled_actions: self.disabled_actions.clone(),
             action_transformers: self.action_transformers.clone(),
+            custom_actions: self.custom_actions.clone(),
+            rate_limits: self.rate_limits.clone(),
             migration: self.migration.clone(),
         };
         Model::new_with_inner(Arc::new(inner))
@@ -455,6 +491,8 @@ impl ModelBuilder {
         if self.identity {
             default.insert(Action::from_u32(SIGN_IN_HANDLER));
             default.insert(Action::from_u32(IDENTITY_HANDLER));
+            default.insert(Action::from_u32(REFRESH_TOKEN_HANDLER));
+            default.insert(Action::from_u32(SIGN_OUT_HANDLER));
         }
         if let Some(disabled) = &self.disabled_actions {
             default.iter().filter(|a| {
@@ -130,28 +130,31 @@ impl ModelIndex {
 
     pub(crate) fn to_sql_drop(&self, dialect: SQLDialect, table_name: &str) -> String {
         let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
         let index_name_cow = self.sql_name(table_name, dialect);
         let index_name = index_name_cow.as_ref();
-        if dialect == SQLDialect::PostgreSQL {
-            format!("DROP INDEX {escape}{index_name}{escape}")
+        if dialect == SQLDialect::PostgreSQL || dialect == SQLDialect::SQLite {
+            format!("DROP INDEX {escape}{index_name}{escape_end}")
         } else {
-            format!("DROP INDEX {escape}{index_name}{escape} ON {escape}{table_name}{escape}")
+            format!("DROP INDEX {escape}{index_name}{escape_end} ON {escape}{table_name}{escape_end}")
         }
     }
 
     pub(crate) fn to_sql_create(&self, dialect: SQLDialect, table_name: &str) -> String {
         let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
         let index_name_cow = self.sql_name(table_name, dialect);
         let index_name = index_name_cow.as_ref();
         let unique = if self.r#type().is_unique() { "UNIQUE " } else { "" };
         let fields: Vec<String> = self.items.iter().map(|item| {
             Self::sql_format_item(dialect, item)
         }).collect();
-        format!("CREATE {unique}INDEX {escape}{index_name}{escape} ON {escape}{table_name}{escape}({})", fields.join(","))
+        format!("CREATE {unique}INDEX {escape}{index_name}{escape_end} ON {escape}{table_name}{escape_end}({})", fields.join(","))
     }
 
     pub(crate) fn sql_format_item(dialect: SQLDialect, item: &ModelIndexItem) -> String {
         let escape = dialect.escape();
+        let escape_end = dialect.escape_end();
         let name = item.field_name();
         let sort = item.sort().to_str();
         let len = if let Some(len) = item.len() {
@@ -163,7 +166,7 @@ impl ModelIndex {
         } else {
             Cow::Borrowed("")
         };
-        format!("{escape}{name}{escape}{len} {sort}")
+        format!("{escape}{name}{escape_end}{len} {sort}")
     }
 
     pub(crate) fn normalize_name(&self, table_name: &str, dialect: SQLDialect) -> String {
@@ -172,7 +175,7 @@ impl ModelIndex {
                 SQLDialect::MySQL => "PRIMARY".to_owned(),
                 SQLDialect::SQLite => format!("sqlite_autoindex_{}_1", table_name),
                 SQLDialect::PostgreSQL => self.normalize_name_psql(table_name),
-                _ => unreachable!()
+                SQLDialect::MSSQL => format!("PK_{}", table_name),
             },
             _ => match dialect {
                 SQLDialect::PostgreSQL => self.normalize_name_psql(table_name),
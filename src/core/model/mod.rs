@@ -4,7 +4,8 @@ use std::sync::Arc;
 use async_recursion::async_recursion;
 use inflector::Inflector;
 use maplit::hashset;
-use crate::core::action::{Action, FIND, IDENTITY, MANY, NESTED, SIGN_IN, SINGLE};
+use crate::core::action::{Action, FIND, IDENTITY, MANY, NESTED, REFRESH_TOKEN, SIGN_IN, SIGN_OUT, SINGLE};
+use crate::core::app::rate_limit::RateLimit;
 use crate::core::field::Field;
 use crate::core::model::migration::ModelMigration;
 use crate::core::pipeline::ctx::Ctx;
@@ -62,6 +63,8 @@ pub struct ModelInner {
     pub(crate) handler_actions: HashSet<Action>,
     pub(crate) disabled_actions: Option<Vec<Action>>,
     pub(crate) action_transformers: Vec<Pipeline>,
+    pub(crate) custom_actions: Vec<String>,
+    pub(crate) rate_limits: Vec<RateLimit>,
     pub(crate) migration: Option<ModelMigration>,
 }
 
@@ -234,7 +237,7 @@ impl Model {
                 return false;
             }
         }
-        if ((action.to_u32() & IDENTITY) != 0) || ((action.to_u32() & SIGN_IN) != 0) {
+        if (action.to_u32() & (IDENTITY | SIGN_IN | SIGN_OUT | REFRESH_TOKEN)) != 0 {
             return self.inner.identity;
         }
         true
@@ -284,6 +287,26 @@ impl Model {
         self.inner.disabled_actions.as_ref()
     }
 
+    pub(crate) fn custom_actions(&self) -> &Vec<String> {
+        &self.inner.custom_actions
+    }
+
+    pub(crate) fn has_custom_action(&self, name: &str) -> bool {
+        self.inner.custom_actions.iter().any(|a| a == name)
+    }
+
+    /// Returns the first rate limit of the model which applies to `action`, along with its
+    /// index.
+    pub(crate) fn rate_limit(&self, action: Action) -> Option<(usize, &RateLimit)> {
+        self.inner.rate_limits.iter().enumerate().find(|(_, limit)| limit.applies_to(action))
+    }
+
+    /// Returns the first rate limit of the model which applies to every handler, custom actions
+    /// can't be listed in `handlers`.
+    pub(crate) fn custom_action_rate_limit(&self) -> Option<(usize, &RateLimit)> {
+        self.inner.rate_limits.iter().enumerate().find(|(_, limit)| limit.handlers.is_none())
+    }
+
     pub(crate) fn has_action_transformers(&self) -> bool {
         self.inner.action_transformers.len() > 0
     }
@@ -12,6 +12,7 @@ use maplit::hashmap;
 use indexmap::IndexMap;
 use to_mut::ToMut;
 use to_mut_proc_macro::ToMut;
+use serde_json::{Value as JsonValue};
 use crate::core::action::{Action, CONNECT, CONNECT_OR_CREATE, CREATE, PROGRAM_CODE, DELETE, DISCONNECT, FIND, JOIN_CREATE, JOIN_DELETE, MANY, NESTED, SINGLE, UPDATE, UPSERT, NESTED_CREATE_ACTION, NESTED_DISCONNECT_ACTION, NESTED_SET_ACTION, NESTED_CONNECT_ACTION, NESTED_DELETE_MANY_ACTION, NESTED_UPDATE_MANY_ACTION, NESTED_UPDATE_ACTION, NESTED_DELETE_ACTION, NESTED_CONNECT_OR_CREATE_ACTION, NESTED_UPSERT_ACTION, INTERNAL_POSITION, SET};
 use crate::core::action::source::ActionSource;
 use crate::core::field::{Field, PreviousValueRule};
@@ -28,6 +29,7 @@ use crate::core::error::{Error, ErrorType};
 use crate::core::field::write_rule::WriteRule;
 use crate::core::relation::delete_rule::DeleteRule;
 use crate::core::relation::delete_rule::DeleteRule::Deny;
+use crate::core::relation::update_rule::UpdateRule;
 use crate::core::result::Result;
 use crate::teon;
 
@@ -175,8 +177,6 @@ impl Object {
                     match Input::decode_field(value) {
                         AtomicUpdator(updator) => self.set_value_to_atomic_updator_map(key, updator),
                         SetValue(value) => {
-                            // record previous value if needed
-                            self.record_previous_value_for_field_if_needed(field);
                             // on set pipeline
                             let context = Ctx::initial_state_with_object(self.clone())
                                 .with_path(path.clone())
@@ -237,7 +237,8 @@ impl Object {
 
     fn record_previous_value_for_field_if_needed(&self, field: &Field) {
         if !self.is_new() && field.previous_value_rule == PreviousValueRule::Keep {
-            if self.inner.previous_value_map.lock().unwrap().get(field.name()).is_none() {
+            // the value before the first modification since the last save is the stored one
+            if !self.inner.modified_fields.lock().unwrap().contains(field.name()) {
                 self.inner.previous_value_map.lock().unwrap().insert(field.name().to_string(), self.get_value(field.name()).unwrap());
             }
         }
@@ -330,6 +331,9 @@ impl Object {
     }
 
     fn set_value_to_value_map(&self, key: &str, value: Value) {
+        if let Some(field) = self.model().field(key) {
+            self.record_previous_value_for_field_if_needed(field);
+        }
         if value.is_null() {
             self.inner.value_map.lock().unwrap().remove(key);
         } else {
@@ -432,6 +436,17 @@ impl Object {
         }
     }
 
+    /// Returns the value which is stored in the database for `key`, this is the previous value
+    /// if the key is modified and the current value otherwise.
+    fn get_stored_value(&self, key: &str) -> Value {
+        if self.inner.modified_fields.lock().unwrap().contains(key) {
+            if let Some(value) = self.inner.previous_value_map.lock().unwrap().get(key) {
+                return value.clone();
+            }
+        }
+        self.get_value(key).unwrap()
+    }
+
     fn get_value_map_value(&self, key: &str) -> Value {
         match self.inner.value_map.lock().unwrap().get(key) {
             Some(value) => value.clone(),
@@ -534,6 +549,7 @@ impl Object {
                         return Err(Error::unexpected_input_value_with_reason(err.message, &(path + key)));
                     }
                     Ok(value) => {
+                        self.record_previous_value_for_field_if_needed(field);
                         self.inner.value_map.lock().unwrap().insert(key.to_string(), value);
                         if !self.inner.is_new.load(Ordering::SeqCst) {
                             self.inner.is_modified.store(true, Ordering::SeqCst);
@@ -652,8 +668,9 @@ impl Object {
         self.inner.is_new.store(false, Ordering::SeqCst);
         self.inner.is_modified.store(false, Ordering::SeqCst);
         if is_new && self.model().identity() && self.action_source().is_identity() && self.action_source().as_identity().is_none() {
+            let request = self.action_source().as_request().cloned();
             let mut_inner = self.inner.as_ref().to_mut();
-            mut_inner.action_source = ActionSource::Identity(Some(self.clone()));
+            mut_inner.action_source = ActionSource::Identity(Some(self.clone()), request);
         }
     }
 
@@ -665,8 +682,13 @@ impl Object {
 
     #[async_recursion]
     pub(crate) async fn delete_from_database(&self, session: Arc<dyn SaveSession>) -> Result<()> {
+        if self.is_new() {
+            return Err(Error::object_is_not_saved_thus_cant_be_deleted());
+        }
         let model = self.model();
-        let graph = self.graph();
+        // related records are read and written in the session, so that the rules are rolled
+        // back with the delete
+        let graph = &self.graph().with_session(session.clone());
         // check deny first
         for relation in model.relations() {
             if relation.through().is_some() {
@@ -675,18 +697,16 @@ impl Object {
             let (opposite_model, opposite_relation) = graph.opposite_relation(relation);
             if let Some(opposite_relation) = opposite_relation {
                 if opposite_relation.delete_rule() == Deny {
-                    let finder = self.intrinsic_where_unique_for_relation(relation);
-                    let count = graph.count(opposite_model.name(), &finder).await.unwrap();
+                    let finder = teon!({ "where": self.intrinsic_where_unique_for_relation(relation) });
+                    let count = graph.count(opposite_model.name(), &finder).await?;
                     if count > 0 {
                         return Err(Error::deletion_denied(relation.name()));
                     }
                 }
             }
         }
-        // real delete
-        let connector = self.graph().connector();
-        connector.delete_object(self, session.clone()).await?;
-        // nullify and cascade
+        // nullify and cascade before the real delete, so that database constraints don't handle
+        // related records without running their callbacks
         for relation in model.relations() {
             if relation.through().is_some() {
                 continue
@@ -700,20 +720,100 @@ impl Object {
                         if !opposite_relation.has_foreign_key() {
                             continue
                         }
-                        let finder = self.intrinsic_where_unique_for_relation(relation);
-                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | DISCONNECT | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| async move {
-                            for key in opposite_relation.fields() {
-                                object.set_value(key, Value::Null)?;
+                        let finder = teon!({ "where": self.intrinsic_where_unique_for_relation(relation) });
+                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | DISCONNECT | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| {
+                            let session = session.clone();
+                            async move {
+                                for key in opposite_relation.fields() {
+                                    object.set_value(key, Value::Null)?;
+                                }
+                                object.save_with_session_and_path(session, &path![]).await?;
+                                Ok(())
                             }
-                            object.save_with_session_and_path(self.graph().connector().new_save_session(), &path![]).await?;
-                            Ok(())
                         }).await?;
                     },
                     DeleteRule::Cascade => {
-                        let finder = self.intrinsic_where_unique_for_relation(relation);
-                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | DELETE | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| async move {
-                            object.delete_from_database(self.graph().connector().new_save_session()).await?;
-                            Ok(())
+                        let finder = teon!({ "where": self.intrinsic_where_unique_for_relation(relation) });
+                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | DELETE | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| {
+                            let session = session.clone();
+                            async move {
+                                object.delete_from_database(session).await?;
+                                Ok(())
+                            }
+                        }).await?;
+                    }
+                }
+            }
+        }
+        // real delete
+        let connector = self.graph().connector();
+        connector.delete_object(self, session.clone()).await?;
+        Ok(())
+    }
+
+    /// Returns relations whose related records reference keys of this object which are modified.
+    fn relations_with_modified_referenced_keys(&self) -> Vec<&Relation> {
+        if self.is_new() {
+            return vec![];
+        }
+        let modified_fields = self.inner.modified_fields.lock().unwrap();
+        self.model().relations().iter().map(|r| r.as_ref()).filter(|relation| {
+            relation.through().is_none() && !relation.has_foreign_key() && relation.fields().iter().any(|f| modified_fields.contains(f))
+        }).collect()
+    }
+
+    fn previous_where_unique_for_relation(&self, relation: &Relation) -> Value {
+        Value::HashMap(relation.iter().map(|(f, r)| (r.to_owned(), self.get_stored_value(f))).collect())
+    }
+
+    async fn check_update_deny_rules(&self) -> Result<()> {
+        let graph = self.graph();
+        for relation in self.relations_with_modified_referenced_keys() {
+            let (opposite_model, opposite_relation) = graph.opposite_relation(relation);
+            if let Some(opposite_relation) = opposite_relation {
+                if opposite_relation.update_rule() == UpdateRule::Deny {
+                    let finder = teon!({ "where": self.previous_where_unique_for_relation(relation) });
+                    let count = graph.count(opposite_model.name(), &finder).await?;
+                    if count > 0 {
+                        return Err(Error::update_denied(relation.name()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_update_rules(&self, relations: Vec<&Relation>, session: Arc<dyn SaveSession>) -> Result<()> {
+        let graph = self.graph();
+        for relation in relations {
+            let (opposite_model, opposite_relation) = graph.opposite_relation(relation);
+            if let Some(opposite_relation) = opposite_relation {
+                let finder = teon!({ "where": self.previous_where_unique_for_relation(relation) });
+                match opposite_relation.update_rule() {
+                    UpdateRule::Default => {}, // do nothing
+                    UpdateRule::Deny => {}, // done before
+                    UpdateRule::Nullify => {
+                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | DISCONNECT | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| {
+                            let session = session.clone();
+                            async move {
+                                for key in opposite_relation.fields() {
+                                    object.set_value(key, Value::Null)?;
+                                }
+                                object.save_with_session_and_path(session, &path![]).await?;
+                                Ok(())
+                            }
+                        }).await?;
+                    }
+                    UpdateRule::Cascade => {
+                        graph.batch(opposite_model.name(), &finder, Action::from_u32(PROGRAM_CODE | UPDATE | (if relation.is_vec() { MANY } else { SINGLE })), ActionSource::ProgramCode, |object| {
+                            let session = session.clone();
+                            async move {
+                                for (key, reference) in opposite_relation.iter() {
+                                    object.set_value(key, self.get_value(reference)?)?;
+                                }
+                                object.save_with_session_and_path(session, &path![]).await?;
+                                Ok(())
+                            }
                         }).await?;
                     }
                 }
@@ -752,7 +852,12 @@ impl Object {
             // perform relation manipulations (has foreign key)
             self.perform_relation_manipulations(|r| r.has_foreign_key(), session.clone(), path).await?;
             if !self.model().r#virtual() {
+                let updated_relations = self.relations_with_modified_referenced_keys();
+                if !updated_relations.is_empty() {
+                    self.check_update_deny_rules().await?;
+                }
                 self.save_to_database(session.clone()).await?;
+                self.apply_update_rules(updated_relations, session.clone()).await?;
             }
         } else {
             // perform relation manipulations (has foreign key)
@@ -769,8 +874,9 @@ impl Object {
     }
 
     pub async fn save(&self) -> Result<()> {
-        let session = self.graph().connector().new_save_session();
-        self.save_with_session_and_path(session, &path![]).await
+        self.graph().run_in_save_session(|_, session| async move {
+            self.save_with_session_and_path(session, &path![]).await
+        }).await
     }
 
     async fn trigger_before_delete_callbacks<'a>(&self, path: impl AsRef<KeyPath<'a>>) -> Result<()> {
@@ -810,13 +916,17 @@ impl Object {
 
     pub async fn delete(&self) -> Result<()> {
         self.trigger_before_delete_callbacks(path![]).await?;
-        self.delete_from_database(self.graph().connector().new_save_session()).await
+        self.graph().run_in_save_session(|_, session| async move {
+            self.delete_from_database(session).await
+        }).await
     }
 
     pub(crate) async fn delete_internal<'a>(&self, path: impl AsRef<KeyPath<'a>>) -> Result<()> {
         self.check_model_write_permission(path.as_ref()).await?;
         self.trigger_before_delete_callbacks(path.as_ref()).await?;
-        self.delete_from_database(self.graph().connector().new_save_session()).await?;
+        self.graph().run_in_save_session(|_, session| async move {
+            self.delete_from_database(session).await
+        }).await?;
         self.trigger_after_delete_callbacks(path.as_ref()).await
     }
 
@@ -909,17 +1019,34 @@ impl Object {
         Value::HashMap(identifier)
     }
 
+    /// Returns the identifier as a JSON object with the primary keys in their declared order, so
+    /// that the same record always serializes to the same auth token id and rate limit key.
+    pub(crate) fn json_identifier(&self) -> JsonValue {
+        let model = self.model();
+        let mut identifier: IndexMap<String, Value> = IndexMap::new();
+        for item in model.primary_index().items() {
+            identifier.insert(item.field_name().to_owned(), self.get_value(item.field_name()).unwrap());
+        }
+        Value::IndexMap(identifier).into()
+    }
+
+    /// Returns the identifier which the record is stored with in the database, before the primary
+    /// keys are modified.
+    pub(crate) fn previous_identifier(&self) -> Value {
+        let model = self.model();
+        let mut identifier: HashMap<String, Value> = HashMap::new();
+        for item in model.primary_index().items() {
+            identifier.insert(item.field_name().to_owned(), self.get_stored_value(item.field_name()));
+        }
+        Value::HashMap(identifier)
+    }
+
     pub(crate) fn db_identifier(&self) -> Value {
         let model = self.model();
         let mut identifier: HashMap<String, Value> = HashMap::new();
-        let modified_fields = self.inner.modified_fields.lock().unwrap();
         for item in model.primary_index().items() {
-            let val = if modified_fields.contains(item.field_name()) {
-                self.get_previous_value(item.field_name()).unwrap()
-            } else {
-                self.get_value(item.field_name()).unwrap()
-            };
-            identifier.insert(self.model().field(item.field_name()).unwrap().column_name().to_owned(), val.clone());
+            let val = self.get_stored_value(item.field_name());
+            identifier.insert(self.model().field(item.field_name()).unwrap().column_name().to_owned(), val);
         }
         Value::HashMap(identifier)
     }
@@ -1005,15 +1132,16 @@ impl Object {
         let foreign = opposite_relation.local();
         let join_local_relation = join_model.relation(local).unwrap();
         let join_foreign_relation = join_model.relation(foreign).unwrap();
-        let local_join_relation = self.graph().opposite_relation(join_local_relation).1.unwrap();
-        let foreign_join_relation = self.graph().opposite_relation(join_foreign_relation).1.unwrap();
-        let where_local = self.intrinsic_where_unique_for_relation(local_join_relation);
-        let where_foreign = object.intrinsic_where_unique_for_relation(foreign_join_relation);
+        // the join record is keyed by the linked values of both sides
         let mut r#where_map: HashMap<String, Value> = hashmap!{};
-        r#where_map.extend(where_local.as_hashmap().cloned().unwrap());
-        r#where_map.extend(where_foreign.as_hashmap().cloned().unwrap());
+        for (field, reference) in join_local_relation.iter() {
+            r#where_map.insert(field.to_owned(), self.get_value(reference).unwrap());
+        }
+        for (field, reference) in join_foreign_relation.iter() {
+            r#where_map.insert(field.to_owned(), object.get_value(reference).unwrap());
+        }
         let r#where = Value::HashMap(r#where_map);
-        let object = match self.graph().find_unique_internal(relation.model(), &teon!({ "where": r#where }), true, action, self.action_source().clone()).await {
+        let object = match self.graph().find_unique_internal(join_model.name(), &teon!({ "where": r#where }), true, action, self.action_source().clone()).await {
             Ok(object) => object,
             Err(_) => return Err(Error::unexpected_input_value_with_reason("Join object is not found.", path)),
         };
@@ -1,5 +1,6 @@
 use key_path::KeyPath;
 use crate::core::action::Action;
+use crate::core::action::request::RequestCtx;
 use crate::core::object::Object;
 use crate::core::result::Result;
 use crate::core::teon::Value;
@@ -11,6 +12,7 @@ pub struct Ctx<'a> {
     pub(crate) object: Option<Object>,
     pub(crate) path: KeyPath<'a>,
     pub(crate) action: Action,
+    pub(crate) request: Option<RequestCtx>,
 }
 
 impl<'a> Ctx<'a> {
@@ -21,15 +23,18 @@ impl<'a> Ctx<'a> {
             object: None,
             path: KeyPath::default(),
             action: Action::empty(),
+            request: None,
         }
     }
 
     pub(crate) fn initial_state_with_object(object: Object) -> Self {
+        let request = object.action_source().as_request().cloned();
         Self {
             value: Value::Null,
             object: Some(object),
             path: KeyPath::default(),
             action: Action::empty(),
+            request,
         }
     }
 
@@ -39,6 +44,7 @@ impl<'a> Ctx<'a> {
             object: self.object.clone(),
             path: path.as_ref().clone(),
             action: self.action.clone(),
+            request: self.request.clone(),
         }
     }
 
@@ -48,6 +54,7 @@ impl<'a> Ctx<'a> {
             object: self.object.clone(),
             path: self.path.clone(),
             action: self.action.clone(),
+            request: self.request.clone(),
         }
     }
 
@@ -58,6 +65,7 @@ impl<'a> Ctx<'a> {
                 object: self.object.clone(),
                 path: self.path.clone(),
                 action: self.action.clone(),
+                request: self.request.clone(),
             }),
             Err(err) => Err(err),
         }
@@ -73,6 +81,17 @@ impl<'a> Ctx<'a> {
             object: self.object.clone(),
             path: self.path.clone(),
             action,
+            request: self.request.clone(),
+        }
+    }
+
+    pub(crate) fn with_request(&self, request: Option<RequestCtx>) -> Self {
+        Self {
+            value: self.value.clone(),
+            object: self.object.clone(),
+            path: self.path.clone(),
+            action: self.action.clone(),
+            request,
         }
     }
 
@@ -107,6 +126,7 @@ impl<'a> Ctx<'a> {
             object: self.object.clone(),
             path: self.path.clone(),
             action: self.action.redirect(action),
+            request: self.request.clone(),
         }
     }
 }
@@ -19,6 +19,7 @@ impl Item for GetLengthItem {
         let len = match &ctx.value {
             Value::String(s) => s.len(),
             Value::Vec(v) => v.len(),
+            Value::Bytes(b) => b.len(),
             _ => {
                 return Err(ctx.internal_server_error("getLength: value is not vector"));
             }
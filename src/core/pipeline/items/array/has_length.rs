@@ -12,6 +12,18 @@ impl HasLengthItem {
     pub fn new(argument: Value) -> Self {
         Self { argument: argument.into() }
     }
+
+    fn length(ctx: &Ctx, value: &Value) -> Result<usize> {
+        let length = match value {
+            Value::I32(n) => usize::try_from(*n).ok(),
+            Value::I64(n) => usize::try_from(*n).ok(),
+            _ => None,
+        };
+        match length {
+            Some(n) => Ok(n),
+            None => Err(ctx.internal_server_error("hasLength: length is not a non negative int")),
+        }
+    }
 }
 
 #[async_trait]
@@ -19,19 +31,19 @@ impl Item for HasLengthItem {
     async fn call<'a>(&self, ctx: Ctx<'a>) -> Result<Ctx<'a>> {
         let argument = self.argument.resolve(ctx.clone()).await?;
         let (lower, upper, closed) = if argument.is_number() {
-            let n = self.argument.as_usize().unwrap();
+            let n = Self::length(&ctx, &argument)?;
             (n, n, true)
-        } else if argument.is_range() {
-            let r = self.argument.as_range().unwrap();
-            let start = r.start.resolve(ctx.clone()).await?.as_usize().unwrap();
-            let end = r.end.resolve(ctx.clone()).await?.as_usize().unwrap();
+        } else if let Some(r) = argument.as_range() {
+            let start = Self::length(&ctx, &r.start.resolve(ctx.clone()).await?)?;
+            let end = Self::length(&ctx, &r.end.resolve(ctx.clone()).await?)?;
             (start, end, r.closed)
         } else {
-            unreachable!()
+            return Err(ctx.internal_server_error("hasLength: argument is not a length or a range"));
         };
         let len = match &ctx.value {
             Value::String(s) => s.len(),
             Value::Vec(v) => v.len(),
+            Value::Bytes(b) => b.len(),
             _ => {
                 return Err(ctx.with_invalid("Value doesn't have length."));
             }
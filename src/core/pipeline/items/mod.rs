@@ -13,3 +13,4 @@ pub mod vector;
 pub mod action;
 pub mod debug;
 pub mod query;
+pub mod request;
@@ -66,6 +66,12 @@ impl Item for GetItem {
                         None => Err(ctx.internal_server_error("get: value at key does not exist"))
                     }
                 }
+                Value::IndexMap(indexmap) => {
+                    match indexmap.get(&s) {
+                        Some(val) => Ok(ctx.with_value(val.clone())),
+                        None => Err(ctx.internal_server_error("get: value at key does not exist"))
+                    }
+                }
                 _ => Err(ctx.internal_server_error("get: ctx value is not map"))
             }
             _ => Err(ctx.internal_server_error("get: incorrect key type"))
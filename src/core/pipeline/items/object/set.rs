@@ -82,6 +82,12 @@ impl Item for SetItem {
                             new_map.insert(s.to_owned(), value);
                             Ok(ctx.with_value(Value::BTreeMap(new_map)))
                         }
+                        Value::IndexMap(indexmap) => {
+                            let value = self.value.resolve(ctx.clone()).await?;
+                            let mut new_map = indexmap.clone();
+                            new_map.insert(s.to_owned(), value);
+                            Ok(ctx.with_value(Value::IndexMap(new_map)))
+                        }
                         _ => Err(ctx.internal_server_error("set: ctx value is not map"))
                     }
                     _ => Err(ctx.internal_server_error("set: incorrect key type"))
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use crate::core::pipeline::item::Item;
+use crate::core::teon::Value;
+use crate::core::result::Result;
+use crate::core::pipeline::ctx::Ctx;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ClientIpItem {}
+
+impl ClientIpItem {
+    pub fn new() -> Self {
+        ClientIpItem { }
+    }
+}
+
+#[async_trait]
+impl Item for ClientIpItem {
+    async fn call<'a>(&self, ctx: Ctx<'a>) -> Result<Ctx<'a>> {
+        let value = match ctx.request.as_ref().and_then(|r| r.client_ip()) {
+            Some(ip) => Value::String(ip.to_owned()),
+            None => Value::Null,
+        };
+        Ok(ctx.with_value(value))
+    }
+}
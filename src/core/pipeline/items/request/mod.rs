@@ -0,0 +1,3 @@
+pub mod request_id;
+pub mod request_header;
+pub mod client_ip;
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use crate::core::pipeline::item::Item;
+use crate::core::teon::Value;
+use crate::core::result::Result;
+use crate::core::pipeline::ctx::Ctx;
+
+#[derive(Debug, Clone)]
+pub struct RequestHeaderItem {
+    name: String
+}
+
+impl RequestHeaderItem {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+#[async_trait]
+impl Item for RequestHeaderItem {
+    async fn call<'a>(&self, ctx: Ctx<'a>) -> Result<Ctx<'a>> {
+        let value = match ctx.request.as_ref().and_then(|r| r.header(&self.name)) {
+            Some(header) => Value::String(header.to_owned()),
+            None => Value::Null,
+        };
+        Ok(ctx.with_value(value))
+    }
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use crate::core::pipeline::item::Item;
+use crate::core::teon::Value;
+use crate::core::result::Result;
+use crate::core::pipeline::ctx::Ctx;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RequestIdItem {}
+
+impl RequestIdItem {
+    pub fn new() -> Self {
+        RequestIdItem { }
+    }
+}
+
+#[async_trait]
+impl Item for RequestIdItem {
+    async fn call<'a>(&self, ctx: Ctx<'a>) -> Result<Ctx<'a>> {
+        let value = match &ctx.request {
+            Some(request) => Value::String(request.id().to_owned()),
+            None => Value::Null,
+        };
+        Ok(ctx.with_value(value))
+    }
+}
@@ -5,6 +5,7 @@ use crate::core::database::r#type::DatabaseType;
 use crate::core::field::optionality::Optionality;
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
 use crate::core::pipeline::Pipeline;
+use crate::core::result::Result;
 
 #[derive(Clone)]
 pub struct Property {
@@ -59,8 +60,9 @@ impl Property {
         self.optionality.is_required()
     }
 
-    pub(crate) fn finalize(&mut self, connector: Arc<dyn Connector>) {
-        self.database_type = Some(connector.default_database_type(self.field_type()));
+    pub(crate) fn finalize(&mut self, connector: Arc<dyn Connector>) -> Result<()> {
+        self.database_type = Some(connector.default_database_type(self.field_type())?);
+        Ok(())
     }
 
     pub(crate) fn set_required(&mut self) {
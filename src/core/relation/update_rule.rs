@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UpdateRule {
+    Default,
+    Nullify,
+    Cascade,
+    Deny,
+}
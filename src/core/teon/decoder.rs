@@ -1,10 +1,12 @@
 use std::collections::{HashSet, HashMap, BTreeMap};
 use std::ops::BitOr;
 use std::str::FromStr;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use bigdecimal::{BigDecimal, FromPrimitive};
 #[cfg(feature = "data-source-mongodb")]
 use bson::oid::ObjectId;
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use key_path::{KeyPath, path};
 use maplit::{hashmap, hashset};
 use once_cell::sync::Lazy;
@@ -26,6 +28,14 @@ impl Decoder {
         Self::decode_object_at_path(model, graph, json_value, path![])
     }
 
+    /// Decodes the identifier of a record, which holds exactly the keys of the primary index.
+    pub(crate) fn decode_identifier(model: &Model, graph: &Graph, json_value: &JsonValue) -> Result<Value> {
+        match json_value.as_object() {
+            Some(json_map) => Self::decode_where_unique_keys(model, graph, model.primary_index().keys(), json_map, path![]),
+            None => Err(Error::unexpected_input_type("object", path![])),
+        }
+    }
+
     pub(crate) fn decode_action_arg(model: &Model, graph: &Graph, action: Action, json_value: &JsonValue) -> Result<Value> {
         Self::decode_action_arg_at_path(model, graph, action, json_value, path![])
     }
@@ -777,19 +787,44 @@ impl Decoder {
             return Err(Error::unexpected_input_value_with_reason("Unique where can't be empty.", path));
         }
         for index in model.indices() {
-            if index.keys() == &json_map.keys().into_iter().map(|k| k.to_owned()).collect::<Vec<String>>() {
-                let mut retval: HashMap<String, Value> = HashMap::new();
-                for (key, value) in json_map {
-                    let field = model.field(key).unwrap();
-                    let path = path + key;
-                    retval.insert(key.to_owned(), Self::decode_value_for_field_type(graph, field.field_type(), field.is_optional(), value, path)?);
-                    return Ok(Value::HashMap(retval));
+            if !index.r#type().is_unique() { continue }
+            let keys = index.keys();
+            // compound keys are nested under their joined name, like `{ a_b: { a, b } }`
+            if keys.len() > 1 && json_map.len() == 1 {
+                let compound_name = keys.join("_");
+                if let Some(compound_value) = json_map.get(&compound_name) {
+                    let path = path + compound_name.as_str();
+                    return match compound_value.as_object() {
+                        Some(compound_map) => Self::decode_where_unique_keys(model, graph, keys, compound_map, path),
+                        None => Err(Error::unexpected_input_type("object", path)),
+                    };
                 }
             }
+            if keys.len() == json_map.len() && keys.iter().all(|k| json_map.contains_key(k)) {
+                return Self::decode_where_unique_keys(model, graph, keys, json_map, path);
+            }
         }
         Err(Error::unexpected_input_key(json_map.keys().next().unwrap(), path))
     }
 
+    fn decode_where_unique_keys<'a>(model: &Model, graph: &Graph, keys: &Vec<String>, json_map: &JsonMap<String, JsonValue>, path: impl AsRef<KeyPath<'a>>) -> Result<Value> {
+        let path = path.as_ref();
+        if let Some(unexpected) = json_map.keys().find(|k| !keys.contains(k)) {
+            return Err(Error::unexpected_input_key(unexpected, path));
+        }
+        let mut retval: HashMap<String, Value> = HashMap::new();
+        for key in keys {
+            let path = path + key;
+            let value = match json_map.get(key) {
+                Some(value) => value,
+                None => return Err(Error::missing_required_input(path)),
+            };
+            let field = model.field(key).unwrap();
+            retval.insert(key.to_owned(), Self::decode_value_for_field_type(graph, field.field_type(), field.is_optional(), value, path)?);
+        }
+        Ok(Value::HashMap(retval))
+    }
+
     fn decode_where_for_field_internal<'a>(graph: &Graph, r#type: &FieldType, optional: bool, json_value: &JsonValue, path: impl AsRef<KeyPath<'a>>, aggregate: bool) -> Result<Value> {
         let path = path.as_ref();
         if json_value.is_object() {
@@ -803,7 +838,9 @@ impl Decoder {
                     "equals" => {
                         retval.insert(key.to_owned(), Self::decode_value_for_field_type(graph, r#type, optional, value, path)?);
                     }
-                    "not" => {
+                    "not" => if r#type.is_json() {
+                        retval.insert(key.to_owned(), Self::decode_value_for_field_type(graph, r#type, optional, value, path)?);
+                    } else {
                         retval.insert(key.to_owned(), Self::decode_where_for_field(graph, r#type, optional, value, path)?);
                     }
                     "gt" | "gte" | "lt" | "lte" | "contains" | "startsWith" | "endsWith" | "matches" => {
@@ -812,6 +849,9 @@ impl Decoder {
                     "in" | "notIn" => {
                         retval.insert(key.to_owned(), Self::decode_value_array_for_field_type(graph, r#type, false, value, path)?);
                     }
+                    "path" => {
+                        retval.insert(key.to_owned(), Self::decode_json_path(value, path)?);
+                    }
                     "mode" => match value.as_str() {
                         Some(s) => if s == "caseInsensitive" {
                             retval.insert(key.to_owned(), Value::String("caseInsensitive".to_owned()));
@@ -890,6 +930,9 @@ impl Decoder {
 
     fn decode_value_or_updator_for_field_type<'a>(graph: &Graph, r#type: &FieldType, optional: bool, json_value: &JsonValue, path: impl AsRef<KeyPath<'a>>, set_only: bool) -> Result<Value> {
         let path = path.as_ref();
+        if r#type.is_json() {
+            return Self::decode_value_for_field_type(graph, r#type, optional, json_value, path);
+        }
         if let Some(json_map) = json_value.as_object() {
             Self::check_length_1(json_value, path)?;
             Self::check_json_keys(json_map, if set_only { r#type.default_updators() } else { r#type.updators() }, path)?;
@@ -973,6 +1016,21 @@ impl Decoder {
                 }
                 None => Err(Error::unexpected_input_type("datetime string", path))
             }
+            FieldType::Time => match json_value.as_str() {
+                Some(s) => match NaiveTime::parse_from_str(s, "%H:%M:%S%.f") {
+                    Ok(naive_time) => Ok(Value::Time(naive_time)),
+                    Err(_) => Err(Error::unexpected_input_value("time string", path))
+                }
+                None => Err(Error::unexpected_input_type("time string", path))
+            }
+            FieldType::Bytes => match json_value.as_str() {
+                Some(s) => match STANDARD.decode(s) {
+                    Ok(bytes) => Ok(Value::Bytes(bytes)),
+                    Err(_) => Err(Error::unexpected_input_value("base64 string", path))
+                }
+                None => Err(Error::unexpected_input_type("base64 string", path))
+            }
+            FieldType::Json => Ok(Self::decode_json(json_value)),
             FieldType::Enum(enum_def) => {
                 let enum_name = enum_def.name();
                 match json_value.as_str() {
@@ -1011,6 +1069,33 @@ impl Decoder {
             FieldType::Object(_) => panic!("Object input is not implemented yet.")
         }
     }
+
+    /// Objects are decoded into index maps. This keeps the key order of the document and tells
+    /// them apart from updators, which are hash maps.
+    pub(crate) fn decode_json(json_value: &JsonValue) -> Value {
+        match json_value {
+            JsonValue::Object(map) => Value::IndexMap(map.iter().map(|(k, v)| (k.to_owned(), Self::decode_json(v))).collect()),
+            JsonValue::Array(array) => Value::Vec(array.iter().map(Self::decode_json).collect()),
+            _ => json_value.into(),
+        }
+    }
+
+    fn decode_json_path<'a>(json_value: &JsonValue, path: impl AsRef<KeyPath<'a>>) -> Result<Value> {
+        let path = path.as_ref();
+        if let Some(array) = json_value.as_array() {
+            Ok(Value::Vec(array.iter().enumerate().map(|(i, v)| {
+                if let Some(s) = v.as_str() {
+                    Ok(Value::String(s.to_owned()))
+                } else if let Some(u) = v.as_u64() {
+                    Ok(Value::I64(u as i64))
+                } else {
+                    Err(Error::unexpected_input_type("string or positive integer number", path + i))
+                }
+            }).collect::<Result<Vec<Value>>>()?))
+        } else {
+            Err(Error::unexpected_input_type("array", path))
+        }
+    }
 }
 
 static NESTED_UPDATE_INPUT_KEYS: Lazy<HashSet<&str>> = Lazy::new(|| {
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 use bson::oid::ObjectId;
-use chrono::{NaiveDate, DateTime, Utc};
+use chrono::{NaiveDate, NaiveTime, DateTime, Utc};
 use bigdecimal::BigDecimal;
 use crate::core::teon::Value;
 use crate::prelude::Object;
@@ -149,6 +149,70 @@ impl From<Value> for Option<NaiveDate> {
     }
 }
 
+// MARK: - Time
+
+impl From<NaiveTime> for Value {
+    fn from(v: NaiveTime) -> Self {
+        Value::Time(v)
+    }
+}
+
+impl From<Value> for NaiveTime {
+    fn from(v: Value) -> Self {
+        v.as_time().unwrap().to_owned()
+    }
+}
+
+impl From<Option<NaiveTime>> for Value {
+    fn from(n: Option<NaiveTime>) -> Self {
+        match n {
+            Some(n) => Value::Time(n),
+            None => Value::Null,
+        }
+    }
+}
+
+impl From<Value> for Option<NaiveTime> {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => None,
+            _ => Some(value.into())
+        }
+    }
+}
+
+// MARK: - Bytes
+
+impl From<Vec<u8>> for Value {
+    fn from(v: Vec<u8>) -> Self {
+        Value::Bytes(v)
+    }
+}
+
+impl From<Value> for Vec<u8> {
+    fn from(v: Value) -> Self {
+        v.as_bytes().unwrap().to_owned()
+    }
+}
+
+impl From<Option<Vec<u8>>> for Value {
+    fn from(n: Option<Vec<u8>>) -> Self {
+        match n {
+            Some(n) => Value::Bytes(n),
+            None => Value::Null,
+        }
+    }
+}
+
+impl From<Value> for Option<Vec<u8>> {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => None,
+            _ => Some(value.into())
+        }
+    }
+}
+
 impl From<DateTime<Utc>> for Value {
     fn from(v: DateTime<Utc>) -> Self {
         Value::DateTime(v)
@@ -46,6 +46,7 @@ impl Index for str {
         match v {
             Value::HashMap(map) => map.get(self),
             Value::BTreeMap(map) => map.get(self),
+            Value::IndexMap(map) => map.get(self),
             _ => None,
         }
     }
@@ -53,6 +54,7 @@ impl Index for str {
         match v {
             Value::HashMap(map) => map.get_mut(self),
             Value::BTreeMap(map) => map.get_mut(self),
+            Value::IndexMap(map) => map.get_mut(self),
             _ => None,
         }
     }
@@ -63,6 +65,7 @@ impl Index for str {
         match v {
             Value::HashMap(map) => map.entry(self.to_owned()).or_insert(Value::Null),
             Value::BTreeMap(map) => map.entry(self.to_owned()).or_insert(Value::Null),
+            Value::IndexMap(map) => map.entry(self.to_owned()).or_insert(Value::Null),
             _ => panic!("cannot access key {:?} in JSON {}", self, Type(v)),
         }
     }
@@ -112,6 +115,8 @@ impl<'a> Display for Type<'a> {
             Value::Decimal(_) => formatter.write_str("decimal"),
             Value::Date(_) => formatter.write_str("date"),
             Value::DateTime(_) => formatter.write_str("date time"),
+            Value::Time(_) => formatter.write_str("time"),
+            Value::Bytes(_) => formatter.write_str("bytes"),
             Value::BTreeMap(_) => formatter.write_str("btree map"),
             Value::IndexMap(_) => formatter.write_str("index map"),
             Value::Object(_) => formatter.write_str("object"),
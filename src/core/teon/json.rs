@@ -1,3 +1,5 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use chrono::SecondsFormat;
 use serde_json::{Value as JsonValue, Number as JsonNumber, Map as JsonMap, json};
 use crate::core::teon::Value;
@@ -39,6 +41,12 @@ impl Into<JsonValue> for Value {
             Value::DateTime(val) => {
                 json!({"$date": val.to_rfc3339_opts(SecondsFormat::Millis, true)})
             }
+            Value::Time(val) => {
+                JsonValue::String(val.format("%H:%M:%S%.f").to_string())
+            }
+            Value::Bytes(val) => {
+                json!({"$bytes": STANDARD.encode(val)})
+            }
             Value::Vec(val) => {
                 JsonValue::Array(val.iter().map(|v| v.into()).collect())
             }
@@ -78,3 +86,25 @@ impl Into<JsonValue> for &Value {
         self.clone().into()
     }
 }
+
+impl From<&JsonValue> for Value {
+    fn from(json_value: &JsonValue) -> Self {
+        match json_value {
+            JsonValue::Null => Value::Null,
+            JsonValue::Bool(b) => Value::Bool(*b),
+            JsonValue::Number(n) => match n.as_i64() {
+                Some(i) => Value::I64(i),
+                None => Value::F64(n.as_f64().unwrap()),
+            },
+            JsonValue::String(s) => Value::String(s.clone()),
+            JsonValue::Array(a) => Value::Vec(a.iter().map(|v| v.into()).collect()),
+            JsonValue::Object(o) => Value::HashMap(o.iter().map(|(k, v)| (k.clone(), v.into())).collect()),
+        }
+    }
+}
+
+impl From<JsonValue> for Value {
+    fn from(json_value: JsonValue) -> Self {
+        (&json_value).into()
+    }
+}
@@ -14,7 +14,7 @@ use chrono::prelude::{DateTime, Utc};
 use indexmap::IndexMap;
 #[cfg(feature = "data-source-mongodb")]
 use bson::oid::ObjectId;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 use maplit::hashmap;
 use regex::Regex;
 use bigdecimal::BigDecimal;
@@ -111,6 +111,14 @@ pub enum Value {
     ///
     DateTime(DateTime<Utc>),
 
+    /// Represents a Tson time of day.
+    ///
+    Time(NaiveTime),
+
+    /// Represents a Tson byte array.
+    ///
+    Bytes(Vec<u8>),
+
     /// Represents a Tson array.
     ///
     Vec(Vec<Value>),
@@ -428,6 +436,28 @@ impl Value {
         }
     }
 
+    pub fn is_time(&self) -> bool {
+        self.as_time().is_some()
+    }
+
+    pub fn as_time(&self) -> Option<&NaiveTime> {
+        match self {
+            Value::Time(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    pub fn is_bytes(&self) -> bool {
+        self.as_bytes().is_some()
+    }
+
+    pub fn as_bytes(&self) -> Option<&Vec<u8>> {
+        match self {
+            Value::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "data-source-mongodb")]
     pub fn is_object_id(&self) -> bool {
         self.as_object_id().is_some()
@@ -599,6 +629,8 @@ impl PartialOrd for Value {
             (String(s), String(o)) => s.partial_cmp(o),
             (Date(s), Date(o)) => s.partial_cmp(o),
             (DateTime(s), DateTime(o)) => s.partial_cmp(o),
+            (Time(s), Time(o)) => s.partial_cmp(o),
+            (Bytes(s), Bytes(o)) => s.partial_cmp(o),
             (Vec(s), Vec(o)) => s.partial_cmp(o),
             (HashMap(_s), HashMap(_o)) => None,
             (BTreeMap(_s), BTreeMap(_o)) => None,
@@ -791,6 +823,8 @@ impl PartialEq for Value {
             (String(s), String(o)) => s == o,
             (Date(s), Date(o)) => s == o,
             (DateTime(s), DateTime(o)) => s == o,
+            (Time(s), Time(o)) => s == o,
+            (Bytes(s), Bytes(o)) => s == o,
             (Vec(s), Vec(o)) => s == o,
             (HashMap(s), HashMap(o)) => s == o,
             (IndexMap(s), IndexMap(o)) => s == o,
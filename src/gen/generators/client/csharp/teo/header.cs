@@ -124,6 +124,44 @@ namespace Teo {
         }
     }
 
+    public class JsonFilter {
+        public new JsonElement? Equals { get; set; }
+        public JsonElement? Not { get; set; }
+        public OneOf<string, int>[]? Path { get; set; }
+        public JsonElement? Contains { get; set; }
+
+        public JsonFilter(
+            JsonElement? equals = null,
+            JsonElement? not = null,
+            OneOf<string, int>[]? path = null,
+            JsonElement? contains = null
+        ) {
+            Equals = equals;
+            Not = not;
+            Path = path;
+            Contains = contains;
+        }
+    }
+
+    public class JsonNullableFilter {
+        public new Optional<JsonElement>? Equals { get; set; }
+        public Optional<JsonElement>? Not { get; set; }
+        public OneOf<string, int>[]? Path { get; set; }
+        public JsonElement? Contains { get; set; }
+
+        public JsonNullableFilter(
+            Optional<JsonElement>? equals = null,
+            Optional<JsonElement>? not = null,
+            OneOf<string, int>[]? path = null,
+            JsonElement? contains = null
+        ) {
+            Equals = equals;
+            Not = not;
+            Path = path;
+            Contains = contains;
+        }
+    }
+
     public class NumberFilter<T> where T: struct {
         public new T? Equals { get; set; }
         public T[]? In { get; set; }
@@ -396,6 +434,106 @@ namespace Teo {
         }
     }
 
+    public class TimeOnlyFilter {
+        public new TimeOnly? Equals { get; set; }
+        public TimeOnly[]? In { get; set; }
+        public TimeOnly[]? NotIn { get; set; }
+        public TimeOnly? Lt { get; set; }
+        public TimeOnly? Lte { get; set; }
+        public TimeOnly? Gt { get; set; }
+        public TimeOnly? Gte { get; set; }
+        public OneOf<TimeOnly, TimeOnlyFilter>? Not { get; set; }
+
+        public TimeOnlyFilter(
+            TimeOnly? equals = null,
+            TimeOnly[]? @in = null,
+            TimeOnly[]? notIn = null,
+            TimeOnly? lt = null,
+            TimeOnly? lte = null,
+            TimeOnly? gt = null,
+            TimeOnly? gte = null,
+            OneOf<TimeOnly, TimeOnlyFilter>? not = null
+        ) {
+            Equals = equals;
+            In = @in;
+            NotIn = notIn;
+            Lt = lt;
+            Lte = lte;
+            Gt = gt;
+            Gte = gte;
+            Not = not;
+        }
+    }
+
+    public class TimeOnlyNullableFilter {
+        public new Optional<TimeOnly>? Equals { get; set; }
+        public Optional<TimeOnly>[]? In { get; set; }
+        public Optional<TimeOnly>[]? NotIn { get; set; }
+        public TimeOnly? Lt { get; set; }
+        public TimeOnly? Lte { get; set; }
+        public TimeOnly? Gt { get; set; }
+        public TimeOnly? Gte { get; set; }
+        public OneOf<Optional<TimeOnly>, TimeOnlyNullableFilter>? Not { get; set; }
+
+        public TimeOnlyNullableFilter(
+            Optional<TimeOnly>? equals = null,
+            Optional<TimeOnly>[]? @in = null,
+            Optional<TimeOnly>[]? notIn = null,
+            TimeOnly? lt = null,
+            TimeOnly? lte = null,
+            TimeOnly? gt = null,
+            TimeOnly? gte = null,
+            OneOf<Optional<TimeOnly>, TimeOnlyNullableFilter>? not = null
+        ) {
+            Equals = equals;
+            In = @in;
+            NotIn = notIn;
+            Lt = lt;
+            Lte = lte;
+            Gt = gt;
+            Gte = gte;
+            Not = not;
+        }
+    }
+
+    public class BytesFilter {
+        public new byte[]? Equals { get; set; }
+        public byte[][]? In { get; set; }
+        public byte[][]? NotIn { get; set; }
+        public OneOf<byte[], BytesFilter>? Not { get; set; }
+
+        public BytesFilter(
+            byte[]? equals = null,
+            byte[][]? @in = null,
+            byte[][]? notIn = null,
+            OneOf<byte[], BytesFilter>? not = null
+        ) {
+            Equals = equals;
+            In = @in;
+            NotIn = notIn;
+            Not = not;
+        }
+    }
+
+    public class BytesNullableFilter {
+        public new Optional<byte[]>? Equals { get; set; }
+        public Optional<byte[]>[]? In { get; set; }
+        public Optional<byte[]>[]? NotIn { get; set; }
+        public OneOf<Optional<byte[]>, BytesNullableFilter>? Not { get; set; }
+
+        public BytesNullableFilter(
+            Optional<byte[]>? equals = null,
+            Optional<byte[]>[]? @in = null,
+            Optional<byte[]>[]? notIn = null,
+            OneOf<Optional<byte[]>, BytesNullableFilter>? not = null
+        ) {
+            Equals = equals;
+            In = @in;
+            NotIn = notIn;
+            Not = not;
+        }
+    }
+
     public class EnumFilter<T> where T: struct {
         public new T? Equals { get; set; }
         public T[]? In { get; set; }
@@ -777,6 +915,26 @@ namespace Teo {
         public NullableDateOnlyFieldUpdateOperationsInput(Optional<DateOnly>? set) { Set = set; }
     }
 
+    public class TimeOnlyFieldUpdateOperationsInput {
+        public TimeOnly? Set { get; set; }
+        public TimeOnlyFieldUpdateOperationsInput(TimeOnly? set) { Set = set; }
+    }
+
+    public class NullableTimeOnlyFieldUpdateOperationsInput {
+        public Optional<TimeOnly>? Set { get; set; }
+        public NullableTimeOnlyFieldUpdateOperationsInput(Optional<TimeOnly>? set) { Set = set; }
+    }
+
+    public class BytesFieldUpdateOperationsInput {
+        public byte[]? Set { get; set; }
+        public BytesFieldUpdateOperationsInput(byte[]? set) { Set = set; }
+    }
+
+    public class NullableBytesFieldUpdateOperationsInput {
+        public Optional<byte[]>? Set { get; set; }
+        public NullableBytesFieldUpdateOperationsInput(Optional<byte[]>? set) { Set = set; }
+    }
+
     public class DateTimeFieldUpdateOperationsInput {
         public DateTime? Set { get; set; }
         public DateTimeFieldUpdateOperationsInput(DateTime? set) { Set = set; }
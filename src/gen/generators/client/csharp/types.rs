@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use crate::core::action::{Action, AGGREGATE_HANDLER, COUNT_HANDLER, CREATE_HANDLER, CREATE_MANY_HANDLER, DELETE_HANDLER, DELETE_MANY_HANDLER, FIND_FIRST_HANDLER, FIND_MANY_HANDLER, FIND_UNIQUE_HANDLER, GROUP_BY_HANDLER, IDENTITY_HANDLER, SIGN_IN_HANDLER, UPDATE_HANDLER, UPDATE_MANY_HANDLER, UPSERT_HANDLER};
+use crate::core::action::{Action, AGGREGATE_HANDLER, COUNT_HANDLER, CREATE_HANDLER, CREATE_MANY_HANDLER, DELETE_HANDLER, DELETE_MANY_HANDLER, FIND_FIRST_HANDLER, FIND_MANY_HANDLER, FIND_UNIQUE_HANDLER, GROUP_BY_HANDLER, IDENTITY_HANDLER, REFRESH_TOKEN_HANDLER, SIGN_IN_HANDLER, SIGN_OUT_HANDLER, UPDATE_HANDLER, UPDATE_MANY_HANDLER, UPSERT_HANDLER};
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
 use crate::gen::lib::shared::type_lookup::TypeLookup;
 
@@ -30,6 +30,10 @@ fn array_prefix(t: &str) -> &str {
         "Ref"
     } else if t == "DateTime" {
         "Ref"
+    } else if t == "TimeOnly" {
+        "Ref"
+    } else if t == "byte[]" {
+        "Ref"
     } else {
         "Value"
     }
@@ -48,6 +52,9 @@ impl CSharpTypes {
             FieldType::String => format!("{prefix}StringFieldUpdateOperationsInput"),
             FieldType::Date => format!("{prefix}DateOnlyFieldUpdateOperationsInput"),
             FieldType::DateTime => format!("{prefix}DateTimeFieldUpdateOperationsInput"),
+            FieldType::Time => format!("{prefix}TimeOnlyFieldUpdateOperationsInput"),
+            FieldType::Bytes => format!("{prefix}BytesFieldUpdateOperationsInput"),
+            FieldType::Json => panic!("Json fields are updated with values only."),
             FieldType::Bool => format!("{prefix}BoolFieldUpdateOperationsInput"),
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 | FieldType::Decimal => {
                 let number_type = self.field_type_to_result_type(field_type, false);
@@ -79,6 +86,9 @@ impl TypeLookup for CSharpTypes {
             FieldType::String => one_of(base_type, format!("String{nullable}Filter")),
             FieldType::Date => one_of(base_type, format!("DateOnly{nullable}Filter")),
             FieldType::DateTime => one_of(base_type, format!("DateTime{nullable}Filter")),
+            FieldType::Time => one_of(base_type, format!("TimeOnly{nullable}Filter")),
+            FieldType::Bytes => one_of(base_type, format!("Bytes{nullable}Filter")),
+            FieldType::Json => format!("Json{nullable}Filter"),
             FieldType::Bool => one_of(base_type, format!("Bool{nullable}Filter")),
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 | FieldType::Decimal => {
                 let number_type = self.field_type_to_result_type(field_type, false);
@@ -109,6 +119,9 @@ impl TypeLookup for CSharpTypes {
 
     fn field_type_to_update_type<'a>(&self, field_type: &'a FieldType, optional: bool) -> Cow<'a, str> {
         let create_input = self.field_type_to_create_type(field_type, optional);
+        if field_type.is_json() {
+            return create_input;
+        }
         let operation_input = self.update_operation_input(field_type, optional);
         Cow::Owned(one_of(create_input.as_ref(), operation_input))
     }
@@ -126,6 +139,9 @@ impl TypeLookup for CSharpTypes {
             FieldType::Decimal => Cow::Borrowed("decimal"),
             FieldType::Date => Cow::Borrowed("DateOnly"),
             FieldType::DateTime => Cow::Borrowed("DateTime"),
+            FieldType::Time => Cow::Borrowed("TimeOnly"),
+            FieldType::Bytes => Cow::Borrowed("byte[]"),
+            FieldType::Json => Cow::Borrowed("JsonElement"),
             FieldType::Enum(enum_def) => Cow::Owned(enum_def.name().to_string()),
             FieldType::Vec(inner) => Cow::Owned(self.field_type_to_result_type(inner.field_type(), inner.is_optional()).as_ref().to_owned() + "[]"),
             FieldType::HashMap(_) => panic!(),
@@ -164,6 +180,8 @@ impl TypeLookup for CSharpTypes {
             GROUP_BY_HANDLER => format!("Response<{model_name}>"),
             SIGN_IN_HANDLER => format!("ResponseWithMeta<TokenInfo, {model_name}>"),
             IDENTITY_HANDLER => format!("Response<{model_name}>"),
+            REFRESH_TOKEN_HANDLER => format!("ResponseWithMeta<TokenInfo, {model_name}>"),
+            SIGN_OUT_HANDLER => format!("Response<{model_name}>"),
             _ => unreachable!()
         })
     }
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use crate::core::action::{Action, AGGREGATE_HANDLER, COUNT_HANDLER, CREATE_HANDLER, CREATE_MANY_HANDLER, DELETE_HANDLER, DELETE_MANY_HANDLER, FIND_FIRST_HANDLER, FIND_MANY_HANDLER, FIND_UNIQUE_HANDLER, GROUP_BY_HANDLER, IDENTITY_HANDLER, SIGN_IN_HANDLER, UPDATE_HANDLER, UPDATE_MANY_HANDLER, UPSERT_HANDLER};
+use crate::core::action::{Action, AGGREGATE_HANDLER, COUNT_HANDLER, CREATE_HANDLER, CREATE_MANY_HANDLER, DELETE_HANDLER, DELETE_MANY_HANDLER, FIND_FIRST_HANDLER, FIND_MANY_HANDLER, FIND_UNIQUE_HANDLER, GROUP_BY_HANDLER, IDENTITY_HANDLER, REFRESH_TOKEN_HANDLER, SIGN_IN_HANDLER, SIGN_OUT_HANDLER, UPDATE_HANDLER, UPDATE_MANY_HANDLER, UPSERT_HANDLER};
 use crate::core::field::r#type::{FieldType, FieldTypeOwner};
 use crate::gen::lib::shared::type_lookup::TypeLookup;
 
@@ -22,6 +22,9 @@ impl TypeLookup for SwiftTypes {
             FieldType::String => if optional { Cow::Borrowed("StringNullableFilter") } else { Cow::Borrowed("StringFilter") },
             FieldType::Date => if optional { Cow::Borrowed("DateNullableFilter") } else { Cow::Borrowed("DateFilter") },
             FieldType::DateTime => if optional { Cow::Borrowed("DateTimeNullableFilter") } else { Cow::Borrowed("DateTimeFilter") },
+            FieldType::Time => if optional { Cow::Borrowed("TimeNullableFilter") } else { Cow::Borrowed("TimeFilter") },
+            FieldType::Bytes => if optional { Cow::Borrowed("BytesNullableFilter") } else { Cow::Borrowed("BytesFilter") },
+            FieldType::Json => if optional { Cow::Borrowed("JsonNullableFilter") } else { Cow::Borrowed("JsonFilter") },
             FieldType::Enum(enum_def) => Cow::Owned("Enum".to_owned() + if optional { "Nullable" } else { "" } + "Filter<" + enum_def.name() + ">"),
             FieldType::Vec(inner) => Cow::Owned("Array".to_owned() + if optional { "Nullable" } else { "" } + "Filter<" + self.field_type_to_result_type(inner.field_type(), true).as_ref() + if inner.is_optional() { "?" } else { "" } + ">"),
             FieldType::HashMap(_) => unreachable!(),
@@ -41,6 +44,9 @@ impl TypeLookup for SwiftTypes {
             FieldType::String => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
             FieldType::Date => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
             FieldType::DateTime => if optional { Cow::Borrowed("NullOr<Date>") } else { Cow::Borrowed("Date") },
+            FieldType::Time => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
+            FieldType::Bytes => if optional { Cow::Borrowed("NullOr<Data>") } else { Cow::Borrowed("Data") },
+            FieldType::Json => if optional { Cow::Borrowed("NullOr<JSONValue>") } else { Cow::Borrowed("JSONValue") },
             FieldType::Enum(enum_def) => if optional { Cow::Owned("NullOr<".to_owned() + enum_def.name() + ">") } else { Cow::Borrowed(enum_def.name()) },
             FieldType::Vec(inner) => Cow::Owned((if optional { "NullOr<[" } else { "[" }).to_owned() + self.field_type_to_result_type(inner.field_type(), true).as_ref() + if inner.is_optional() { "?" } else { "" } + if optional { "]>" } else { ">" }),
             FieldType::HashMap(_) => unreachable!(),
@@ -61,6 +67,9 @@ impl TypeLookup for SwiftTypes {
             FieldType::String => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
             FieldType::Date => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
             FieldType::DateTime => if optional { Cow::Borrowed("NullOr<Date>") } else { Cow::Borrowed("Date") },
+            FieldType::Time => if optional { Cow::Borrowed("NullOr<String>") } else { Cow::Borrowed("String") },
+            FieldType::Bytes => if optional { Cow::Borrowed("NullOr<Data>") } else { Cow::Borrowed("Data") },
+            FieldType::Json => if optional { Cow::Borrowed("NullOr<JSONValue>") } else { Cow::Borrowed("JSONValue") },
             FieldType::Enum(enum_def) => if optional { Cow::Owned("NullOr<".to_owned() + enum_def.name() + ">") } else { Cow::Borrowed(enum_def.name()) },
             FieldType::Vec(inner) => Cow::Owned((if optional { "NullOr<[" } else { "[" }).to_owned() + self.field_type_to_result_type(inner.field_type(), true).as_ref() + if inner.is_optional() { "?" } else { "" } + if optional { "]>" } else { ">" }),
             FieldType::HashMap(_) => unreachable!(),
@@ -81,6 +90,9 @@ impl TypeLookup for SwiftTypes {
             FieldType::String => Cow::Borrowed("String"),
             FieldType::Date => Cow::Borrowed("String"),
             FieldType::DateTime => Cow::Borrowed("Date"),
+            FieldType::Time => Cow::Borrowed("String"),
+            FieldType::Bytes => Cow::Borrowed("Data"),
+            FieldType::Json => Cow::Borrowed("JSONValue"),
             FieldType::Enum(enum_def) => Cow::Borrowed(enum_def.name()),
             FieldType::Vec(inner) => Cow::Owned("[".to_owned() + self.field_type_to_result_type(inner.field_type(), true).as_ref() + if inner.is_optional() { "?" } else { "" } + "]"),
             FieldType::HashMap(_) => unreachable!(),
@@ -114,6 +126,8 @@ impl TypeLookup for SwiftTypes {
             GROUP_BY_HANDLER => format!("Response<{model_name}>"),
             SIGN_IN_HANDLER => format!("ResponseWithMeta<TokenInfo, {model_name}>"),
             IDENTITY_HANDLER => format!("Response<{model_name}>"),
+            REFRESH_TOKEN_HANDLER => format!("ResponseWithMeta<TokenInfo, {model_name}>"),
+            SIGN_OUT_HANDLER => format!("Response<{model_name}>"),
             _ => unreachable!()
         })
     }
@@ -139,6 +139,56 @@ export type DateNullableFilter = {{
     not?: DateNullableFilter | string | null
 }}
 
+export type TimeFilter = {{
+    equals?: string
+    in?: string[]
+    notIn?: string[]
+    lt?: string
+    lte?: string
+    gt?: string
+    gte?: string
+    not?: TimeFilter | string
+}}
+
+export type TimeNullableFilter = {{
+    equals?: string | null
+    in?: (string | null)[]
+    notIn?: (string | null)[]
+    lt?: string
+    lte?: string
+    gt?: string
+    gte?: string
+    not?: TimeNullableFilter | string | null
+}}
+
+export type BytesFilter = {{
+    equals?: Uint8Array
+    in?: Uint8Array[]
+    notIn?: Uint8Array[]
+    not?: BytesFilter | Uint8Array
+}}
+
+export type BytesNullableFilter = {{
+    equals?: Uint8Array | null
+    in?: (Uint8Array | null)[]
+    notIn?: (Uint8Array | null)[]
+    not?: BytesNullableFilter | Uint8Array | null
+}}
+
+export type JsonFilter = {{
+    equals?: any
+    not?: any
+    path?: (string | number)[]
+    contains?: any
+}}
+
+export type JsonNullableFilter = {{
+    equals?: any | null
+    not?: any | null
+    path?: (string | number)[]
+    contains?: any
+}}
+
 export type DateTimeFilter = {{
     equals?: {datetime_base}
     in?: {datetime_base}[]
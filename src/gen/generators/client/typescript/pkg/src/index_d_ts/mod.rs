@@ -12,6 +12,7 @@ use crate::gen::generators::client::typescript::pkg::src::filter_d_ts::generate_
 use crate::gen::generators::client::typescript::pkg::src::operation_d_ts::generate_operation_types;
 use crate::gen::generators::client::typescript::pkg::src::runtime_d_ts::generate_client_runtime_types;
 use crate::gen::lib::code::Code;
+use crate::gen::lib::shared::model_input::{compound_unique_input_name, compound_unique_key};
 use crate::gen::generators::server::nodejs::runtime_d_ts::generate_server_runtime_types;
 
 fn generate_model_create_nested_input(_graph: &Graph, model: &Model, without: Option<&str>, many: bool) -> String {
@@ -434,22 +435,45 @@ export declare class TeoError extends Error {
                     }
                 })
             }, "}");
+            // compound unique
+            let mut used_compound_names: Vec<String> = Vec::new();
+            m.indices().iter().for_each(|index| {
+                if (index.r#type() == Primary || index.r#type() == Unique) && index.keys().len() > 1 {
+                    let compound_name = compound_unique_input_name(model_name, index.keys());
+                    if used_compound_names.contains(&compound_name) { return }
+                    c.block(format!("export type {compound_name} = {{"), |b| {
+                        index.items().iter().for_each(|item| {
+                            let field = m.field(item.field_name()).unwrap();
+                            let ts_type = field.field_type().to_typescript_type(field.optionality.is_optional());
+                            let field_name = item.field_name();
+                            b.doc(field_doc(field));
+                            b.line(format!("{field_name}: {ts_type}"));
+                        });
+                    }, "}");
+                    used_compound_names.push(compound_name);
+                }
+            });
             // where unique
             c.block(format!("export type {model_name}WhereUniqueInput = {{"), |b| {
-                let mut used_field_names: Vec<&str> = Vec::new();
+                let mut used_field_names: Vec<String> = Vec::new();
                 m.indices().iter().for_each(|index| {
                     if index.r#type() == Primary || index.r#type() == Unique {
-                        index.items().iter().for_each(|item| {
-                            if !used_field_names.contains(&&***&&item.field_name()) {
-                                if let Some(field) = m.field(&item.field_name()) {
-                                    let ts_type = field.field_type().to_typescript_type(field.optionality.is_optional());
-                                    let field_name = &item.field_name();
-                                    b.doc(field_doc(field));
-                                    b.line(format!("{field_name}?: {ts_type}"));
-                                }
-                                used_field_names.push(item.field_name());
+                        if index.keys().len() == 1 {
+                            let field_name = index.keys().first().unwrap();
+                            if used_field_names.contains(field_name) { return }
+                            if let Some(field) = m.field(field_name) {
+                                let ts_type = field.field_type().to_typescript_type(field.optionality.is_optional());
+                                b.doc(field_doc(field));
+                                b.line(format!("{field_name}?: {ts_type}"));
                             }
-                        });
+                            used_field_names.push(field_name.clone());
+                        } else {
+                            let key = compound_unique_key(index.keys());
+                            if used_field_names.contains(&key) { return }
+                            let compound_name = compound_unique_input_name(model_name, index.keys());
+                            b.line(format!("{key}?: {compound_name}"));
+                            used_field_names.push(key);
+                        }
                     }
                 });
             }, "}");
@@ -82,12 +82,35 @@ function getBearerToken() {{
     }}
 }}
 
+function bytesToBase64(bytes) {{
+    let binary = ''
+    for (let i = 0; i < bytes.length; i++) {{
+        binary += String.fromCharCode(bytes[i])
+    }}
+    return btoa(binary)
+}}
+
+function base64ToBytes(base64) {{
+    let binary = atob(base64)
+    let bytes = new Uint8Array(binary.length)
+    for (let i = 0; i < binary.length; i++) {{
+        bytes[i] = binary.charCodeAt(i)
+    }}
+    return bytes
+}}
+
 async function request(urlSegmentName, action, args, token = getBearerToken()) {{
   let url = "{host}/" + urlSegmentName + "/action/" + action
   let response = await fetch(url, {{
       method: "POST",
       headers: token ? {{ "Authorization": `Bearer ${{token}}` }} : undefined,
-      body: JSON.stringify(args)
+      body: JSON.stringify(args, (key, value) => {{
+        if (value instanceof Uint8Array) {{
+          return bytesToBase64(value)
+        }} else {{
+          return value
+        }}
+      }})
   }})
   let response_text = await response.text()
   let response_json = JSON.parse(response_text, (key, value) => {{
@@ -96,6 +119,8 @@ async function request(urlSegmentName, action, args, token = getBearerToken()) {
         return new Date(value['$date'])
       }} else if (value['$decimal']) {{
         return new Decimal(value['$decimal'])
+      }} else if (typeof value['$bytes'] === 'string') {{
+        return base64ToBytes(value['$bytes'])
       }}  else {{
         return value
       }}
@@ -76,6 +76,22 @@ export type NullableDateFieldUpdateOperationsInput = {{
     set?: string | null
 }}
 
+export type TimeFieldUpdateOperationsInput = {{
+    set?: string
+}}
+
+export type NullableTimeFieldUpdateOperationsInput = {{
+    set?: string | null
+}}
+
+export type BytesFieldUpdateOperationsInput = {{
+    set?: Uint8Array
+}}
+
+export type NullableBytesFieldUpdateOperationsInput = {{
+    set?: Uint8Array | null
+}}
+
 export type DateTimeFieldUpdateOperationsInput = {{
     set?: {datetime_base}
 }}
@@ -14,8 +14,10 @@ impl ToTypeScriptType for FieldType {
         let base: String = match self {
             #[cfg(feature = "data-source-mongodb")]
             FieldType::ObjectId => "string".to_string(),
-            FieldType::String | FieldType::Date => "string".to_string(),
+            FieldType::String | FieldType::Date | FieldType::Time => "string".to_string(),
             FieldType::DateTime => "Date".to_string(),
+            FieldType::Bytes => "Uint8Array".to_string(),
+            FieldType::Json => "any".to_string(),
             FieldType::Bool => "boolean".to_string(),
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 => "number".to_string(),
             FieldType::Decimal => "Decimal".to_string(),
@@ -39,6 +41,12 @@ impl ToTypeScriptType for FieldType {
             FieldType::ObjectId => "string | ObjectId".to_string(),
             FieldType::String => "string | String".to_string(),
             FieldType::Date => "string | Date".to_string(),
+            FieldType::Time => "string | Time".to_string(),
+            FieldType::Bytes => "Uint8Array | Bytes".to_string(),
+            FieldType::Json => {
+                with_generic = true;
+                if optional { "JsonNullableFilter | null" } else { "JsonFilter" }.to_string()
+            },
             FieldType::DateTime => "string | Date | DateTime".to_string(),
             FieldType::Bool => "boolean | Bool".to_string(),
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 => "number | Number".to_string(),
@@ -82,7 +90,9 @@ impl ToTypeScriptType for FieldType {
             FieldType::ObjectId => "string".to_string(),
             FieldType::String => "string".to_string(),
             FieldType::Decimal => if server_mode { "Decimal" } else { "string | Decimal" }.to_string(),
-            FieldType::Date | FieldType::DateTime => "string".to_string(),
+            FieldType::Date | FieldType::DateTime | FieldType::Time => "string".to_string(),
+            FieldType::Bytes => "Uint8Array".to_string(),
+            FieldType::Json => "any".to_string(),
             FieldType::Bool => "boolean".to_string(),
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 => "number".to_string(),
             FieldType::Enum(enum_def) => enum_def.name().to_string(),
@@ -99,6 +109,9 @@ impl ToTypeScriptType for FieldType {
     }
 
     fn to_typescript_update_input_type(&self, optional: bool, server_mode: bool) -> String {
+        if self.is_json() {
+            return self.to_typescript_create_input_type(optional, server_mode);
+        }
         let update_operation = self.to_typescript_update_operation_input(optional, server_mode);
         let create_input = self.to_typescript_create_input_type(optional, server_mode);
         return format!("{update_operation} | {create_input}");
@@ -117,6 +130,8 @@ impl ToTypeScriptType for FieldType {
             FieldType::String => "String",
             FieldType::Date => "Date",
             FieldType::DateTime => "DateTime",
+            FieldType::Time => "Time",
+            FieldType::Bytes => "Bytes",
             FieldType::Decimal => "Decimal",
             FieldType::Bool => "Bool",
             FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 => "Number",
@@ -3,9 +3,11 @@ use crate::core::relation::Relation;
 
 pub(crate) fn field_to_nodejs_api_type<T>(field: &T) -> String where T: FieldTypeOwner {
     let base = match field.field_type() {
-        FieldType::String => "string".to_string(),
+        FieldType::String | FieldType::Time => "string".to_string(),
         FieldType::Date => "Date".to_string(),
         FieldType::DateTime => "Date".to_string(),
+        FieldType::Bytes => "Uint8Array".to_string(),
+        FieldType::Json => "any".to_string(),
         FieldType::Bool => "boolean".to_string(),
         FieldType::I32 | FieldType::I64 | FieldType::F32 | FieldType::F64 => "number".to_string(),
         FieldType::Decimal => "Decimal".to_string(),
@@ -62,6 +62,9 @@ impl RustEntityGenerator {
             FieldType::String => "String".to_owned(),
             FieldType::Date => "NaiveDate".to_owned(),
             FieldType::DateTime => "DateTime<Utc>".to_owned(),
+            FieldType::Time => "NaiveTime".to_owned(),
+            FieldType::Bytes => "Vec<u8>".to_owned(),
+            FieldType::Json => "Value".to_owned(),
             FieldType::Enum(enum_def) => enum_def.name().to_owned(),
             FieldType::Vec(inner) => format!("Vec<{}>", self.getter_type_for_field(inner.as_ref())),
             FieldType::HashMap(inner) => format!("HashMap<String, {}>", self.getter_type_for_field(inner.as_ref())),
@@ -107,6 +110,10 @@ impl RustEntityGenerator {
                 chrono_requirements.push("Utc");
                 package_requirements.insert("chrono");
             }
+            if model.fields().iter().find(|f| f.field_type().is_time()).is_some() {
+                chrono_requirements.push("NaiveTime");
+                package_requirements.insert("chrono");
+            }
             match chrono_requirements.len() {
                 0 => (),
                 1 => b.line(format!("use chrono::prelude::{};", chrono_requirements.get(0).unwrap())),
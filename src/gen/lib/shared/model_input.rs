@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use inflector::Inflector;
 use itertools::Itertools;
-use crate::core::action::{Action, IDENTITY_HANDLER, SIGN_IN_HANDLER};
+use crate::core::action::{Action, IDENTITY_HANDLER, REFRESH_TOKEN_HANDLER, SIGN_IN_HANDLER, SIGN_OUT_HANDLER};
 use crate::core::field::r#type::FieldTypeOwner;
 use crate::gen::lib::shared::type_lookup::TypeLookup;
 use crate::prelude::Graph;
@@ -23,6 +23,11 @@ pub(crate) struct ModelWhereUniqueField<'a> {
     pub(crate) create_type: Cow<'a, str>,
 }
 
+pub(crate) struct ModelCompoundUnique<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) fields: Vec<ModelWhereUniqueField<'a>>,
+}
+
 pub(crate) struct ModelCreateField<'a> {
     pub(crate) name: Cow<'a, str>,
     pub(crate) create_type: Cow<'a, str>,
@@ -58,6 +63,7 @@ pub(crate) struct ModelInput<'a> {
     pub(crate) includes: Vec<ModelInclude<'a>>,
     pub(crate) where_fields: Vec<ModelWhereField<'a>>,
     pub(crate) where_unique_fields: Vec<ModelWhereUniqueField<'a>>,
+    pub(crate) compound_uniques: Vec<ModelCompoundUnique<'a>>,
     pub(crate) order_by_fields: Vec<Cow<'a, str>>,
     pub(crate) create_fields: Vec<ModelCreateField<'a>>,
     pub(crate) update_fields: Vec<ModelUpdateField<'a>>,
@@ -75,6 +81,15 @@ impl<'a> ModelInput<'a> {
     }
 }
 
+/// The key which a compound unique index is queried with in a unique where input.
+pub(crate) fn compound_unique_key(keys: &[String]) -> String {
+    keys.join("_")
+}
+
+pub(crate) fn compound_unique_input_name(model: &str, keys: &[String]) -> String {
+    format!("{model}{}CompoundUniqueInput", keys.iter().map(|k| k.to_pascal_case()).join(""))
+}
+
 fn args_where_field(model: &str, doc_singular: bool, optional: bool) -> ActionArgField {
     ActionArgField {
         name: "where",
@@ -295,10 +310,25 @@ pub(crate) fn model_inputs<'a, T>(graph: &'a Graph, lookup: T) -> Vec<ModelInput
                     filter_type: if relation.is_vec() { Cow::Owned(relation.model().to_owned() + "ListRelationFilter") } else { Cow::Owned(relation.model().to_owned() + "RelationFilter") }
                 }
             } else { unreachable!() }).collect(),
-            where_unique_fields: m.indices().iter().filter(|i| i.r#type().is_unique()).map(|i| i.keys().iter().map(|k| m.field(k).unwrap()).map(|f| ModelWhereUniqueField {
-                name: Cow::Borrowed(f.name()),
-                create_type: lookup.field_type_to_create_type(f.field_type(), f.is_optional()),
-            })).flatten().dedup_by(|f1, f2| f1.name == f2.name).collect(),
+            where_unique_fields: m.indices().iter().filter(|i| i.r#type().is_unique()).map(|i| if i.keys().len() == 1 {
+                let f = m.field(i.keys().first().unwrap()).unwrap();
+                ModelWhereUniqueField {
+                    name: Cow::Borrowed(f.name()),
+                    create_type: lookup.field_type_to_create_type(f.field_type(), f.is_optional()),
+                }
+            } else {
+                ModelWhereUniqueField {
+                    name: Cow::Owned(compound_unique_key(i.keys())),
+                    create_type: Cow::Owned(compound_unique_input_name(m.name(), i.keys())),
+                }
+            }).unique_by(|f| f.name.clone()).collect(),
+            compound_uniques: m.indices().iter().filter(|i| i.r#type().is_unique() && i.keys().len() > 1).map(|i| ModelCompoundUnique {
+                name: Cow::Owned(compound_unique_input_name(m.name(), i.keys())),
+                fields: i.keys().iter().map(|k| m.field(k).unwrap()).map(|f| ModelWhereUniqueField {
+                    name: Cow::Borrowed(f.name()),
+                    create_type: lookup.field_type_to_create_type(f.field_type(), f.is_optional()),
+                }).collect(),
+            }).unique_by(|c| c.name.clone()).collect(),
             order_by_fields: m.sort_keys().iter().map(|k| Cow::Borrowed(k.as_str())).collect(),
             create_fields: m.input_keys().iter().map(|k| if let Some(field) = m.field(k) {
                 ModelCreateField {
@@ -525,6 +555,26 @@ pub(crate) fn model_inputs<'a, T>(graph: &'a Graph, lookup: T) -> Vec<ModelInput
                         ]
                     })
                 }
+                if m.has_action(Action::from_u32(REFRESH_TOKEN_HANDLER)) {
+                    args.push(ActionArg {
+                        name: "RefreshTokenArgs",
+                        docs: None,
+                        fields: vec![
+                            args_select_field(m.name(), false),
+                            args_include_field(m.name(), false),
+                        ]
+                    })
+                }
+                if m.has_action(Action::from_u32(SIGN_OUT_HANDLER)) {
+                    args.push(ActionArg {
+                        name: "SignOutArgs",
+                        docs: None,
+                        fields: vec![
+                            args_select_field(m.name(), false),
+                            args_include_field(m.name(), false),
+                        ]
+                    })
+                }
                 args
             }
         }
@@ -6,6 +6,11 @@ pub(crate) mod gen;
 pub mod prelude {
     pub use crate::core::app::App;
     pub use crate::core::app::builder::AppBuilder;
+    pub use crate::core::app::revocation::TokenRevocationStore;
+    pub use crate::core::app::route::{Method, RouteHandler};
+    pub use crate::core::action::source::ActionSource;
+    pub use crate::core::action::request::RequestCtx;
+    pub use crate::core::app::rate_limit::{RateLimitHit, RateLimitStore};
     pub use crate::core::graph::Graph;
     pub use crate::core::graph::builder::GraphBuilder;
     pub use crate::core::teon::Value;
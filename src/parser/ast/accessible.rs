@@ -18,6 +18,7 @@ use crate::parser::std::callables::int_constructor::int_constructor;
 #[cfg(feature = "data-source-mongodb")]
 use crate::parser::std::callables::object_id_constructor::object_id_constructor;
 use crate::parser::std::callables::string_constructor::string_constructor;
+use crate::parser::std::callables::time_constructor::time_constructor;
 use crate::parser::std::constants::EnvObject;
 use crate::prelude::Value;
 
@@ -78,6 +79,7 @@ impl Container {
                 "Float".to_owned() => Entity::Accessible(Accessible::Callable(float_constructor)),
                 "Date".to_owned() => Entity::Accessible(Accessible::Callable(date_constructor)),
                 "DateTime".to_owned() => Entity::Accessible(Accessible::Callable(datetime_constructor)),
+                "Time".to_owned() => Entity::Accessible(Accessible::Callable(time_constructor)),
                 "String".to_owned() => Entity::Accessible(Accessible::Callable(string_constructor)),
             }
         }
@@ -1,3 +1,5 @@
+use crate::core::app::conf::{CorsConf, JwtConf, LogConf};
+use crate::core::app::rate_limit::RateLimit;
 use crate::parser::ast::span::Span;
 use crate::parser::ast::item::Item;
 
@@ -9,7 +11,16 @@ pub struct ServerConfig {
     pub(crate) span: Span,
     pub(crate) bind: Option<(String, u16)>,
     pub(crate) jwt_secret: Option<String>,
+    pub(crate) jwt: Option<JwtConf>,
     pub(crate) path_prefix: Option<String>,
+    pub(crate) cors: Option<CorsConf>,
+    pub(crate) body_limit: Option<usize>,
+    pub(crate) health: Option<bool>,
+    pub(crate) ready: Option<bool>,
+    pub(crate) meta: Option<bool>,
+    pub(crate) logging: Option<LogConf>,
+    pub(crate) rate_limit: Option<RateLimit>,
+    pub(crate) shutdown_timeout: Option<u64>,
 }
 
 impl ServerConfig {
@@ -21,7 +32,16 @@ impl ServerConfig {
             span,
             bind: None,
             jwt_secret: None,
+            jwt: None,
             path_prefix: None,
+            cors: None,
+            body_limit: None,
+            health: None,
+            ready: None,
+            meta: None,
+            logging: None,
+            rate_limit: None,
+            shutdown_timeout: None,
         }
     }
 }
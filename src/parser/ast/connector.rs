@@ -11,12 +11,13 @@ pub struct Connector {
     pub(crate) provider: Option<DatabaseName>,
     pub(crate) url: Option<String>,
     pub(crate) debug: bool,
+    pub(crate) foreign_keys: bool,
 }
 
 impl Connector {
     pub(crate) fn new(items: Vec<Item>, span: Span, source_id: usize, item_id: usize) -> Self {
         Self {
-            id: item_id, items, span, source_id, provider: None, url: None, debug: false
+            id: item_id, items, span, source_id, provider: None, url: None, debug: false, foreign_keys: true
         }
     }
 }
@@ -0,0 +1,50 @@
+use crate::parser::ast::expression::DictionaryLiteral;
+use crate::parser::ast::identifier::Identifier;
+use crate::prelude::Value;
+
+#[derive(Debug)]
+pub(crate) struct DataSet {
+    pub(crate) id: usize,
+    pub(crate) identifier: Identifier,
+    pub(crate) auto_seed: bool,
+    pub(crate) groups: Vec<DataSetGroup>,
+    pub(crate) resolved: bool,
+}
+
+impl DataSet {
+    pub(crate) fn new(item_id: usize, identifier: Identifier, auto_seed: bool, groups: Vec<DataSetGroup>) -> Self {
+        Self {
+            id: item_id,
+            identifier,
+            auto_seed,
+            groups,
+            resolved: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DataSetGroup {
+    pub(crate) identifier: Identifier,
+    pub(crate) records: Vec<DataSetRecord>,
+    pub(crate) resolved: bool,
+}
+
+impl DataSetGroup {
+    pub(crate) fn new(identifier: Identifier, records: Vec<DataSetRecord>) -> Self {
+        Self { identifier, records, resolved: false }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DataSetRecord {
+    pub(crate) identifier: Identifier,
+    pub(crate) dictionary: DictionaryLiteral,
+    pub(crate) resolved: Option<Value>,
+}
+
+impl DataSetRecord {
+    pub(crate) fn new(identifier: Identifier, dictionary: DictionaryLiteral) -> Self {
+        Self { identifier, dictionary, resolved: None }
+    }
+}
@@ -28,3 +28,4 @@ pub(crate) mod constant;
 pub(crate) mod entity;
 pub(crate) mod arith_expr;
 pub(crate) mod comment_block;
+pub(crate) mod data_set;
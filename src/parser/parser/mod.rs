@@ -20,6 +20,7 @@ use crate::parser::ast::comment_block::CommentBlock;
 use crate::parser::ast::config::ServerConfig;
 use crate::parser::ast::connector::Connector;
 use crate::parser::ast::constant::Constant;
+use crate::parser::ast::data_set::{DataSet, DataSetGroup, DataSetRecord};
 use crate::parser::ast::decorator::Decorator;
 use crate::parser::ast::expression::{Expression, ExpressionKind, ArrayLiteral, BoolLiteral, DictionaryLiteral, EnumChoiceLiteral, NullLiteral, NumericLiteral, RangeLiteral, StringLiteral, TupleLiteral, RegExpLiteral, NullishCoalescing, Negation, BitwiseNegation };
 use crate::parser::ast::field::Field;
@@ -74,6 +75,7 @@ pub(crate) struct Parser {
     pub(crate) config: Option<(usize, usize)>,
     pub(crate) generators: Vec<(usize, usize)>,
     pub(crate) clients: Vec<(usize, usize)>,
+    pub(crate) data_sets: Vec<(usize, usize)>,
     pub(crate) next_id: usize,
     pub(crate) resolved: bool,
     pub(crate) global_model_decorators: Option<GlobalModelDecorators>,
@@ -96,6 +98,7 @@ impl Parser {
             config: None,
             generators: vec![],
             clients: vec![],
+            data_sets: vec![],
             next_id: 0,
             resolved: false,
             global_model_decorators: None,
@@ -189,6 +192,11 @@ impl Parser {
                     let config_block = self.parse_config_block(current, source_id, item_id);
                     tops.insert(item_id, config_block);
                 },
+                Rule::dataset_declaration => {
+                    let data_set = Self::parse_data_set(current, item_id);
+                    tops.insert(data_set.id(), data_set);
+                    self.data_sets.push((source_id, item_id));
+                },
                 Rule::EOI | Rule::EMPTY_LINES => {},
                 Rule::CATCH_ALL => panic!("Catch all: {}", current.as_str()),
                 Rule::comment_block => (),
@@ -464,6 +472,49 @@ impl Parser {
         }
     }
 
+    fn parse_data_set(pair: Pair<'_>, item_id: usize) -> Top {
+        let mut identifier: Option<Identifier> = None;
+        let mut auto_seed = false;
+        let mut groups: Vec<DataSetGroup> = vec![];
+        for current in pair.into_inner() {
+            match current.as_rule() {
+                Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE | Rule::EMPTY_LINES | Rule::comment_block => (),
+                Rule::AUTOSEED_KEYWORD => auto_seed = true,
+                Rule::identifier => identifier = Some(Self::parse_identifier(&current)),
+                Rule::dataset_group_declaration => groups.push(Self::parse_data_set_group(current)),
+                _ => panic!("error. {}", current),
+            }
+        }
+        Top::DataSet(DataSet::new(item_id, identifier.unwrap(), auto_seed, groups))
+    }
+
+    fn parse_data_set_group(pair: Pair<'_>) -> DataSetGroup {
+        let mut identifier: Option<Identifier> = None;
+        let mut records: Vec<DataSetRecord> = vec![];
+        for current in pair.into_inner() {
+            match current.as_rule() {
+                Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE | Rule::EMPTY_LINES | Rule::comment_block => (),
+                Rule::identifier => identifier = Some(Self::parse_identifier(&current)),
+                Rule::dataset_group_record_declaration => records.push(Self::parse_data_set_record(current)),
+                _ => panic!("error. {}", current),
+            }
+        }
+        DataSetGroup::new(identifier.unwrap(), records)
+    }
+
+    fn parse_data_set_record(pair: Pair<'_>) -> DataSetRecord {
+        let mut identifier: Option<Identifier> = None;
+        let mut dictionary: Option<DictionaryLiteral> = None;
+        for current in pair.into_inner() {
+            match current.as_rule() {
+                Rule::identifier => identifier = Some(Self::parse_identifier(&current)),
+                Rule::dictionary_literal => dictionary = Some(Self::parse_dictionary_literal(current)),
+                _ => panic!("error. {}", current),
+            }
+        }
+        DataSetRecord::new(identifier.unwrap(), dictionary.unwrap())
+    }
+
     fn parse_config_item(pair: Pair<'_>) -> Item {
         let span = Self::parse_span(&pair);
         let mut identifier: Option<Identifier> = None;
@@ -472,12 +523,32 @@ impl Parser {
             match current.as_rule() {
                 Rule::identifier => identifier = Some(Self::parse_identifier(&current)),
                 Rule::expression => expression = Some(Self::parse_expression(current)),
+                Rule::config_item_block => expression = Some(Expression::new(ExpressionKind::DictionaryLiteral(Self::parse_config_item_block(current)))),
                 _ => panic!("error."),
             }
         }
         Item { identifier: identifier.unwrap(), expression: expression.unwrap(), span }
     }
 
+    // A nested block like `cors { credentials true }` is read as a dictionary keyed by the item
+    // names.
+    fn parse_config_item_block(pair: Pair<'_>) -> DictionaryLiteral {
+        let span = Self::parse_span(&pair);
+        let mut expressions: Vec<(ExpressionKind, ExpressionKind)> = vec![];
+        for current in pair.into_inner() {
+            match current.as_rule() {
+                Rule::config_item => {
+                    let item = Self::parse_config_item(current);
+                    let key = StringLiteral { value: format!("\"{}\"", item.identifier.name), span: item.identifier.span };
+                    expressions.push((ExpressionKind::StringLiteral(key), item.expression.kind));
+                }
+                Rule::comment_block => (),
+                _ => unreachable!(),
+            }
+        }
+        DictionaryLiteral { expressions, span }
+    }
+
     fn parse_decorator(pair: Pair<'_>) -> Decorator {
         let span = Self::parse_span(&pair);
         let mut unit: Option<ExpressionKind> = None;
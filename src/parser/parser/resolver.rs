@@ -1,7 +1,10 @@
 use std::collections::HashMap;
+use indexmap::IndexMap;
 use std::i64;
 use std::path::PathBuf;
 use std::str::FromStr;
+use jsonwebtoken::Algorithm;
+use log::LevelFilter;
 use path_absolutize::Absolutize;
 use regex::Regex;
 use snailquote::unescape;
@@ -11,6 +14,7 @@ use crate::parser::ast::accessible::{Accessible, ASTPipeline, ASTPipelineItem, C
 use crate::parser::ast::argument::ArgumentList;
 use crate::parser::ast::config::ServerConfig;
 use crate::parser::ast::constant::Constant;
+use crate::parser::ast::data_set::DataSet;
 use crate::parser::ast::decorator::Decorator;
 use crate::parser::ast::entity::Entity;
 use crate::parser::ast::expression::{ArrayLiteral, BitwiseNegation, BoolLiteral, DictionaryLiteral, EnumChoiceLiteral, Expression, ExpressionKind, Negation, NullishCoalescing, NullLiteral, NumericLiteral, RangeLiteral, RegExpLiteral, StringLiteral, TupleLiteral};
@@ -34,6 +38,8 @@ use crate::parser::std::decorators::relation::GlobalRelationDecorators;
 use crate::prelude::Value;
 use to_mut::ToMut;
 use crate::core::action::Action;
+use crate::core::app::conf::{CorsConf, JwtConf, LogConf, LogFormat};
+use crate::core::app::rate_limit::{RateLimit, RateLimitKey};
 use crate::core::app::environment::Environment;
 use crate::parser::ast::arith_expr::{ArithExpr, Op};
 use crate::parser::ast::client::{Client, ClientLanguage};
@@ -58,6 +64,11 @@ impl Resolver {
             if *index == 1 { continue }
             Self::resolve_source(parser, source);
         }
+        for (source_id, data_set_id) in parser.data_sets.iter() {
+            let source = parser.get_source(*source_id);
+            let data_set = source.to_mut().tops.get_mut(data_set_id).unwrap().as_data_set_mut().unwrap();
+            Self::resolve_data_set(parser, source, data_set);
+        }
         parser.to_mut().resolved = true;
     }
 
@@ -89,6 +100,9 @@ impl Resolver {
                 Top::ServerConfig(config) => {
                     Self::resolve_config(parser, source, config);
                 }
+                Top::DataSet(_data_set) => {
+                    continue;
+                }
             }
         }
         source.to_mut().resolved = true;
@@ -360,6 +374,8 @@ impl Resolver {
                             for (index, arg) in &mut args.arguments.iter_mut().enumerate() {
                                 let value = if ((&previous_identifier.unwrap().name == "when") || (&previous_identifier.unwrap().name == "redirect")) && index == 0 {
                                     Self::resolve_expression_kind_force_value(parser, source, &arg.value, true)
+                                } else if &previous_identifier.unwrap().name == "queryRaw" {
+                                    Self::resolve_raw_query_argument(parser, source, &arg.value)
                                 } else {
                                     Self::resolve_expression_kind_force_value(parser, source, &arg.value, false)
                                 };
@@ -440,6 +456,8 @@ impl Resolver {
                         "mongo" => connector.provider = Some(DatabaseName::MongoDB),
                         "mysql" => connector.provider = Some(DatabaseName::MySQL),
                         "postgres" => connector.provider = Some(DatabaseName::PostgreSQL),
+                        #[cfg(feature = "data-source-mssql")]
+                        "mssql" => connector.provider = Some(DatabaseName::MSSQL),
                         _ => panic!("Unrecognized provider. {}", provider_str)
                     }
                 },
@@ -455,6 +473,12 @@ impl Resolver {
                     let bool = bool_value.as_bool().unwrap();
                     connector.debug = bool;
                 }
+                "foreignKeys" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let bool_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    let bool = bool_value.as_bool().unwrap();
+                    connector.foreign_keys = bool;
+                }
                 _ => { panic!("Undefined name '{}' in connector block.", item.identifier.name.as_str())}
             }
         }
@@ -587,11 +611,251 @@ impl Resolver {
                         _ => panic!("Value of 'pathPrefix' should be string.")
                     }
                 }
+                "bodyLimit" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let body_limit_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match body_limit_value.as_i64() {
+                        Some(i) if i > 0 => config.body_limit = Some(i as usize),
+                        _ => panic!("Value of 'bodyLimit' should be a positive int.")
+                    }
+                }
+                "health" | "ready" | "meta" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let enabled_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    let enabled = match enabled_value.as_bool() {
+                        Some(b) => b,
+                        None => panic!("Value of '{}' should be bool.", item.identifier.name.as_str()),
+                    };
+                    match item.identifier.name.as_str() {
+                        "health" => config.health = Some(enabled),
+                        "ready" => config.ready = Some(enabled),
+                        _ => config.meta = Some(enabled),
+                    }
+                }
+                "shutdownTimeout" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let shutdown_timeout_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match shutdown_timeout_value.as_i64() {
+                        Some(i) if i >= 0 => config.shutdown_timeout = Some(i as u64),
+                        _ => panic!("Value of 'shutdownTimeout' should be a non negative int.")
+                    }
+                }
+                "jwt" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let jwt_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match jwt_value.as_hashmap() {
+                        Some(map) => config.jwt = Some(Self::resolve_jwt(source, map)),
+                        None => panic!("Value of 'jwt' should be a block.")
+                    }
+                }
+                "logging" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let logging_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match logging_value.as_hashmap() {
+                        Some(map) => config.logging = Some(Self::resolve_logging(map)),
+                        None => panic!("Value of 'logging' should be a block.")
+                    }
+                }
+                "rateLimit" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let rate_limit_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match rate_limit_value.as_hashmap() {
+                        Some(map) => config.rate_limit = Some(Self::resolve_rate_limit(map)),
+                        None => panic!("Value of 'rateLimit' should be a block.")
+                    }
+                }
+                "cors" => {
+                    Self::resolve_expression(parser, source, &mut item.expression);
+                    let cors_value = Self::unwrap_into_value_if_needed(parser, source, item.expression.resolved.as_ref().unwrap());
+                    match cors_value.as_hashmap() {
+                        Some(map) => config.cors = Some(Self::resolve_cors(map)),
+                        None => panic!("Value of 'cors' should be a block.")
+                    }
+                }
                 _ => { panic!("Undefined name '{}' in config block.", item.identifier.name.as_str())}
             }
         }
     }
 
+    fn resolve_jwt(source: &Source, map: &HashMap<String, Value>) -> JwtConf {
+        let mut jwt = JwtConf::default();
+        let string = |key: &str, value: &Value| -> String {
+            match value.as_str() {
+                Some(s) => s.to_owned(),
+                None => panic!("Value of '{}' in jwt block should be string.", key),
+            }
+        };
+        let read_key = |key: &str, value: &Value| -> Vec<u8> {
+            let mut path = source.path.clone();
+            path.pop();
+            let path = path.join(PathBuf::from(string(key, value)));
+            match std::fs::read(&path) {
+                Ok(content) => content,
+                Err(err) => panic!("Cannot read '{}' of jwt block at '{}': {}", key, path.display(), err),
+            }
+        };
+        for (key, value) in map {
+            match key.as_str() {
+                "algorithm" => jwt.algorithm = match value.as_raw_enum_choice() {
+                    Some("hs256") => Algorithm::HS256,
+                    Some("hs384") => Algorithm::HS384,
+                    Some("hs512") => Algorithm::HS512,
+                    Some("rs256") => Algorithm::RS256,
+                    Some("rs384") => Algorithm::RS384,
+                    Some("rs512") => Algorithm::RS512,
+                    Some("es256") => Algorithm::ES256,
+                    Some("es384") => Algorithm::ES384,
+                    _ => panic!("Unrecognized jwt algorithm."),
+                },
+                "expiresIn" => match value.as_i64() {
+                    Some(i) if i > 0 => jwt.expires_in = i,
+                    _ => panic!("Value of 'expiresIn' in jwt block should be a positive int."),
+                },
+                "issuer" => jwt.issuer = Some(string(key, value)),
+                "audience" => jwt.audience = Some(string(key, value)),
+                "privateKey" => jwt.private_key = Some(read_key(key, value)),
+                "publicKey" => jwt.public_key = Some(read_key(key, value)),
+                _ => panic!("Undefined name '{}' in jwt block.", key),
+            }
+        }
+        if jwt.uses_key_pair() && (jwt.private_key.is_none() || jwt.public_key.is_none()) {
+            panic!("Jwt algorithm {:?} requires both 'privateKey' and 'publicKey'.", jwt.algorithm);
+        }
+        jwt
+    }
+
+    fn resolve_logging(map: &HashMap<String, Value>) -> LogConf {
+        let mut logging = LogConf::default();
+        for (key, value) in map {
+            match key.as_str() {
+                "format" => logging.format = match value.as_raw_enum_choice() {
+                    Some("pretty") => LogFormat::Pretty,
+                    Some("json") => LogFormat::Json,
+                    _ => panic!("Unrecognized log format."),
+                },
+                "level" => logging.level = match value.as_raw_enum_choice() {
+                    Some("off") => LevelFilter::Off,
+                    Some("error") => LevelFilter::Error,
+                    Some("warn") => LevelFilter::Warn,
+                    Some("info") => LevelFilter::Info,
+                    Some("debug") => LevelFilter::Debug,
+                    Some("trace") => LevelFilter::Trace,
+                    _ => panic!("Unrecognized log level."),
+                },
+                _ => panic!("Undefined name '{}' in logging block.", key),
+            }
+        }
+        logging
+    }
+
+    fn resolve_rate_limit(map: &HashMap<String, Value>) -> RateLimit {
+        let mut window: Option<u64> = None;
+        let mut max: Option<u32> = None;
+        let mut key = RateLimitKey::Ip;
+        for (k, value) in map {
+            match k.as_str() {
+                "window" => match value.as_i64() {
+                    Some(i) if i > 0 => window = Some(i as u64),
+                    _ => panic!("Value of 'window' in rateLimit block should be a positive int."),
+                },
+                "max" => match value.as_i64().and_then(|i| u32::try_from(i).ok()) {
+                    Some(i) if i > 0 => max = Some(i),
+                    _ => panic!("Value of 'max' in rateLimit block should be a positive int not greater than {}.", u32::MAX),
+                },
+                "key" => key = match value.as_raw_enum_choice().and_then(RateLimitKey::from_name) {
+                    Some(key) => key,
+                    None => panic!("Value of 'key' in rateLimit block should be .ip or .identity."),
+                },
+                _ => panic!("Undefined name '{}' in rateLimit block.", k),
+            }
+        }
+        match (window, max) {
+            (Some(window), Some(max)) => RateLimit { window, max, key, handlers: None },
+            _ => panic!("rateLimit block requires 'window' and 'max'."),
+        }
+    }
+
+    fn resolve_cors(map: &HashMap<String, Value>) -> CorsConf {
+        let mut cors = CorsConf::default();
+        let string_list = |key: &str, value: &Value| -> Vec<String> {
+            match value {
+                Value::String(s) => vec![s.clone()],
+                Value::Vec(values) => values.iter().map(|v| match v.as_str() {
+                    Some(s) => s.to_owned(),
+                    None => panic!("Value of '{}' in cors block should be string or array of strings.", key),
+                }).collect(),
+                _ => panic!("Value of '{}' in cors block should be string or array of strings.", key),
+            }
+        };
+        for (key, value) in map {
+            match key.as_str() {
+                "origins" => cors.origins = string_list(key, value),
+                "methods" => cors.methods = string_list(key, value).into_iter().map(|m| m.to_uppercase()).collect(),
+                "headers" => cors.headers = string_list(key, value),
+                "exposeHeaders" => cors.expose_headers = string_list(key, value),
+                "credentials" => match value.as_bool() {
+                    Some(b) => cors.credentials = b,
+                    None => panic!("Value of 'credentials' in cors block should be bool."),
+                },
+                "maxAge" => match value {
+                    Value::Null => cors.max_age = None,
+                    _ => match value.as_i64().and_then(|i| u32::try_from(i).ok()) {
+                        Some(i) => cors.max_age = Some(i),
+                        None => panic!("Value of 'maxAge' in cors block should be a non negative int not greater than {}.", u32::MAX),
+                    }
+                },
+                _ => panic!("Undefined name '{}' in cors block.", key),
+            }
+        }
+        cors
+    }
+
+    // Data set
+
+    pub(crate) fn resolve_data_set(parser: &Parser, source: &Source, data_set: &mut DataSet) {
+        for group in data_set.groups.iter_mut() {
+            let model = match parser.models.iter().find(|(source_id, model_id)| {
+                parser.get_source(*source_id).get_model(*model_id).identifier.name == group.identifier.name
+            }) {
+                Some((source_id, model_id)) => parser.get_source(*source_id).get_model(*model_id),
+                None => panic!("Undefined model '{}' in data set '{}'.", group.identifier.name, data_set.identifier.name),
+            };
+            for record in group.records.iter_mut() {
+                let mut resolved: HashMap<String, Value> = HashMap::new();
+                for (key, value) in record.dictionary.expressions.iter() {
+                    let key = match key {
+                        ExpressionKind::Identifier(identifier) => identifier.name.clone(),
+                        _ => Self::resolve_expression_kind_force_value(parser, source, key, false).as_str().unwrap().to_string(),
+                    };
+                    let field = match model.fields.iter().find(|f| f.identifier.name == key) {
+                        Some(field) => field,
+                        None => panic!("Undefined field '{}' on model '{}' in data set '{}'.", key, model.identifier.name, data_set.identifier.name),
+                    };
+                    let value = match field.field_class {
+                        FieldClass::Relation => Self::resolve_data_set_record_reference(value),
+                        _ => match Self::resolve_expression_kind_force_value(parser, source, value, false) {
+                            Value::RawEnumChoice(name, None) => Value::String(name),
+                            value => value,
+                        }
+                    };
+                    resolved.insert(key, value);
+                }
+                record.resolved = Some(Value::HashMap(resolved));
+            }
+            group.resolved = true;
+        }
+        data_set.resolved = true;
+    }
+
+    fn resolve_data_set_record_reference(expression_kind: &ExpressionKind) -> Value {
+        match expression_kind {
+            ExpressionKind::Identifier(identifier) => Value::String(identifier.name.clone()),
+            ExpressionKind::ArrayLiteral(array_literal) => Value::Vec(array_literal.expressions.iter().map(Self::resolve_data_set_record_reference).collect()),
+            ExpressionKind::NullLiteral(_) => Value::Null,
+            _ => panic!("Relation value in data set should be a record name or an array of record names."),
+        }
+    }
+
     // Expression
 
     pub(crate) fn resolve_expression<'a>(parser: &Parser, source: &Source, expression: &mut Expression) {
@@ -888,6 +1152,27 @@ impl Resolver {
         Entity::Value(Value::HashMap(resolved))
     }
 
+    /// Resolves the argument of `$queryRaw`. Dictionary literals become ordered maps, the stages
+    /// of MongoDB pipelines and commands depend on the order of their keys.
+    fn resolve_raw_query_argument(parser: &Parser, source: &Source, expression_kind: &ExpressionKind) -> Value {
+        match expression_kind {
+            ExpressionKind::Group(group) => Self::resolve_raw_query_argument(parser, source, &group.expression),
+            ExpressionKind::Unit(unit) if unit.expressions.len() == 1 => Self::resolve_raw_query_argument(parser, source, unit.expressions.first().unwrap()),
+            ExpressionKind::ArrayLiteral(array_literal) => {
+                Value::Vec(array_literal.expressions.iter().map(|e| Self::resolve_raw_query_argument(parser, source, e)).collect())
+            }
+            ExpressionKind::DictionaryLiteral(dic) => {
+                let mut resolved: IndexMap<String, Value> = IndexMap::new();
+                for (key, value) in dic.expressions.iter() {
+                    let k = Self::resolve_expression_kind_force_value(parser, source, key, false);
+                    resolved.insert(k.as_str().unwrap().to_string(), Self::resolve_raw_query_argument(parser, source, value));
+                }
+                Value::IndexMap(resolved)
+            }
+            _ => Self::resolve_expression_kind_force_value(parser, source, expression_kind, false),
+        }
+    }
+
     fn resolve_nullish_coalescing(parser: &Parser, source: &Source, nullish_coalescing: &NullishCoalescing) -> Entity {
         let mut resolved = Entity::Value(Value::Null);
         for e in nullish_coalescing.expressions.iter() {
@@ -1066,3 +1351,56 @@ impl Resolver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use crate::core::app::builder::CallbackLookupTable;
+    use crate::prelude::Value;
+    use crate::parser::parser::Parser;
+    use super::Resolver;
+
+    fn resolve_constant(content: &str) -> Value {
+        let path = std::env::temp_dir().join(format!("teo_resolve_constant_{}_{}.teo", std::process::id(), content.len()));
+        std::fs::write(&path, format!("connector {{\n  provider .sqlite\n  url \"sqlite::memory:\"\n}}\n\n{}\n", content)).unwrap();
+        let mut parser = Parser::new(Arc::new(Mutex::new(CallbackLookupTable::new())));
+        parser.parse(Some(path.to_str().unwrap()));
+        std::fs::remove_file(&path).unwrap();
+        let source = parser.get_source(1);
+        let constant = source.tops.values().find_map(|top| top.as_constant()).unwrap();
+        constant.expression.resolved.as_ref().unwrap().as_value().unwrap().clone()
+    }
+
+    #[test]
+    fn dictionary_literal_is_hashmap() {
+        let value = resolve_constant("let sort = {\"zeta\": 1, \"alpha\": -1}");
+        assert!(value.is_hashmap());
+    }
+
+    #[test]
+    fn raw_query_argument_keeps_key_order() {
+        let value = resolve_constant("let raw = $queryRaw({\"pipeline\": [{\"$sort\": {\"zeta\": 1, \"alpha\": -1, \"mu\": 1, \"beta\": -1, \"omega\": 1, \"gamma\": -1}}]})");
+        let debug = format!("{:?}", value);
+        assert!(debug.contains("IndexMap"));
+        let positions: Vec<usize> = ["zeta", "alpha", "mu", "beta", "omega", "gamma"].iter().map(|k| debug.find(&format!("\"{}\"", k)).unwrap()).collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value of 'maxAge' in cors block should be a non negative int")]
+    fn cors_max_age_out_of_range_is_rejected() {
+        let mut map = HashMap::new();
+        map.insert("maxAge".to_owned(), Value::I64(u32::MAX as i64 + 1));
+        Resolver::resolve_cors(&map);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value of 'max' in rateLimit block should be a positive int")]
+    fn rate_limit_max_out_of_range_is_rejected() {
+        let mut map = HashMap::new();
+        map.insert("window".to_owned(), Value::I64(60));
+        map.insert("max".to_owned(), Value::I64(u32::MAX as i64 + 1));
+        Resolver::resolve_rate_limit(&map);
+    }
+}
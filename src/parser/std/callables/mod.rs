@@ -3,4 +3,5 @@ pub(crate) mod int_constructor;
 pub(crate) mod float_constructor;
 pub(crate) mod date_constructor;
 pub(crate) mod datetime_constructor;
+pub(crate) mod time_constructor;
 pub(crate) mod string_constructor;
@@ -0,0 +1,8 @@
+use chrono::NaiveTime;
+use crate::parser::ast::argument::Argument;
+use crate::prelude::Value;
+
+pub(crate) fn time_constructor(args: Vec<Argument>) -> Value {
+    let b = args.first().unwrap().resolved.as_ref().unwrap().as_value().unwrap().as_str().unwrap();
+    Value::Time(NaiveTime::parse_from_str(b, "%H:%M:%S%.f").unwrap())
+}
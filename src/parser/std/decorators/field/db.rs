@@ -14,6 +14,10 @@ pub(crate) fn db_container(database_name: DatabaseName) -> Container {
         DatabaseName::SQLite => {
             Container { objects: hashmap!{} }
         }
+        #[cfg(feature = "data-source-mssql")]
+        DatabaseName::MSSQL => {
+            Container { objects: hashmap!{} }
+        }
         DatabaseName::MongoDB => {
             Container { objects: hashmap!{} }
         }
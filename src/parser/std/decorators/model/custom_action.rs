@@ -0,0 +1,12 @@
+use crate::core::action::Action;
+use crate::core::model::builder::ModelBuilder;
+use crate::parser::ast::argument::Argument;
+
+pub(crate) fn custom_action_decorator(args: Vec<Argument>, model: &mut ModelBuilder) {
+    let value = args.first().unwrap().resolved.as_ref().unwrap().as_value().unwrap();
+    match value.as_str() {
+        Some(name) if Action::handler_from_name(name).is_some() => panic!("Custom action '{}' collides with a builtin handler.", name),
+        Some(name) => model.custom_actions.push(name.to_owned()),
+        None => panic!("Argument to 'customAction' should be a string."),
+    }
+}
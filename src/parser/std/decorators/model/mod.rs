@@ -10,6 +10,8 @@ pub(crate) mod can_read;
 pub(crate) mod can_mutate;
 pub(crate) mod disable;
 pub(crate) mod action;
+pub(crate) mod custom_action;
+pub(crate) mod rate_limit;
 pub(crate) mod migration;
 
 use std::collections::HashMap;
@@ -22,6 +24,8 @@ use crate::parser::std::decorators::model::before_delete::before_delete_decorato
 use crate::parser::std::decorators::model::before_save::before_save_decorator;
 use crate::parser::std::decorators::model::can_mutate::can_mutate_decorator;
 use crate::parser::std::decorators::model::can_read::can_read_decorator;
+use crate::parser::std::decorators::model::custom_action::custom_action_decorator;
+use crate::parser::std::decorators::model::rate_limit::rate_limit_decorator;
 use crate::parser::std::decorators::model::disable::disable_decorator;
 use crate::parser::std::decorators::model::identity::identity_decorator;
 use crate::parser::std::decorators::model::index::{index_decorator, id_decorator, unique_decorator};
@@ -55,6 +59,8 @@ impl GlobalModelDecorators {
         objects.insert("afterDelete".to_owned(), Accessible::ModelDecorator(after_delete_decorator));
         objects.insert("disable".to_owned(), Accessible::ModelDecorator(disable_decorator));
         objects.insert("action".to_owned(), Accessible::ModelDecorator(action_decorator));
+        objects.insert("customAction".to_owned(), Accessible::ModelDecorator(custom_action_decorator));
+        objects.insert("rateLimit".to_owned(), Accessible::ModelDecorator(rate_limit_decorator));
         objects.insert("canRead".to_owned(), Accessible::ModelDecorator(can_read_decorator));
         objects.insert("canMutate".to_owned(), Accessible::ModelDecorator(can_mutate_decorator));
         objects.insert("migration".to_owned(), Accessible::ModelDecorator(migration_decorator));
@@ -0,0 +1,48 @@
+use crate::core::action::Action;
+use crate::core::app::rate_limit::{RateLimit, RateLimitKey};
+use crate::core::model::builder::ModelBuilder;
+use crate::parser::ast::argument::Argument;
+use crate::prelude::Value;
+
+pub(crate) fn rate_limit_decorator(args: Vec<Argument>, model: &mut ModelBuilder) {
+    let mut window: Option<u64> = None;
+    let mut max: Option<u32> = None;
+    let mut key = RateLimitKey::Ip;
+    let mut handlers: Option<Vec<Action>> = None;
+    for arg in args.iter() {
+        let name = match &arg.name {
+            Some(name) => name.name.as_str(),
+            None => panic!("Arguments to 'rateLimit' should be named."),
+        };
+        let value = arg.resolved.as_ref().unwrap().as_value().unwrap();
+        match name {
+            "window" => match value.as_i64() {
+                Some(i) if i > 0 => window = Some(i as u64),
+                _ => panic!("Argument 'window' of 'rateLimit' should be a positive int."),
+            },
+            "max" => match value.as_i64().and_then(|i| u32::try_from(i).ok()) {
+                Some(i) if i > 0 => max = Some(i),
+                _ => panic!("Argument 'max' of 'rateLimit' should be a positive int not greater than {}.", u32::MAX),
+            },
+            "key" => key = match value.as_raw_enum_choice().and_then(RateLimitKey::from_name) {
+                Some(key) => key,
+                None => panic!("Argument 'key' of 'rateLimit' should be .ip or .identity."),
+            },
+            "handlers" => {
+                let values = match value {
+                    Value::Vec(values) => values.clone(),
+                    value => vec![value.clone()],
+                };
+                handlers = Some(values.iter().map(|v| match v.as_raw_enum_choice().and_then(Action::handler_from_name) {
+                    Some(action) => action,
+                    None => panic!("Argument 'handlers' of 'rateLimit' should be handler names."),
+                }).collect());
+            }
+            _ => panic!("Undefined argument '{}' of 'rateLimit'.", name),
+        }
+    }
+    match (window, max) {
+        (Some(window), Some(max)) => model.rate_limits.push(RateLimit { window, max, key, handlers }),
+        _ => panic!("'rateLimit' requires 'window' and 'max'."),
+    }
+}
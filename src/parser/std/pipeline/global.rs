@@ -28,6 +28,7 @@ use crate::parser::std::pipeline::object::{
     assign, ctx_self, is, is_a, object_get, object_previous_value, object_set,
 };
 use crate::parser::std::pipeline::query::query_raw;
+use crate::parser::std::pipeline::request::{client_ip, request_header, request_id};
 use crate::parser::std::pipeline::string::generation::{cuid, random_digits, slug, uuid};
 use crate::parser::std::pipeline::string::transform::{ellipsis, to_lower_case, to_upper_case, pad_end, pad_start, regex_replace, split, trim, to_word_case, to_sentence_case, to_title_case};
 use crate::parser::std::pipeline::string::validation::{has_prefix, has_suffix, is_alphabetic, is_alphanumeric, is_email, is_hex_color, is_numeric, is_prefix_of, is_secure_password, is_suffix_of, regex_match};
@@ -62,6 +63,8 @@ impl GlobalPipelineInstallers {
         // bcrypt
         objects.insert("bcryptSalt".to_owned(), bcrypt_salt);
         objects.insert("bcryptVerify".to_owned(), bcrypt_verify);
+        // bytes, `hasLength` counts the bytes of a bytes value
+        objects.insert("bytesLength".to_owned(), has_length);
         // datetime
         objects.insert("now".to_owned(), now);
         objects.insert("today".to_owned(), today);
@@ -157,6 +160,10 @@ impl GlobalPipelineInstallers {
         objects.insert("map".to_owned(), map);
         // query
         objects.insert("queryRaw".to_owned(), query_raw);
+        // request
+        objects.insert("requestId".to_owned(), request_id);
+        objects.insert("requestHeader".to_owned(), request_header);
+        objects.insert("clientIp".to_owned(), client_ip);
         Self { objects }
     }
 
@@ -14,4 +14,5 @@ pub(crate) mod value;
 pub(crate) mod vector;
 pub(crate) mod debug;
 pub(crate) mod query;
+pub(crate) mod request;
 pub(crate) mod global;
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+use crate::core::pipeline::item::Item;
+use crate::core::pipeline::items::request::client_ip::ClientIpItem;
+use crate::core::pipeline::items::request::request_header::RequestHeaderItem;
+use crate::core::pipeline::items::request::request_id::RequestIdItem;
+use crate::parser::ast::argument::Argument;
+
+pub(crate) fn request_id(_args: Vec<Argument>) -> Arc<dyn Item> {
+    Arc::new(RequestIdItem::new())
+}
+
+pub(crate) fn request_header(args: Vec<Argument>) -> Arc<dyn Item> {
+    let value = args.first().unwrap().resolved.as_ref().unwrap().as_value().unwrap();
+    match value.as_str() {
+        Some(name) => Arc::new(RequestHeaderItem::new(name)),
+        None => panic!("Argument to 'requestHeader' should be a string."),
+    }
+}
+
+pub(crate) fn client_ip(_args: Vec<Argument>) -> Arc<dyn Item> {
+    Arc::new(ClientIpItem::new())
+}
@@ -49,7 +49,16 @@ private func request<I: Encodable, O: Decodable>(model: String, action: String,
     request.httpBody = try! JSONEncoder().encode(input)
     let (data, response) = try! await URLSession.shared.data(for: request)
     guard response is HTTPURLResponse else { fatalError("response format is unexpected") }
-    return try! JSONDecoder().decode(O.self, from: data)
+    let decoder = JSONDecoder()
+    decoder.dataDecodingStrategy = .custom { decoder in
+        let container = try decoder.singleValueContainer()
+        let wrapped = try container.decode([String: String].self)
+        guard let base64 = wrapped["$bytes"], let bytes = Data(base64Encoded: base64) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "bytes should be encoded as {\"$bytes\": base64}")
+        }
+        return bytes
+    }
+    return try! decoder.decode(O.self, from: data)
 }
 
 struct AnyEncodable: Encodable {
@@ -126,6 +135,48 @@ extension NullOr: ExpressibleByFloatLiteral where T: ExpressibleByFloatLiteral {
     }
 }
 
+public indirect enum JSONValue: Codable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let b = try? container.decode(Bool.self) {
+            self = .bool(b)
+        } else if let n = try? container.decode(Double.self) {
+            self = .number(n)
+        } else if let s = try? container.decode(String.self) {
+            self = .string(s)
+        } else if let a = try? container.decode([JSONValue].self) {
+            self = .array(a)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case .bool(let b):
+            try container.encode(b)
+        case .number(let n):
+            try container.encode(n)
+        case .string(let s):
+            try container.encode(s)
+        case .array(let a):
+            try container.encode(a)
+        case .object(let o):
+            try container.encode(o)
+        }
+    }
+}
+
 public class NumberFilter<T: Encodable>: Encodable {
     public let equals: T?
     public let `in`: [T]?
@@ -164,6 +215,8 @@ public typealias DoubleFilter = NumberFilter<Double>
 public typealias DecimalFilter = NumberFilter<Decimal>
 public typealias DateFilter = NumberFilter<String>
 public typealias DateTimeFilter = NumberFilter<Date>
+public typealias TimeFilter = NumberFilter<String>
+public typealias BytesFilter = NumberFilter<Data>
 
 public class NumberNullableFilter<T: Encodable>: Encodable {
     public let equals: NullOr<T>?
@@ -203,7 +256,29 @@ public typealias DoubleNullableFilter = NumberNullableFilter<Double>
 public typealias DecimalNullableFilter = NumberNullableFilter<Decimal>
 public typealias DateNullableFilter = NumberNullableFilter<String>
 public typealias DateTimeNullableFilter = NumberNullableFilter<Date>
+public typealias TimeNullableFilter = NumberNullableFilter<String>
+public typealias BytesNullableFilter = NumberNullableFilter<Data>
+
+
+public class JsonFilter: Encodable {
+    public let equals: JSONValue?
+    public let not: JSONValue?
+    public let path: [JSONValue]?
+    public let contains: JSONValue?
+    public init(
+        equals: JSONValue? = nil,
+        not: JSONValue? = nil,
+        path: [JSONValue]? = nil,
+        contains: JSONValue? = nil
+    ) {
+        self.equals = equals
+        self.not = not
+        self.path = path
+        self.contains = contains
+    }
+}
 
+public typealias JsonNullableFilter = JsonFilter
 
 public class BoolFilter: Encodable, ExpressibleByBooleanLiteral {
     public let equals: Bool?
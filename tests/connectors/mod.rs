@@ -1,4 +1,5 @@
 pub mod mongodb;
+pub mod mssql;
 pub mod mysql;
 pub mod postgres;
 pub mod sqlite;
@@ -1,2 +1,4 @@
+pub mod compound_id;
+pub mod query_raw;
 pub mod relations;
 pub mod types;
@@ -0,0 +1,36 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::server_fixture;
+
+    server_fixture!(4046);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn pipeline_stages_keep_key_order() {
+        for (group, value) in [(1, 1), (2, 2), (1, 3), (2, 5), (1, 2)] {
+            req(PORT, "create", "Record", json!({"create": {"group": group, "value": value}}));
+        }
+        let res = req(PORT, "findFirst", "Record", json!({}));
+        let sorted = res.get("data").unwrap().get("sorted").unwrap().as_array().unwrap().clone();
+        assert!(sorted.len() >= 5);
+        for pair in sorted.windows(2) {
+            let (group, next_group) = (pair[0]["group"].as_i64().unwrap(), pair[1]["group"].as_i64().unwrap());
+            assert!(group <= next_group);
+            if group == next_group {
+                assert!(pair[0]["value"].as_i64().unwrap() >= pair[1]["value"].as_i64().unwrap());
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod compound_id;
+pub mod migration;
+pub mod params;
+pub mod relations;
+pub mod types;
@@ -0,0 +1,59 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4043);
+    static TITLE: &str = "O'Brien $1 ;-- DROP TABLE records";
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn create_and_find_round_trip() {
+        let res = req(PORT, "create", "Record", json!({
+            "create": { "title": TITLE, "note": TITLE },
+        }));
+        assert_json!(res, matcher!({
+            "data": { "id": ignore, "title": TITLE, "note": TITLE },
+        }));
+        let id = res.get("data").unwrap().get("id").unwrap().as_i64().unwrap();
+        let res = req(PORT, "findMany", "Record", json!({
+            "where": { "id": id, "title": TITLE, "note": { "contains": "$1 ;--" } },
+        }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": id, "title": TITLE, "note": TITLE }],
+        }))
+    }
+
+    #[test]
+    fn update_round_trip() {
+        let res = req(PORT, "create", "Record", json!({
+            "create": { "title": "plain", "note": "plain" },
+        }));
+        let id = res.get("data").unwrap().get("id").unwrap().as_i64().unwrap();
+        let res = req(PORT, "update", "Record", json!({
+            "where": { "id": id },
+            "update": { "title": TITLE, "note": TITLE },
+        }));
+        assert_json!(res, matcher!({
+            "data": { "id": id, "title": TITLE, "note": TITLE },
+        }));
+        let res = req(PORT, "findUnique", "Record", json!({
+            "where": { "id": id },
+        }));
+        assert_json!(res, matcher!({
+            "data": { "id": id, "title": TITLE, "note": TITLE },
+        }))
+    }
+}
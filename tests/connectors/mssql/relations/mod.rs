@@ -0,0 +1,92 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4044);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    // records are kept between runs, so every test works on names of its own
+    fn unique(name: &str) -> String {
+        format!("{}-{}", name, SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos())
+    }
+
+    #[test]
+    fn nested_create_and_include() {
+        let name = unique("include");
+        let res = req(PORT, "create", "Author", json!({
+            "create": { "name": name, "posts": { "create": [{ "title": "first" }, { "title": "second" }] } },
+            "include": { "posts": { "orderBy": { "id": "asc" } } },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "name": name.as_str(),
+                "posts": [
+                    { "id": ignore, "title": "first", "authorId": ignore },
+                    { "id": ignore, "title": "second", "authorId": ignore },
+                ],
+            },
+        }))
+    }
+
+    #[test]
+    fn filter_by_relation() {
+        let name = unique("filter");
+        let title = unique("title");
+        req(PORT, "create", "Author", json!({
+            "create": { "name": name, "posts": { "create": { "title": title } } },
+        }));
+        let res = req(PORT, "findMany", "Author", json!({
+            "where": { "posts": { "some": { "title": title } } },
+        }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "name": name.as_str() }],
+        }));
+        let res = req(PORT, "findMany", "Author", json!({
+            "where": { "name": name, "posts": { "none": { "title": title } } },
+        }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+
+    #[test]
+    fn join_table_include() {
+        let name = unique("author");
+        let res = req(PORT, "create", "Post", json!({
+            "create": {
+                "title": "tagged",
+                "author": { "create": { "name": name } },
+                "tags": { "create": [{ "name": "a" }, { "name": "b" }] },
+            },
+        }));
+        let id = res.get("data").unwrap().get("id").unwrap().as_i64().unwrap();
+        let res = req(PORT, "findUnique", "Post", json!({
+            "where": { "id": id },
+            "include": { "tags": { "orderBy": { "name": "asc" } } },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": id,
+                "title": "tagged",
+                "authorId": ignore,
+                "tags": [{ "id": ignore, "name": "a" }, { "id": ignore, "name": "b" }],
+            },
+        }))
+    }
+}
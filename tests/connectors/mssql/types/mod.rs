@@ -0,0 +1,246 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::lib::matcher_functions::{date_time_value, decimal_value, bytes_value};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4016);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn int32() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "int32": 1,
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "int32": 1,
+            }
+        }))
+    }
+
+    #[test]
+    fn int64() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "int64": 1,
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "int64": 1,
+            }
+        }))
+    }
+
+    #[test]
+    fn float32() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "float32": 1.5,
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "float32": 1.5,
+            }
+        }))
+    }
+
+    #[test]
+    fn float64() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "float64": 1.2,
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "float64": 1.2,
+            }
+        }))
+    }
+
+    #[test]
+    fn bool() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "bool": true,
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bool": true,
+            }
+        }))
+    }
+
+    #[test]
+    fn string() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "string": "KOF XV",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "string": "KOF XV",
+            }
+        }))
+    }
+
+    #[test]
+    fn date() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "date": "2005-12-25",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "date": "2005-12-25",
+            }
+        }))
+    }
+
+    #[test]
+    fn date_time() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "dateTime": "2003-04-17T08:12:34.567Z",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "dateTime": date_time_value("2003-04-17T08:12:34.567Z"),
+            }
+        }))
+    }
+
+    #[test]
+    fn time() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "time": "21:45:10.250",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "time": "21:45:10.250",
+            }
+        }))
+    }
+
+    #[test]
+    fn time_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "time": "06:30:00",
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "time": {
+                    "gt": "06:29:59.999",
+                    "lt": "06:30:00.001",
+                    "in": ["06:30:00", "18:30:00"],
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "time": "06:30:00",
+            }
+        }))
+    }
+
+    #[test]
+    fn bytes() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "aGVsbG8gdGVv",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bytes": bytes_value("aGVsbG8gdGVv"),
+            }
+        }))
+    }
+
+    #[test]
+    fn bytes_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "AAEC/w==",
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "bytes": {
+                    "equals": "AAEC/w==",
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bytes": bytes_value("AAEC/w=="),
+            }
+        }))
+    }
+
+    #[test]
+    fn decimal() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "decimal": "5.78",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "decimal": decimal_value("5.78"),
+            }
+        }))
+    }
+
+    #[test]
+    fn r#enum() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "sex": "FEMALE",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "sex": "FEMALE",
+            }
+        }))
+    }
+}
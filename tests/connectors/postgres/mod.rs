@@ -1,2 +1,6 @@
+pub mod compound_id;
+pub mod migration;
+pub mod no_migration;
+pub mod params;
 pub mod relations;
 pub mod types;
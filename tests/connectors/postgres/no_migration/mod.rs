@@ -0,0 +1,24 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use crate::server_fixture;
+
+    server_fixture!();
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve --no-migration");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn serve_without_migration_refuses_missing_database() {
+        let status = HANDLE.lock().unwrap().exit_status();
+        assert!(status.is_some());
+        assert!(!status.unwrap().success());
+    }
+}
@@ -3,17 +3,12 @@ use test_helpers::*;
 #[before_all]
 #[after_all]
 mod test {
-    use std::sync::Mutex;
-    use serde_json::{json, Value};
-    use crate::lib::{ExecutionHandle, req};
-    use crate::{assert_json, matcher};
-    use crate::lib::matcher_functions::{date_time_value, decimal_value};
-    use once_cell::sync::Lazy;
-
-    static HANDLE: Lazy<Mutex<ExecutionHandle>> = Lazy::new(|| {
-        Mutex::new(ExecutionHandle::new())
-    });
-    static PORT: i32 = 4013;
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+    use crate::lib::matcher_functions::{date_time_value, decimal_value, bytes_value};
+
+    server_fixture!(4013);
 
     fn before_all() {
         HANDLE.lock().unwrap().execute(file!(), "serve");
@@ -144,6 +139,142 @@ mod test {
         }))
     }
 
+    #[test]
+    fn time() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "time": "21:45:10.250",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "time": "21:45:10.250",
+            }
+        }))
+    }
+
+    #[test]
+    fn time_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "time": "06:30:00",
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "time": {
+                    "gt": "06:29:59.999",
+                    "lt": "06:30:00.001",
+                    "in": ["06:30:00", "18:30:00"],
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "time": "06:30:00",
+            }
+        }))
+    }
+
+    #[test]
+    fn bytes() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "aGVsbG8gdGVv",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bytes": bytes_value("aGVsbG8gdGVv"),
+            }
+        }))
+    }
+
+    #[test]
+    fn bytes_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "AAEC/w==",
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "bytes": {
+                    "equals": "AAEC/w==",
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bytes": bytes_value("AAEC/w=="),
+            }
+        }))
+    }
+
+    #[test]
+    fn json() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "json": {"name": "teo", "tags": ["orm", "server"], "meta": {"stars": 5}},
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "json": {"name": "teo", "tags": ["orm", "server"], "meta": {"stars": 5}},
+            }
+        }))
+    }
+
+    #[test]
+    fn json_path_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "json": {"kind": "path", "sizes": [{"width": 300}, {"width": 640}]},
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "json": {
+                    "path": ["sizes", 1, "width"],
+                    "equals": 640,
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "json": {"kind": "path", "sizes": [{"width": 300}, {"width": 640}]},
+            }
+        }))
+    }
+
+    #[test]
+    fn json_contains_filter() {
+        let _ = req(PORT, "create", "Support", json!({
+            "create": {
+                "json": {"kind": "contains", "labels": ["red", "green", "blue"]},
+            },
+        }));
+        let res = req(PORT, "findFirst", "Support", json!({
+            "where": {
+                "json": {
+                    "contains": {"kind": "contains", "labels": ["blue", "red"]},
+                },
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "json": {"kind": "contains", "labels": ["red", "green", "blue"]},
+            }
+        }))
+    }
+
     #[test]
     fn decimal() {
         let res = req(PORT, "create", "Support", json!({
@@ -0,0 +1,99 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4028);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_connectors_sqlite_compound_id.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn create_user_and_group(name: &str) -> (i64, i64) {
+        let user = req(PORT, "create", "User", json!({ "create": { "name": name } }));
+        let group = req(PORT, "create", "Group", json!({ "create": { "name": name } }));
+        (user["data"]["id"].as_i64().unwrap(), group["data"]["id"].as_i64().unwrap())
+    }
+
+    #[test]
+    fn find_unique_by_compound_key() {
+        let (user_id, group_id) = create_user_and_group("find");
+        req(PORT, "create", "Membership", json!({
+            "create": { "userId": user_id, "groupId": group_id, "role": "admin" },
+        }));
+        let res = req(PORT, "findUnique", "Membership", json!({
+            "where": { "userId_groupId": { "userId": user_id, "groupId": group_id } },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "userId": user_id,
+                "groupId": group_id,
+                "role": "admin",
+            }
+        }))
+    }
+
+    #[test]
+    fn update_by_compound_key() {
+        let (user_id, group_id) = create_user_and_group("update");
+        req(PORT, "create", "Membership", json!({
+            "create": { "userId": user_id, "groupId": group_id, "role": "admin" },
+        }));
+        let res = req(PORT, "update", "Membership", json!({
+            "where": { "userId_groupId": { "userId": user_id, "groupId": group_id } },
+            "update": { "role": "member" },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "userId": user_id,
+                "groupId": group_id,
+                "role": "member",
+            }
+        }))
+    }
+
+    #[test]
+    fn compound_key_requires_every_field() {
+        let res = req(PORT, "findUnique", "Membership", json!({
+            "where": { "userId_groupId": { "userId": 1 } },
+        }));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "MissingRequiredInput",
+                "message": "Missing required input.",
+                "errors": {
+                    "where.userId_groupId.groupId": "value is required",
+                },
+            }
+        }))
+    }
+
+    #[test]
+    fn disconnect_deletes_join_record() {
+        let (user_id, group_id) = create_user_and_group("disconnect");
+        req(PORT, "update", "User", json!({
+            "where": { "id": user_id },
+            "update": { "groups": { "connect": { "id": group_id } } },
+        }));
+        req(PORT, "update", "User", json!({
+            "where": { "id": user_id },
+            "update": { "groups": { "disconnect": { "id": group_id } } },
+        }));
+        let res = req(PORT, "findMany", "Membership", json!({
+            "where": { "userId": user_id },
+        }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+}
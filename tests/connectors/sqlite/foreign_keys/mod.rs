@@ -0,0 +1,92 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::{req, run_in_dir};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4038);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_connectors_sqlite_foreign_keys.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn failed_delete_rolls_back_cascade() {
+        let res = req(PORT, "create", "Author", json!({
+            "create": {
+                "code": "rollback",
+                "posts": { "create": [{ "title": "rollback-1" }, { "title": "rollback-2", "comments": { "create": {} } }] },
+            },
+        }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "rollback" } }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "findMany", "Post", json!({ "where": { "authorCode": "rollback" }, "orderBy": { "id": "asc" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 2 },
+            "data": [
+                { "id": ignore, "title": "rollback-1", "authorCode": "rollback" },
+                { "id": ignore, "title": "rollback-2", "authorCode": "rollback" },
+            ],
+        }))
+    }
+
+    #[test]
+    fn default_rule_declares_no_constraint() {
+        let res = req(PORT, "create", "Author", json!({
+            "create": { "code": "default", "notes": { "create": { "title": "default" } } },
+        }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "default" } }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "findMany", "Note", json!({ "where": { "title": "default" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "default", "authorCode": "default" }],
+        }))
+    }
+
+    #[test]
+    fn constraints_are_enforced() {
+        let res = req(PORT, "create", "Post", json!({
+            "create": { "title": "orphan", "authorCode": "missing" },
+        }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "findMany", "Post", json!({ "where": { "title": "orphan" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+
+    #[test]
+    fn update_rule_alone_declares_constraint() {
+        let output = run_in_dir(file!(), "schema.teo", "migrate --dry");
+        let _ = std::fs::remove_file(std::path::Path::new(file!()).parent().unwrap().join("test_connectors_sqlite_foreign_keys.sqlite"));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("CONSTRAINT `tags_authorCode_fkey` FOREIGN KEY (`authorCode`) REFERENCES `authors`(`code`) ON DELETE NO ACTION ON UPDATE CASCADE"));
+    }
+
+    #[test]
+    fn update_rule_alone_cascades() {
+        let res = req(PORT, "create", "Author", json!({
+            "create": { "code": "renamed", "tags": { "create": { "name": "renamed" } } },
+        }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "update", "Author", json!({ "where": { "code": "renamed" }, "update": { "code": "renamed-2" } }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "findMany", "Tag", json!({ "where": { "name": "renamed" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "name": "renamed", "authorCode": "renamed-2" }],
+        }))
+    }
+}
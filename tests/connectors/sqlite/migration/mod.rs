@@ -0,0 +1,56 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use crate::lib::run_in_dir;
+
+    fn dir() -> PathBuf {
+        Path::new(file!()).parent().unwrap().to_path_buf()
+    }
+
+    fn stdout(schema: &str, args: &str) -> String {
+        let output = run_in_dir(file!(), schema, args);
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn up_to_date(schema: &str) -> bool {
+        stdout(schema, "migrate --dry").contains("Database is up to date.")
+    }
+
+    /// Rolls back the files applied by a previous run so each run starts from empty tables.
+    fn clean() {
+        if dir().join("migrations").exists() {
+            for _ in 0..2 {
+                let _ = run_in_dir(file!(), "after.teo", "migrate rollback");
+            }
+        }
+        let _ = fs::remove_dir_all(dir().join("migrations"));
+        let _ = std::fs::remove_file(dir().join("test_connectors_sqlite_migration.sqlite"));
+    }
+
+    fn before_all() {
+        clean();
+    }
+
+    fn after_all() {
+        clean();
+    }
+
+    #[test]
+    fn migration_files_apply_and_roll_back() {
+        stdout("before.teo", "migrate generate init");
+        stdout("before.teo", "migrate apply");
+        assert!(up_to_date("before.teo"));
+        assert!(stdout("after.teo", "migrate generate rename").contains("Generated"));
+        stdout("after.teo", "migrate apply");
+        assert!(up_to_date("after.teo"));
+        assert!(stdout("after.teo", "migrate rollback").contains("Rolled back"));
+        assert!(up_to_date("before.teo"));
+        stdout("after.teo", "migrate apply");
+        assert!(up_to_date("after.teo"));
+    }
+}
@@ -1,2 +1,6 @@
+pub mod compound_id;
+pub mod foreign_keys;
+pub mod migration;
+pub mod params;
 pub mod relations;
 pub mod types;
@@ -0,0 +1,52 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use crate::lib::run_in_dir;
+
+    fn dir() -> PathBuf {
+        Path::new(file!()).parent().unwrap().to_path_buf()
+    }
+
+    fn clean() {
+        let _ = fs::remove_file(dir().join("test_core_app_migrate_files.sqlite"));
+        let _ = fs::remove_dir_all(dir().join("migrations"));
+    }
+
+    fn up_file(name: &str) -> String {
+        let version = fs::read_dir(dir().join("migrations")).unwrap()
+            .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_owned())
+            .find(|version| version.ends_with(name)).unwrap();
+        fs::read_to_string(dir().join("migrations").join(version).join("up.sql")).unwrap()
+    }
+
+    fn before_all() {
+        clean();
+    }
+
+    fn after_all() {
+        clean();
+    }
+
+    #[test]
+    fn migration_files_create_indices_last_and_run_actions() {
+        assert!(run_in_dir(file!(), "before.teo", "migrate generate init").status.success());
+        assert!(run_in_dir(file!(), "before.teo", "migrate apply").status.success());
+        assert!(run_in_dir(file!(), "after.teo", "migrate generate email").status.success());
+        let up = up_file("_email");
+        let add_column = up.find("ADD").unwrap();
+        let action = up.find("-- teo:action users email").unwrap();
+        let create_index = up.find("CREATE INDEX").unwrap();
+        assert!(add_column < action);
+        assert!(action < create_index);
+        let output = run_in_dir(file!(), "after.teo", "migrate apply");
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("email migration action"));
+        let output = run_in_dir(file!(), "after.teo", "migrate apply");
+        assert!(String::from_utf8(output.stdout).unwrap().contains("No migration files to apply."));
+    }
+}
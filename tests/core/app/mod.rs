@@ -0,0 +1,3 @@
+pub mod migrate;
+pub mod seed;
+pub mod serve;
@@ -0,0 +1,48 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::{req, run};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4029);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_app_seed_autoseed.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn autoseed_creates_records_with_relations() {
+        let res = req(PORT, "findUnique", "Author", json!({
+            "where": { "name": "Alice" },
+            "include": { "posts": { "orderBy": { "title": "asc" } } },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "name": "Alice",
+                "posts": [
+                    { "id": ignore, "title": "First", "authorId": ignore },
+                    { "id": ignore, "title": "Second", "authorId": ignore },
+                ],
+            }
+        }))
+    }
+
+    #[test]
+    fn seeding_again_does_not_duplicate_records() {
+        assert!(run(file!(), "seed").success());
+        let res = req(PORT, "findMany", "Post", json!({}));
+        assert_json!(res, matcher!({
+            "meta": { "count": 2 },
+            "data": ignore,
+        }))
+    }
+}
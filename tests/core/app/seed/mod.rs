@@ -0,0 +1,3 @@
+pub mod autoseed;
+pub mod no_migration;
+pub mod rollback;
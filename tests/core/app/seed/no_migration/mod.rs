@@ -0,0 +1,30 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::{req, run};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4030);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_app_seed_no_migration.sqlite");
+        assert!(run(file!(), "migrate").success());
+        HANDLE.lock().unwrap().execute(file!(), "serve --no-migration");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn serve_without_migration_skips_autoseed() {
+        let res = req(PORT, "findMany", "Author", json!({}));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+}
@@ -0,0 +1,54 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::StatusCode;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4018);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn within_limit() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "string": "a".repeat(512),
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "string": "a".repeat(512),
+            }
+        }))
+    }
+
+    #[test]
+    fn over_limit() {
+        let url = format!("http://127.0.0.1:{}/Support/action/create", PORT);
+        let res = reqwest::blocking::Client::new().post(url).json(&json!({
+            "create": {
+                "string": "a".repeat(2048),
+            },
+        })).send().unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let res: Value = res.json().unwrap();
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "PayloadTooLarge",
+                "message": "Request body is larger than 1024 bytes.",
+                "errors": null,
+            }
+        }))
+    }
+}
@@ -0,0 +1,73 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::{Client, Response};
+    use reqwest::Method;
+    use crate::server_fixture;
+
+    server_fixture!(4017);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(method: Method, origin: &str, headers: Vec<(&str, &str)>) -> Response {
+        let url = format!("http://127.0.0.1:{}/Support/action/findMany", PORT);
+        let mut builder = Client::new().request(method, url).header("Origin", origin);
+        for (k, v) in headers {
+            builder = builder.header(k, v);
+        }
+        builder.body("{}").send().unwrap()
+    }
+
+    fn header(res: &Response, name: &str) -> Option<String> {
+        res.headers().get(name).map(|v| v.to_str().unwrap().to_owned())
+    }
+
+    #[test]
+    fn preflight_allowed_origin() {
+        let res = send(Method::OPTIONS, "https://app.example.com", vec![("Access-Control-Request-Method", "POST")]);
+        assert_eq!(res.status().as_u16(), 204);
+        assert_eq!(header(&res, "Access-Control-Allow-Origin").as_deref(), Some("https://app.example.com"));
+        assert_eq!(header(&res, "Access-Control-Allow-Methods").as_deref(), Some("OPTIONS, POST, GET"));
+        assert_eq!(header(&res, "Access-Control-Allow-Headers").as_deref(), Some("Authorization, Content-Type"));
+        assert_eq!(header(&res, "Access-Control-Allow-Credentials").as_deref(), Some("true"));
+        assert_eq!(header(&res, "Access-Control-Max-Age").as_deref(), Some("600"));
+    }
+
+    #[test]
+    fn preflight_disallowed_origin() {
+        let res = send(Method::OPTIONS, "https://evil.example.com", vec![("Access-Control-Request-Method", "POST")]);
+        assert_eq!(res.status().as_u16(), 204);
+        assert_eq!(header(&res, "Access-Control-Allow-Origin"), None);
+    }
+
+    #[test]
+    fn preflight_disallowed_method() {
+        let res = send(Method::OPTIONS, "https://app.example.com", vec![("Access-Control-Request-Method", "DELETE")]);
+        assert_eq!(res.status().as_u16(), 204);
+        assert_eq!(header(&res, "Access-Control-Allow-Origin"), None);
+    }
+
+    #[test]
+    fn request_allowed_origin() {
+        let res = send(Method::POST, "https://app.example.com", vec![]);
+        assert_eq!(res.status().as_u16(), 200);
+        assert_eq!(header(&res, "Access-Control-Allow-Origin").as_deref(), Some("https://app.example.com"));
+        assert_eq!(header(&res, "Access-Control-Allow-Credentials").as_deref(), Some("true"));
+        assert_eq!(header(&res, "Access-Control-Expose-Headers").as_deref(), Some("X-Total-Count"));
+        assert_eq!(header(&res, "Vary").as_deref(), Some("Origin"));
+    }
+
+    #[test]
+    fn request_disallowed_origin() {
+        let res = send(Method::POST, "https://evil.example.com", vec![]);
+        assert_eq!(header(&res, "Access-Control-Allow-Origin"), None);
+    }
+}
@@ -0,0 +1,67 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::StatusCode;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4045);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute_example("custom_actions", file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn same_name_on_two_models() {
+        let res = req(PORT, "publish", "Post", json!({}));
+        assert_json!(res, matcher!({
+            "data": { "published": "post" }
+        }));
+        let res = req(PORT, "publish", "Comment", json!({}));
+        assert_json!(res, matcher!({
+            "data": { "published": "comment", "requestId": ignore }
+        }))
+    }
+
+    #[test]
+    fn handler_receives_request() {
+        let url = format!("http://127.0.0.1:{}/Comment/action/publish", PORT);
+        let res: Value = reqwest::blocking::Client::new().post(url).header("X-Request-Id", "custom-action-request").json(&json!({})).send().unwrap().json().unwrap();
+        assert_json!(res, matcher!({
+            "data": { "published": "comment", "requestId": "custom-action-request" }
+        }))
+    }
+
+    #[test]
+    fn custom_action_is_limited() {
+        for _ in 0..2 {
+            let res = req(PORT, "publish", "Draft", json!({}));
+            assert_json!(res, matcher!({
+                "data": { "published": "draft" }
+            }));
+        }
+        let res = req(PORT, "publish", "Draft", json!({}));
+        assert_json!(res, matcher!({
+            "error": { "type": "TooManyRequests", "message": ignore, "errors": null }
+        }))
+    }
+
+    #[test]
+    fn custom_route_is_limited() {
+        let url = format!("http://127.0.0.1:{}/ping", PORT);
+        for _ in 0..2 {
+            let res = reqwest::blocking::Client::new().post(&url).send().unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+        let res = reqwest::blocking::Client::new().post(&url).send().unwrap();
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(res.headers().get("Retry-After").is_some());
+    }
+}
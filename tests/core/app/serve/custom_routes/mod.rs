@@ -0,0 +1,58 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4022);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn unregistered_custom_action() {
+        let res = req(PORT, "publish", "Post", json!({}));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InternalServerError",
+                "message": "Custom action 'publish' is not registered.",
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn undeclared_custom_action() {
+        let res = req(PORT, "archive", "Post", json!({}));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "DestinationNotFound",
+                "message": ignore,
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn builtin_actions() {
+        let res = req(PORT, "create", "Post", json!({
+            "create": {
+                "title": "Title",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "title": "Title",
+            }
+        }))
+    }
+}
@@ -0,0 +1,61 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use chrono::Utc;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use reqwest::blocking::Client;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4020);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(action: &str, token: &str) -> Value {
+        let url = format!("http://127.0.0.1:{}/User/action/{}", PORT, action);
+        let builder = Client::new().post(url).json(&json!({})).header("Authorization", format!("Bearer {}", token));
+        builder.send().unwrap().json().unwrap()
+    }
+
+    #[test]
+    fn sign_in_and_identity() {
+        req(PORT, "create", "User", json!({"create": {"email": "es256@teo.dev", "password": "123456"}}));
+        let res = req(PORT, "signIn", "User", json!({"credentials": {"email": "es256@teo.dev", "password": "123456"}}));
+        let token = res["meta"]["token"].as_str().unwrap();
+        let res = send("identity", token);
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "email": "es256@teo.dev",
+            }
+        }))
+    }
+
+    #[test]
+    fn hmac_token_is_rejected() {
+        let res = req(PORT, "create", "User", json!({"create": {"email": "hmac@teo.dev", "password": "123456"}}));
+        let claims = json!({
+            "id": {"id": res["data"]["id"]},
+            "model": "User",
+            "exp": Utc::now().timestamp() + 3600,
+        });
+        let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(b"secret")).unwrap();
+        let res = send("identity", &token);
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InvalidAuthToken",
+                "message": "This auth token is invalid.",
+                "errors": null,
+            }
+        }))
+    }
+}
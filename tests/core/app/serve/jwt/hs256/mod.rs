@@ -0,0 +1,141 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use chrono::Utc;
+    use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+    use reqwest::blocking::Client;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4019);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(action: &str, token: Option<&str>) -> Value {
+        send_to("User", action, token)
+    }
+
+    fn send_to(model: &str, action: &str, token: Option<&str>) -> Value {
+        let url = format!("http://127.0.0.1:{}/{}/action/{}", PORT, model, action);
+        let mut builder = Client::new().post(url).json(&json!({}));
+        if let Some(token) = token {
+            builder = builder.header("Authorization", format!("Bearer {}", token));
+        }
+        builder.send().unwrap().json().unwrap()
+    }
+
+    fn sign_in(email: &str) -> String {
+        req(PORT, "create", "User", json!({"create": {"email": email, "password": "123456"}}));
+        let res = req(PORT, "signIn", "User", json!({"credentials": {"email": email, "password": "123456"}}));
+        res["meta"]["token"].as_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn sign_in_uses_configured_claims() {
+        let token = sign_in("claims@teo.dev");
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&["teo-tests"]);
+        validation.set_audience(&["teo-tests-client"]);
+        let claims = decode::<Value>(&token, &DecodingKey::from_secret(b"hs256-secret"), &validation).unwrap().claims;
+        let lifetime = claims["exp"].as_i64().unwrap() - Utc::now().timestamp();
+        assert!(lifetime > 3500 && lifetime <= 3600);
+    }
+
+    #[test]
+    fn refresh_token() {
+        let token = sign_in("refresh@teo.dev");
+        let res = send("refreshToken", Some(&token));
+        assert_json!(res, matcher!({
+            "meta": {
+                "token": ignore,
+            },
+            "data": {
+                "id": ignore,
+                "email": "refresh@teo.dev",
+            }
+        }))
+    }
+
+    #[test]
+    fn refresh_token_without_token() {
+        let res = send("refreshToken", None);
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "MissingAuthToken",
+                "message": "This action requires an auth token.",
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn token_from_other_issuer() {
+        let res = req(PORT, "create", "User", json!({"create": {"email": "issuer@teo.dev", "password": "123456"}}));
+        let claims = json!({
+            "id": {"id": res["data"]["id"]},
+            "model": "User",
+            "exp": Utc::now().timestamp() + 3600,
+            "iss": "someone-else",
+            "aud": "teo-tests-client",
+        });
+        let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(b"hs256-secret")).unwrap();
+        let res = send("identity", Some(&token));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InvalidAuthToken",
+                "message": "This auth token is invalid.",
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn compound_identity_token() {
+        req(PORT, "create", "Member", json!({"create": {"handle": "alice", "tenant": "teo", "email": "alice@teo.dev", "password": "123456"}}));
+        let res = req(PORT, "signIn", "Member", json!({"credentials": {"email": "alice@teo.dev", "password": "123456"}}));
+        let token = res["meta"]["token"].as_str().unwrap();
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&["teo-tests"]);
+        validation.set_audience(&["teo-tests-client"]);
+        let claims = decode::<Value>(token, &DecodingKey::from_secret(b"hs256-secret"), &validation).unwrap().claims;
+        assert_eq!(claims["id"].to_string(), r#"{"tenant":"teo","handle":"alice"}"#);
+        let res = send_to("Member", "identity", Some(token));
+        assert_json!(res, matcher!({
+            "data": {
+                "tenant": "teo",
+                "handle": "alice",
+                "email": "alice@teo.dev",
+            }
+        }))
+    }
+
+    #[test]
+    fn token_without_full_identifier() {
+        req(PORT, "create", "Member", json!({"create": {"handle": "bob", "tenant": "teo", "email": "bob@teo.dev", "password": "123456"}}));
+        let claims = json!({
+            "id": {"email": "bob@teo.dev"},
+            "model": "Member",
+            "exp": Utc::now().timestamp() + 3600,
+            "iss": "teo-tests",
+            "aud": "teo-tests-client",
+        });
+        let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(b"hs256-secret")).unwrap();
+        let res = send_to("Member", "identity", Some(&token));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InvalidAuthToken",
+                "message": "This auth token is invalid.",
+                "errors": null,
+            }
+        }))
+    }
+}
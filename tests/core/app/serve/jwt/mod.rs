@@ -0,0 +1,2 @@
+pub mod hs256;
+pub mod es256;
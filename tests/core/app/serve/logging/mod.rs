@@ -0,0 +1,90 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use std::thread;
+    use std::time::Duration;
+    use reqwest::blocking::Client;
+    use serde_json::{json, Value};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4024);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute_capturing_output(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn records(request_id: &str) -> Vec<Value> {
+        thread::sleep(Duration::from_millis(200));
+        HANDLE.lock().unwrap().output().iter()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+            .filter(|record| record.get("requestId") == Some(&json!(request_id)))
+            .collect()
+    }
+
+    #[test]
+    fn warning_is_logged_as_json() {
+        let url = format!("http://127.0.0.1:{}/Support/action/findUnique", PORT);
+        let res = Client::new().post(url).header("X-Request-Id", "logging-1").body("[]").send().unwrap();
+        assert_eq!(res.status().as_u16(), 400);
+        let records = records("logging-1");
+        assert_eq!(records.len(), 1);
+        assert_json!(records[0].clone(), matcher!({
+            "timestamp": ignore,
+            "level": "warn",
+            "method": "POST",
+            "path": "/Support/action/findUnique",
+            "status": 400,
+            "latency": ignore,
+            "handled": false,
+            "requestId": "logging-1",
+            "model": "Support",
+            "handler": "findUnique",
+        }))
+    }
+
+    #[test]
+    fn invalid_argument_is_logged() {
+        let url = format!("http://127.0.0.1:{}/Support/action/findMany", PORT);
+        let res = Client::new().post(url).header("X-Request-Id", "logging-3").body(r#"{"where": 1}"#).send().unwrap();
+        assert_eq!(res.status().as_u16(), 400);
+        let records = records("logging-3");
+        assert_eq!(records.len(), 1);
+        assert_json!(records[0].clone(), matcher!({
+            "timestamp": ignore,
+            "level": "warn",
+            "method": "POST",
+            "path": "/Support/action/findMany",
+            "status": 400,
+            "latency": ignore,
+            "handled": false,
+            "requestId": "logging-3",
+            "model": "Support",
+            "handler": "findMany",
+        }))
+    }
+
+    #[test]
+    fn invalid_auth_token_is_logged() {
+        let url = format!("http://127.0.0.1:{}/Support/action/findMany", PORT);
+        let res = Client::new().post(url).header("X-Request-Id", "logging-4").bearer_auth("invalid").body("{}").send().unwrap();
+        assert_eq!(res.status().as_u16(), 401);
+        let records = records("logging-4");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("status"), Some(&json!(401)));
+        assert_eq!(records[0].get("level"), Some(&json!("warn")));
+    }
+
+    #[test]
+    fn info_is_filtered_by_level() {
+        let url = format!("http://127.0.0.1:{}/Support/action/findMany", PORT);
+        let res = Client::new().post(url).header("X-Request-Id", "logging-2").body("{}").send().unwrap();
+        assert_eq!(res.status().as_u16(), 200);
+        assert!(records("logging-2").is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+pub mod cors;
+pub mod body_limit;
+pub mod jwt;
+pub mod sign_out;
+pub mod custom_routes;
+pub mod custom_actions;
+pub mod probes;
+pub mod logging;
+pub mod request_context;
+pub mod rate_limit;
+pub mod shutdown;
+pub mod transaction;
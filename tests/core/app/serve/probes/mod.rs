@@ -0,0 +1,53 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::Value;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4023);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn get(path: &str) -> (u16, Value) {
+        let res = reqwest::blocking::get(format!("http://127.0.0.1:{}{}", PORT, path)).unwrap();
+        (res.status().as_u16(), res.json().unwrap())
+    }
+
+    #[test]
+    fn health() {
+        let (status, res) = get("/_health");
+        assert_eq!(status, 200);
+        assert_json!(res, matcher!({"status": "ok"}))
+    }
+
+    #[test]
+    fn ready() {
+        let (status, res) = get("/_ready");
+        assert_eq!(status, 200);
+        assert_json!(res, matcher!({"status": "ready"}))
+    }
+
+    #[test]
+    fn meta() {
+        let (status, res) = get("/_meta");
+        assert_eq!(status, 200);
+        assert_json!(res, matcher!({
+            "version": ignore,
+            "environment": ignore,
+            "models": [
+                {
+                    "name": "Support",
+                    "handlers": ignore,
+                }
+            ]
+        }))
+    }
+}
@@ -0,0 +1,65 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::{Client, Response};
+    use serde_json::{json, Value};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4026);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(model: &str, action: &str, body: Value) -> Response {
+        let url = format!("http://127.0.0.1:{}/{}/action/{}", PORT, model, action);
+        Client::new().post(url).json(&body).send().unwrap()
+    }
+
+    fn assert_limited(res: Response) {
+        assert_eq!(res.status().as_u16(), 429);
+        let retry_after: u64 = res.headers().get("Retry-After").unwrap().to_str().unwrap().parse().unwrap();
+        assert!(retry_after > 0 && retry_after <= 60);
+        let res: Value = res.json().unwrap();
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "TooManyRequests",
+                "message": ignore,
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn model_limit() {
+        for _ in 0..2 {
+            let res = send("Support", "create", json!({"create": {}}));
+            assert_eq!(res.status().as_u16(), 200);
+        }
+        assert_limited(send("Support", "create", json!({"create": {}})));
+    }
+
+    #[test]
+    fn server_default() {
+        for _ in 0..3 {
+            let res = send("Other", "findMany", json!({}));
+            assert_eq!(res.status().as_u16(), 200);
+        }
+        assert_limited(send("Other", "findMany", json!({})));
+    }
+
+    #[test]
+    fn server_default_is_counted_per_handler() {
+        for _ in 0..3 {
+            let res = send("Other", "count", json!({}));
+            assert_eq!(res.status().as_u16(), 200);
+        }
+        assert_limited(send("Other", "count", json!({})));
+    }
+}
@@ -0,0 +1,62 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::{Client, Response};
+    use serde_json::{json, Value};
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4025);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn create(headers: Vec<(&str, &str)>) -> Response {
+        let url = format!("http://127.0.0.1:{}/Audit/action/create", PORT);
+        let mut builder = Client::new().post(url);
+        for (k, v) in headers {
+            builder = builder.header(k, v);
+        }
+        builder.json(&json!({"create": {}})).send().unwrap()
+    }
+
+    fn request_id(res: &Response) -> String {
+        res.headers().get("X-Request-Id").unwrap().to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn propagates_request_id() {
+        let res = create(vec![("X-Request-Id", "request-context-1"), ("X-Tenant", "acme")]);
+        assert_eq!(request_id(&res), "request-context-1");
+        let res: Value = res.json().unwrap();
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "tenant": "acme",
+                "requestId": "request-context-1",
+                "clientIp": "127.0.0.1",
+            }
+        }))
+    }
+
+    #[test]
+    fn generates_request_id() {
+        let res = create(vec![]);
+        let generated = request_id(&res);
+        assert_eq!(generated.len(), 36);
+        let res: Value = res.json().unwrap();
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "requestId": generated,
+                "clientIp": "127.0.0.1",
+            }
+        }))
+    }
+}
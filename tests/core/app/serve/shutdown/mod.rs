@@ -0,0 +1,28 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::Client;
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4027);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() { }
+
+    #[test]
+    fn graceful_shutdown() {
+        let res = req(PORT, "findMany", "Support", json!({}));
+        assert_json!(res, matcher!({"data": [], "meta": {"count": 0}}));
+        let status = HANDLE.lock().unwrap().terminate();
+        assert!(status.success());
+        let url = format!("http://127.0.0.1:{}/Support/action/findMany", PORT);
+        assert!(Client::new().post(url).body("{}").send().is_err());
+    }
+}
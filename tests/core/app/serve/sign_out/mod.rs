@@ -0,0 +1,105 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::Client;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4021);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(action: &str, token: Option<&str>) -> Value {
+        let url = format!("http://127.0.0.1:{}/User/action/{}", PORT, action);
+        let mut builder = Client::new().post(url).json(&json!({}));
+        if let Some(token) = token {
+            builder = builder.header("Authorization", format!("Bearer {}", token));
+        }
+        builder.send().unwrap().json().unwrap()
+    }
+
+    fn sign_in(email: &str) -> String {
+        let res = req(PORT, "signIn", "User", json!({"credentials": {"email": email, "password": "123456"}}));
+        res["meta"]["token"].as_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn sign_out_revokes_token() {
+        req(PORT, "create", "User", json!({"create": {"email": "revoke@teo.dev", "password": "123456"}}));
+        let token = sign_in("revoke@teo.dev");
+        let res = send("signOut", Some(&token));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "email": "revoke@teo.dev",
+            }
+        }));
+        let res = send("identity", Some(&token));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InvalidAuthToken",
+                "message": "This auth token is invalid.",
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn sign_out_keeps_other_tokens() {
+        req(PORT, "create", "User", json!({"create": {"email": "other@teo.dev", "password": "123456"}}));
+        let token = sign_in("other@teo.dev");
+        let other_token = sign_in("other@teo.dev");
+        send("signOut", Some(&token));
+        let res = send("identity", Some(&other_token));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "email": "other@teo.dev",
+            }
+        }))
+    }
+
+    #[test]
+    fn sign_out_without_token() {
+        let res = send("signOut", None);
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "MissingAuthToken",
+                "message": "This action requires an auth token.",
+                "errors": null,
+            }
+        }))
+    }
+
+    #[test]
+    fn refresh_token_revokes_replaced_token() {
+        req(PORT, "create", "User", json!({"create": {"email": "refresh@teo.dev", "password": "123456"}}));
+        let token = sign_in("refresh@teo.dev");
+        let res = send("refreshToken", Some(&token));
+        let refreshed = res["meta"]["token"].as_str().unwrap().to_owned();
+        let res = send("identity", Some(&token));
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "InvalidAuthToken",
+                "message": "This auth token is invalid.",
+                "errors": null,
+            }
+        }));
+        let res = send("identity", Some(&refreshed));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "email": "refresh@teo.dev",
+            }
+        }))
+    }
+}
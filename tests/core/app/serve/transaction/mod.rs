@@ -0,0 +1,63 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4031);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_app_serve_transaction.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn failed_nested_create_rolls_back_parent() {
+        let res = req(PORT, "create", "User", json!({
+            "create": {
+                "email": "nested@teo.io",
+                "posts": { "create": [{ "title": "nested" }, { "title": "nested" }] },
+            },
+        }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "findMany", "User", json!({ "where": { "email": "nested@teo.io" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+
+    #[test]
+    fn create_many_is_atomic() {
+        let res = req(PORT, "createMany", "User", json!({
+            "create": [{ "email": "many@teo.io" }, { "email": "many@teo.io" }],
+        }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "findMany", "User", json!({ "where": { "email": "many@teo.io" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+
+    #[test]
+    fn create_many_creates_every_record() {
+        let res = req(PORT, "createMany", "User", json!({
+            "create": [{ "email": "a@teo.io" }, { "email": "b@teo.io" }],
+        }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 2 },
+            "data": [
+                { "id": ignore, "email": "a@teo.io" },
+                { "id": ignore, "email": "b@teo.io" },
+            ],
+        }))
+    }
+}
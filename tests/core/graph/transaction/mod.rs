@@ -0,0 +1,54 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use reqwest::blocking::Client;
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4032);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_graph_transaction.sqlite");
+        HANDLE.lock().unwrap().execute_example("transaction", file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    fn send(route: &str, prefix: &str) -> Value {
+        let url = format!("http://127.0.0.1:{}/{}", PORT, route);
+        Client::new().post(url).json(&json!({ "prefix": prefix })).send().unwrap().json().unwrap()
+    }
+
+    #[test]
+    fn transaction_commits_on_ok() {
+        let res = send("commit", "commit");
+        assert_json!(res, matcher!({ "data": null }));
+        let res = req(PORT, "findMany", "User", json!({ "where": { "email": { "startsWith": "commit" } } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 2 },
+            "data": ignore,
+        }))
+    }
+
+    #[test]
+    fn transaction_rolls_back_on_err() {
+        let res = send("rollback", "rollback");
+        assert_json!(res, matcher!({
+            "error": {
+                "type": "CustomValidationError",
+                "message": "Rolled back.",
+                "errors": null,
+            }
+        }));
+        let res = req(PORT, "findMany", "User", json!({ "where": { "email": { "startsWith": "rollback" } } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }))
+    }
+}
@@ -1 +1,5 @@
 pub mod pipeline;
+pub mod app;
+pub mod graph;
+pub mod model;
+pub mod object;
@@ -0,0 +1,9 @@
+use crate::lib::run_in_dir;
+
+#[test]
+fn custom_action_named_like_builtin_handler_is_rejected() {
+    let output = run_in_dir(file!(), "schema.teo", "migrate");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Custom action 'create' collides with a builtin handler."));
+}
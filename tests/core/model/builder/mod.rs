@@ -0,0 +1,2 @@
+pub mod nullify_required;
+pub mod custom_action_collision;
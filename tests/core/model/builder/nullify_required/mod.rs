@@ -0,0 +1,11 @@
+use std::path::Path;
+use crate::lib::run_in_dir;
+
+#[test]
+fn nullify_rule_on_required_field_is_rejected() {
+    let output = run_in_dir(file!(), "schema.teo", "migrate");
+    let _ = std::fs::remove_file(Path::new(file!()).parent().unwrap().join("test_core_model_builder_nullify_required.sqlite"));
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Relation 'author' of model 'Post' can't nullify required field 'authorId'."));
+}
@@ -0,0 +1,2 @@
+pub mod primary_key;
+pub mod relation_rules;
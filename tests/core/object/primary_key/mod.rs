@@ -0,0 +1,38 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4037);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_object_primary_key.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn update_primary_key_updates_stored_record() {
+        req(PORT, "create", "Tag", json!({ "create": { "name": "old", "description": "a" } }));
+        req(PORT, "create", "Tag", json!({ "create": { "name": "other", "description": "b" } }));
+        let res = req(PORT, "update", "Tag", json!({ "where": { "name": "old" }, "update": { "name": "new" } }));
+        assert_json!(res, matcher!({
+            "data": { "name": "new", "description": "a" },
+        }));
+        let res = req(PORT, "findMany", "Tag", json!({ "orderBy": { "name": "asc" } }));
+        assert_json!(res, matcher!({
+            "meta": { "count": 2 },
+            "data": [
+                { "name": "new", "description": "a" },
+                { "name": "other", "description": "b" },
+            ],
+        }))
+    }
+}
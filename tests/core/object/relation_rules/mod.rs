@@ -0,0 +1,120 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::{json, Value};
+    use crate::lib::req;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4036);
+
+    fn before_all() {
+        let _ = std::fs::remove_file("test_core_object_relation_rules.sqlite");
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    /// Creates an author with a post of `model`.
+    fn create_author(code: &str, model: &str) {
+        let relation = match model {
+            "CascadePost" => "cascadePosts",
+            "NullifyPost" => "nullifyPosts",
+            _ => "denyPosts",
+        };
+        let res = req(PORT, "create", "Author", json!({
+            "create": { "code": code, relation: { "create": { "title": code } } },
+        }));
+        assert!(res.get("error").is_none());
+    }
+
+    fn posts(model: &str, title: &str) -> Value {
+        req(PORT, "findMany", model, json!({ "where": { "title": title } }))
+    }
+
+    #[test]
+    fn cascade_update_only_updates_related_records() {
+        create_author("cu1", "CascadePost");
+        create_author("cu2", "CascadePost");
+        let res = req(PORT, "update", "Author", json!({ "where": { "code": "cu1" }, "update": { "code": "cu1-new" } }));
+        assert!(res.get("error").is_none());
+        assert_json!(posts("CascadePost", "cu1"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "cu1", "authorCode": "cu1-new" }],
+        }));
+        assert_json!(posts("CascadePost", "cu2"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "cu2", "authorCode": "cu2" }],
+        }))
+    }
+
+    #[test]
+    fn cascade_delete_only_deletes_related_records() {
+        create_author("cd1", "CascadePost");
+        create_author("cd2", "CascadePost");
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "cd1" } }));
+        assert!(res.get("error").is_none());
+        assert_json!(posts("CascadePost", "cd1"), matcher!({
+            "meta": { "count": 0 },
+            "data": [],
+        }));
+        assert_json!(posts("CascadePost", "cd2"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "cd2", "authorCode": "cd2" }],
+        }))
+    }
+
+    #[test]
+    fn nullify_update_only_nullifies_related_records() {
+        create_author("nu1", "NullifyPost");
+        create_author("nu2", "NullifyPost");
+        let res = req(PORT, "update", "Author", json!({ "where": { "code": "nu1" }, "update": { "code": "nu1-new" } }));
+        assert!(res.get("error").is_none());
+        assert_json!(posts("NullifyPost", "nu1"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "nu1" }],
+        }));
+        assert_json!(posts("NullifyPost", "nu2"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "nu2", "authorCode": "nu2" }],
+        }))
+    }
+
+    #[test]
+    fn nullify_delete_only_nullifies_related_records() {
+        create_author("nd1", "NullifyPost");
+        create_author("nd2", "NullifyPost");
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "nd1" } }));
+        assert!(res.get("error").is_none());
+        assert_json!(posts("NullifyPost", "nd1"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "nd1" }],
+        }));
+        assert_json!(posts("NullifyPost", "nd2"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "nd2", "authorCode": "nd2" }],
+        }))
+    }
+
+    #[test]
+    fn deny_only_denies_authors_with_related_records() {
+        create_author("dn1", "DenyPost");
+        let res = req(PORT, "create", "Author", json!({ "create": { "code": "dn2" } }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "update", "Author", json!({ "where": { "code": "dn1" }, "update": { "code": "dn1-new" } }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "dn1" } }));
+        assert!(res.get("error").is_some());
+        let res = req(PORT, "update", "Author", json!({ "where": { "code": "dn2" }, "update": { "code": "dn2-new" } }));
+        assert!(res.get("error").is_none());
+        let res = req(PORT, "delete", "Author", json!({ "where": { "code": "dn2-new" } }));
+        assert!(res.get("error").is_none());
+        assert_json!(posts("DenyPost", "dn1"), matcher!({
+            "meta": { "count": 1 },
+            "data": [{ "id": ignore, "title": "dn1", "authorCode": "dn1" }],
+        }))
+    }
+}
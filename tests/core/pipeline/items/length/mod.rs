@@ -0,0 +1,65 @@
+use test_helpers::*;
+
+#[before_all]
+#[after_all]
+mod test {
+    use serde_json::json;
+    use crate::lib::req;
+    use crate::lib::matcher_functions::bytes_value;
+    use crate::{assert_json, matcher, server_fixture};
+
+    server_fixture!(4047);
+
+    fn before_all() {
+        HANDLE.lock().unwrap().execute(file!(), "serve");
+    }
+
+    fn after_all() {
+        HANDLE.lock().unwrap().exit();
+    }
+
+    #[test]
+    fn bytes_length_in_range() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "aGVs",
+            },
+        }));
+        assert_json!(res, matcher!({
+            "data": {
+                "id": ignore,
+                "bytes": bytes_value("aGVs"),
+            }
+        }))
+    }
+
+    #[test]
+    fn bytes_length_out_of_range() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "bytes": "aGVsbG8=",
+            },
+        }));
+        assert_eq!(res.get("error").unwrap().get("type").unwrap(), "ValidationError");
+    }
+
+    #[test]
+    fn has_length() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "string": "abcd",
+            },
+        }));
+        assert_eq!(res.get("error").unwrap().get("type").unwrap(), "ValidationError");
+    }
+
+    #[test]
+    fn invalid_length_argument() {
+        let res = req(PORT, "create", "Support", json!({
+            "create": {
+                "invalid": "abc",
+            },
+        }));
+        assert_eq!(res.get("error").unwrap().get("type").unwrap(), "InternalServerError");
+    }
+}
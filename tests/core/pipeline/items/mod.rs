@@ -1 +1,2 @@
+pub mod length;
 pub mod string;
@@ -3,17 +3,12 @@ use test_helpers::*;
 #[before_all]
 #[after_all]
 mod test {
-    use std::sync::Mutex;
-    use serde_json::{json, Value};
-    use crate::lib::{ExecutionHandle, req};
+    use serde_json::json;
+    use crate::lib::req;
     use crate::lib::matcher_functions::{date_time_value, decimal_value};
-    use crate::{assert_json, matcher};
-    use once_cell::sync::Lazy;
+    use crate::{assert_json, matcher, server_fixture};
 
-    static HANDLE: Lazy<Mutex<ExecutionHandle>> = Lazy::new(|| {
-        Mutex::new(ExecutionHandle::new())
-    });
-    static PORT: i32 = 4015;
+    server_fixture!(4015);
 
     fn before_all() {
         HANDLE.lock().unwrap().execute(file!(), "serve");
@@ -23,3 +23,15 @@ pub fn decimal_value(val: impl AsRef<str>) -> impl Fn(&Value) -> bool {
         date_value.as_str().unwrap() == val.as_ref()
     }
 }
+
+pub fn bytes_value(val: impl AsRef<str>) -> impl Fn(&Value) -> bool {
+    return move |v: &Value| {
+        if !v.is_object() { return false }
+        let obj = v.as_object().unwrap();
+        if obj.len() != 1 { return false }
+        if obj.get("$bytes").is_none() { return false }
+        let bytes_value = obj.get("$bytes").unwrap();
+        if !bytes_value.is_string() { return false }
+        bytes_value.as_str().unwrap() == val.as_ref()
+    }
+}
@@ -1,13 +1,14 @@
 pub mod matcher;
 pub mod matcher_functions;
 
-use std::process::{Child, Command};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
 use std::{env, thread};
 use std::borrow::Borrow;
 use std::collections::HashSet;
-use std::io::Read;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::ptr::null_mut;
+use std::sync::{Arc, Mutex};
 use inflector::Inflector;
 use key_path::{KeyPath, path};
 use once_cell::sync::Lazy;
@@ -41,20 +42,76 @@ fn teo_exe_path() -> String {
     teo_exe_path_buf().to_str().unwrap().to_string()
 }
 
+/// `cargo test` only writes examples with a hash suffix, `cargo build --examples` writes them
+/// without.
+fn example_exe_path(name: &str) -> String {
+    let dir = teo_exe_path_buf().parent().unwrap().join("examples");
+    let extension = if whoami::platform() == Platform::Windows { ".exe" } else { "" };
+    let exact = dir.join(format!("{name}{extension}"));
+    if exact.is_file() {
+        return exact.to_str().unwrap().to_string();
+    }
+    let prefix = format!("{name}-");
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let file_name = entry.unwrap().file_name().to_str().unwrap().to_string();
+        let is_executable = if extension.is_empty() { !file_name.contains('.') } else { file_name.ends_with(extension) };
+        if file_name.starts_with(&prefix) && is_executable {
+            return dir.join(file_name).to_str().unwrap().to_string();
+        }
+    }
+    panic!("Cannot find example '{}'.", name)
+}
+
 pub struct ExecutionHandle {
-    child: Option<Child>
+    child: Option<Child>,
+    output: Arc<Mutex<Vec<String>>>,
 }
 
 impl ExecutionHandle {
     pub fn new() -> Self {
-        Self { child: None }
+        Self { child: None, output: Arc::new(Mutex::new(vec![])) }
     }
 
     pub fn execute(&mut self, file: &str, args: &str) {
-        self.child = Some(Command::new(teo_exe_path()).arg("-s").arg(schema_from_file(file)).arg(args).spawn().unwrap());
+        self.child = Some(Command::new(teo_exe_path()).arg("-s").arg(schema_from_file(file)).args(args.split_whitespace()).spawn().unwrap());
+        thread::sleep(std::time::Duration::from_secs(2))
+    }
+
+    /// Runs an app from `examples` instead of the CLI, for tests which need custom code.
+    pub fn execute_example(&mut self, example: &str, file: &str, args: &str) {
+        self.child = Some(Command::new(example_exe_path(example)).arg("-s").arg(schema_from_file(file)).args(args.split_whitespace()).spawn().unwrap());
         thread::sleep(std::time::Duration::from_secs(2))
     }
 
+    pub fn execute_capturing_output(&mut self, file: &str, args: &str) {
+        let mut child = Command::new(teo_exe_path()).arg("-s").arg(schema_from_file(file)).args(args.split_whitespace()).stdout(Stdio::piped()).spawn().unwrap();
+        let stdout = child.stdout.take().unwrap();
+        let output = self.output.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                output.lock().unwrap().push(line);
+            }
+        });
+        self.child = Some(child);
+        thread::sleep(std::time::Duration::from_secs(2))
+    }
+
+    pub fn output(&self) -> Vec<String> {
+        self.output.lock().unwrap().clone()
+    }
+
+    /// Sends SIGTERM to the server and waits for it to exit.
+    pub fn terminate(&mut self) -> ExitStatus {
+        let child = self.child.as_mut().unwrap();
+        Command::new("kill").arg("-TERM").arg(child.id().to_string()).status().unwrap();
+        child.wait().unwrap()
+    }
+
+    /// Returns the exit status if the process has already exited.
+    pub fn exit_status(&mut self) -> Option<ExitStatus> {
+        self.child.as_mut().unwrap().try_wait().unwrap()
+    }
+
     pub fn exit(&mut self) {
         if let Some(child) = &mut self.child {
             child.kill().unwrap();
@@ -64,6 +121,33 @@ impl ExecutionHandle {
 
 unsafe impl Sync for ExecutionHandle { }
 
+/// Declares the `HANDLE` of the server a test module starts in `before_all` and stops in
+/// `after_all`, and the `PORT` its schema binds if requests are sent to it.
+#[macro_export]
+macro_rules! server_fixture {
+    () => {
+        static HANDLE: ::once_cell::sync::Lazy<::std::sync::Mutex<$crate::lib::ExecutionHandle>> = ::once_cell::sync::Lazy::new(|| {
+            ::std::sync::Mutex::new($crate::lib::ExecutionHandle::new())
+        });
+    };
+    ($port:expr) => {
+        $crate::server_fixture!();
+        static PORT: i32 = $port;
+    };
+}
+
+/// Runs a one-shot command like `seed` or `migrate` and waits for it to finish.
+pub fn run(file: &str, args: &str) -> ExitStatus {
+    Command::new(teo_exe_path()).arg("-s").arg(schema_from_file(file)).args(args.split_whitespace()).status().unwrap()
+}
+
+/// Runs a one-shot command in the directory of `file` with `schema` from that directory, for
+/// commands which read or write files relative to the working directory.
+pub fn run_in_dir(file: &str, schema: &str, args: &str) -> Output {
+    let dir = Path::new(file).parent().unwrap();
+    Command::new(teo_exe_path()).current_dir(dir).arg("-s").arg(schema).args(args.split_whitespace()).output().unwrap()
+}
+
 pub fn req<J: Borrow<Value>>(port: i32, action: &str, model: &str, data: J) -> Value {
     let url = format!("http://127.0.0.1:{}/{}/action/{}", port, model, action);
     let client = reqwest::blocking::Client::new();